@@ -101,6 +101,8 @@ pub fn Accordion(props: AccordionProps) -> Element {
             background: "{background}",
             onclick,
             border: "1 solid {border_fill}",
+            a11y_role: "group",
+            a11y_expanded: "{open}",
             {&props.summary}
             rect {
                 overflow: "clip",
@@ -2,6 +2,8 @@ use dioxus::prelude::*;
 use freya_elements::{
     elements as dioxus_elements,
     events::{
+        AccessibilityAction,
+        AccessibilityActionEvent,
         KeyboardEvent,
         PointerEvent,
         PointerType,
@@ -25,6 +27,7 @@ use winit::{
 pub enum PressEvent {
     Pointer(PointerEvent),
     Key(KeyboardEvent),
+    Accessibility(AccessibilityActionEvent),
 }
 
 impl PressEvent {
@@ -32,6 +35,7 @@ impl PressEvent {
         match &self {
             Self::Pointer(ev) => ev.stop_propagation(),
             Self::Key(ev) => ev.stop_propagation(),
+            Self::Accessibility(ev) => ev.stop_propagation(),
         }
     }
 }
@@ -159,6 +163,22 @@ pub fn Button(
         }
     };
 
+    let ona11yaction = {
+        to_owned![onpress, onclick];
+        move |ev: AccessibilityActionEvent| {
+            if ev.get_action() != &AccessibilityAction::Default {
+                return;
+            }
+            ev.stop_propagation();
+            focus.focus();
+            if let Some(onpress) = &onpress {
+                onpress.call(PressEvent::Accessibility(ev))
+            } else if let Some(onclick) = onclick {
+                onclick.call(())
+            }
+        }
+    };
+
     let background = match *status.read() {
         ButtonStatus::Hovering => hover_background,
         ButtonStatus::Idle => background,
@@ -175,6 +195,7 @@ pub fn Button(
             onmouseenter,
             onmouseleave,
             onglobalkeydown,
+            ona11yaction,
             a11y_id,
             width: "{width}",
             height: "{height}",
@@ -246,5 +267,13 @@ mod test {
         utils.wait_for_update().await;
 
         assert_eq!(label.get(0).text(), Some("false"));
+
+        // Activate it with an assistive technology
+        let a11y_id = root.get(0).state().accessibility.a11y_id.unwrap();
+        utils
+            .request_a11y_action(a11y_id, AccessibilityAction::Default)
+            .await;
+
+        assert_eq!(label.get(0).text(), Some("true"));
     }
 }
@@ -89,6 +89,8 @@ pub fn Checkbox(
             corner_radius: "4",
             border: "2 solid {border}",
             background: "{fill}",
+            a11y_role: "checkBox",
+            a11y_checked: "{selected}",
             if selected {
                 TickIcon {
                     fill: selected_icon_fill
@@ -5,6 +5,8 @@ use freya_elements::{
     elements as dioxus_elements,
     events::{
         keyboard::Key,
+        AccessibilityAction,
+        AccessibilityActionEvent,
         KeyboardEvent,
         MouseEvent,
     },
@@ -105,6 +107,18 @@ where
         }
     };
 
+    let ona11yaction = {
+        to_owned![onclick];
+        move |ev: AccessibilityActionEvent| {
+            if ev.get_action() == &AccessibilityAction::Default {
+                ev.stop_propagation();
+                if let Some(onclick) = &onclick {
+                    onclick.call(())
+                }
+            }
+        }
+    };
+
     let onclick = move |_: MouseEvent| {
         if let Some(onclick) = &onclick {
             onclick.call(())
@@ -125,6 +139,7 @@ where
             onmouseleave,
             onclick,
             onglobalkeydown,
+            ona11yaction,
             {children}
         }
     )
@@ -231,6 +246,18 @@ where
         }
     };
 
+    let ona11yaction = move |e: AccessibilityActionEvent| {
+        let open = match e.get_action() {
+            AccessibilityAction::Default => !is_opened,
+            AccessibilityAction::Expand => true,
+            AccessibilityAction::Collapse => false,
+            _ => return,
+        };
+        e.stop_propagation();
+        focus.focus();
+        opened.set(open);
+    };
+
     let onmouseenter = move |_| {
         platform.set_cursor(CursorIcon::Pointer);
         status.set(DropdownStatus::Hovering);
@@ -268,8 +295,11 @@ where
                 onmouseleave,
                 onclick,
                 onglobalkeydown,
+                ona11yaction,
                 margin: "{margin}",
                 a11y_id,
+                a11y_role: "comboBox",
+                a11y_expanded: "{is_opened}",
                 background: "{button_background}",
                 color: "{font_theme.color}",
                 corner_radius: "8",
@@ -388,5 +418,23 @@ mod test {
 
         // The second optio was selected
         assert_eq!(label.get(0).text(), Some("Value B"));
+
+        // Expand and collapse it with an assistive technology
+        let a11y_id = root.get(0).get(0).state().accessibility.a11y_id.unwrap();
+        utils
+            .request_a11y_action(a11y_id, AccessibilityAction::Expand)
+            .await;
+
+        assert!(utils.sdom().get().layout().size() > start_size);
+        assert_eq!(
+            root.get(0).get(0).state().accessibility.a11y_expanded,
+            Some(true)
+        );
+
+        utils
+            .request_a11y_action(a11y_id, AccessibilityAction::Collapse)
+            .await;
+
+        assert_eq!(utils.sdom().get().layout().size(), start_size);
     }
 }
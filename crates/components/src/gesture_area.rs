@@ -1,5 +1,8 @@
 use std::{
-    collections::VecDeque,
+    collections::{
+        BTreeMap,
+        VecDeque,
+    },
     time::Instant,
 };
 
@@ -8,10 +11,15 @@ use freya_elements::{
     elements as dioxus_elements,
     events::{
         touch::TouchPhase,
+        MouseEvent,
+        PinchEvent,
+        RotationEvent,
+        TouchData,
         TouchEvent,
     },
 };
 use futures_util::StreamExt;
+use torin::geometry::CursorPoint;
 
 /// Distance between the first tap and the second tap in [`Gesture::DoubleTap`] gesture.
 const DOUBLE_TAP_DISTANCE: f64 = 100.0;
@@ -22,17 +30,102 @@ const DOUBLE_TAP_TIMEOUT: u128 = 300; // 300ms
 /// Minimum time between the end of the first time to the start of the second tap in a [`Gesture::DoubleTap`] gesture.
 const DOUBLE_TAP_MIN: u128 = 40; // 40ms
 
+/// Minimum distance that a finger must move to start a [`Gesture::Pan`] gesture.
+const PAN_MIN_DISTANCE: f64 = 10.0;
+
+/// Minimum velocity (in pixels per second) of a finger when lifted to emit a [`Gesture::Swipe`] gesture.
+const SWIPE_MIN_VELOCITY: f64 = 300.0;
+
+/// Maximum time since the last movement of a finger when lifted to emit a [`Gesture::Swipe`] gesture.
+const SWIPE_MAX_IDLE: u128 = 100; // 100ms
+
 /// In-memory events queue maximum size.
 const MAX_EVENTS_QUEUE: usize = 20;
 
+/// Direction of a [`Gesture::Swipe`] gesture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwipeDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
 /// Gesture emitted by the [`GestureArea`] component.
-#[derive(Debug, PartialEq, Eq)]
+///
+/// The floating point values are compared with [`f64::total_cmp`], so gestures can be `Eq`.
+#[derive(Debug, Clone)]
 pub enum Gesture {
     TapUp,
     TapDown,
     DoubleTap,
+    /// A finger was kept pressed without moving.
+    LongPress,
+    /// A finger is being dragged.
+    /// `delta` is the movement since the previous [`Gesture::Pan`] and `velocity` is in pixels per second.
+    Pan {
+        delta: CursorPoint,
+        velocity: CursorPoint,
+    },
+    /// A finger was dragged and lifted quickly.
+    Swipe(SwipeDirection),
+    /// Two fingers (or the trackpad) are pinching.
+    /// `scale` is relative to the start of the gesture and `focal_point` is relative to the [`GestureArea`].
+    Pinch {
+        scale: f64,
+        focal_point: CursorPoint,
+    },
+    /// Two fingers (or the trackpad) are rotating.
+    /// `angle` is in degrees relative to the start of the gesture, positive values rotate clockwise.
+    /// `focal_point` is relative to the [`GestureArea`].
+    Rotate {
+        angle: f64,
+        focal_point: CursorPoint,
+    },
 }
 
+/// Check if two floats are equal according to [`f64::total_cmp`].
+fn total_eq(a: f64, b: f64) -> bool {
+    a.total_cmp(&b).is_eq()
+}
+
+/// Check if two points are equal according to [`f64::total_cmp`].
+fn total_eq_point(a: CursorPoint, b: CursorPoint) -> bool {
+    total_eq(a.x, b.x) && total_eq(a.y, b.y)
+}
+
+impl PartialEq for Gesture {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Pan { delta, velocity },
+                Self::Pan {
+                    delta: other_delta,
+                    velocity: other_velocity,
+                },
+            ) => total_eq_point(*delta, *other_delta) && total_eq_point(*velocity, *other_velocity),
+            (Self::Swipe(direction), Self::Swipe(other_direction)) => direction == other_direction,
+            (
+                Self::Pinch { scale, focal_point },
+                Self::Pinch {
+                    scale: other_scale,
+                    focal_point: other_focal_point,
+                },
+            ) => total_eq(*scale, *other_scale) && total_eq_point(*focal_point, *other_focal_point),
+            (
+                Self::Rotate { angle, focal_point },
+                Self::Rotate {
+                    angle: other_angle,
+                    focal_point: other_focal_point,
+                },
+            ) => total_eq(*angle, *other_angle) && total_eq_point(*focal_point, *other_focal_point),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Gesture {}
+
 /// Properties for the [`GestureArea`] component.
 #[derive(Props, Clone, PartialEq)]
 pub struct GestureAreaProps {
@@ -42,9 +135,248 @@ pub struct GestureAreaProps {
     pub ongesture: EventHandler<Gesture>,
 }
 
-type EventsQueue = VecDeque<(Instant, TouchEvent)>;
+type EventsQueue = VecDeque<(Instant, TouchData)>;
+
+/// Events processed by the [`GestureArea`].
+enum GestureEvent {
+    Touch(Instant, TouchData),
+    LongPress,
+    Pinch(PinchEvent),
+    Rotation(RotationEvent),
+}
+
+/// A finger touching the [`GestureArea`].
+struct Finger {
+    start: CursorPoint,
+    position: CursorPoint,
+    time: Instant,
+    velocity: CursorPoint,
+}
+
+/// Distance, angle (in degrees) and middle point between the two first fingers.
+fn measure_fingers(fingers: &BTreeMap<u64, Finger>) -> Option<(f64, f64, CursorPoint)> {
+    let mut fingers = fingers.values();
+    let first = fingers.next()?.position;
+    let second = fingers.next()?.position;
+    let vector = second - first;
+
+    Some((
+        vector.length(),
+        vector.y.atan2(vector.x).to_degrees(),
+        first.lerp(second, 0.5),
+    ))
+}
+
+/// Normalize an angle in degrees to the `-180..=180` range.
+fn normalize_angle(angle: f64) -> f64 {
+    let angle = angle % 360.0;
+    if angle > 180.0 {
+        angle - 360.0
+    } else if angle < -180.0 {
+        angle + 360.0
+    } else {
+        angle
+    }
+}
+
+/// Touch state of a [`GestureArea`], turns the touch events into gestures.
+#[derive(Default)]
+struct TouchGestures {
+    touch_events: EventsQueue,
+    fingers: BTreeMap<u64, Finger>,
+    panning: bool,
+    // Distance and angle between the fingers when the multi finger gesture started
+    multi_finger_start: Option<(f64, f64)>,
+}
+
+impl TouchGestures {
+    /// Check if a [`Gesture::LongPress`] can be emitted, only one finger must be pressed and not panning.
+    fn is_long_press(&self) -> bool {
+        self.fingers.len() == 1 && !self.panning
+    }
+
+    /// Process a touch event that happened at the given `time`, calling `emit` with the detected gestures.
+    fn process(&mut self, time: Instant, event: TouchData, mut emit: impl FnMut(Gesture)) {
+        self.touch_events.push_back((time, event));
+
+        // Keep the touch events queue under a certain size
+        if self.touch_events.len() > MAX_EVENTS_QUEUE {
+            self.touch_events.pop_front();
+        }
 
-/// Detect complex touch gestures such as [`Gesture::DoubleTap`].
+        // Find the first event with the `target_phase` that happened before the `start_time`
+        let find_previous_event = |start_time: &Instant,
+                                   events: &EventsQueue,
+                                   target_phase: TouchPhase|
+         -> Option<(Instant, TouchData)> {
+            let mut start = false;
+            for (time, event) in events.iter().rev() {
+                if time == start_time {
+                    start = true;
+                    continue;
+                }
+                if event.phase == target_phase && start {
+                    return Some((*time, event.clone()));
+                }
+            }
+            None
+        };
+
+        // Process the most recent event
+        let event = self.touch_events.iter().last();
+
+        if let Some((time, event)) = event {
+            let phase = event.get_touch_phase();
+            let position = event.get_element_coordinates();
+
+            match phase {
+                TouchPhase::Started => {
+                    self.fingers.insert(
+                        event.get_finger_id(),
+                        Finger {
+                            start: position,
+                            position,
+                            time: *time,
+                            velocity: CursorPoint::zero(),
+                        },
+                    );
+
+                    // Pinch and Rotate
+                    if self.fingers.len() > 1 {
+                        self.panning = false;
+                        if self.multi_finger_start.is_none() {
+                            self.multi_finger_start = measure_fingers(&self.fingers)
+                                .map(|(distance, angle, _)| (distance, angle));
+                        }
+                        return;
+                    }
+
+                    // TapDown
+                    emit(Gesture::TapDown);
+
+                    let last_ended_event =
+                        find_previous_event(time, &self.touch_events, TouchPhase::Ended);
+                    let last_started_event =
+                        find_previous_event(time, &self.touch_events, TouchPhase::Started);
+
+                    // DoubleTap
+                    if let Some(((ended_time, ended_event), (started_time, _))) =
+                        last_ended_event.zip(last_started_event)
+                    {
+                        // Has the latest `touchend` event went too far?
+                        let is_ended_close = event
+                            .get_screen_coordinates()
+                            .distance_to(ended_event.get_screen_coordinates())
+                            < DOUBLE_TAP_DISTANCE;
+                        // Is the latest `touchend` mature enough?
+                        let is_ended_mature =
+                            time.duration_since(ended_time).as_millis() >= DOUBLE_TAP_MIN;
+
+                        // Hast the latest `touchstart` event expired?
+                        let is_started_recent =
+                            time.duration_since(started_time).as_millis() <= DOUBLE_TAP_TIMEOUT;
+
+                        if is_ended_close && is_ended_mature && is_started_recent {
+                            emit(Gesture::DoubleTap);
+                        }
+                    }
+                }
+                TouchPhase::Moved => {
+                    let Some(finger) = self.fingers.get_mut(&event.get_finger_id()) else {
+                        return;
+                    };
+
+                    let delta = position - finger.position;
+                    let elapsed = time.duration_since(finger.time).as_secs_f64();
+                    if elapsed > 0.0 {
+                        finger.velocity = (delta / elapsed).to_point();
+                    }
+                    finger.position = position;
+                    finger.time = *time;
+
+                    // Pinch and Rotate
+                    if let Some((start_distance, start_angle)) = self.multi_finger_start {
+                        if let Some((distance, angle, focal_point)) = measure_fingers(&self.fingers)
+                        {
+                            if start_distance > 0.0 {
+                                emit(Gesture::Pinch {
+                                    scale: distance / start_distance,
+                                    focal_point,
+                                });
+                            }
+                            emit(Gesture::Rotate {
+                                angle: normalize_angle(angle - start_angle),
+                                focal_point,
+                            });
+                        }
+                        return;
+                    }
+
+                    // Pan
+                    if !self.panning && finger.start.distance_to(position) >= PAN_MIN_DISTANCE {
+                        self.panning = true;
+                    }
+
+                    if self.panning {
+                        emit(Gesture::Pan {
+                            delta: delta.to_point(),
+                            velocity: finger.velocity,
+                        });
+                    }
+                }
+                TouchPhase::Ended | TouchPhase::Cancelled => {
+                    let finger = self.fingers.remove(&event.get_finger_id());
+
+                    if self.fingers.len() < 2 {
+                        self.multi_finger_start = None;
+                    }
+
+                    if phase == TouchPhase::Cancelled {
+                        self.panning = false;
+                        return;
+                    }
+
+                    // Swipe
+                    if let Some(Finger {
+                        velocity,
+                        time: moved_time,
+                        ..
+                    }) = finger.filter(|_| self.panning)
+                    {
+                        let is_fast = velocity.to_vector().length() >= SWIPE_MIN_VELOCITY;
+                        let is_recent =
+                            time.duration_since(moved_time).as_millis() <= SWIPE_MAX_IDLE;
+                        if is_fast && is_recent {
+                            let direction = if velocity.x.abs() > velocity.y.abs() {
+                                if velocity.x > 0.0 {
+                                    SwipeDirection::Right
+                                } else {
+                                    SwipeDirection::Left
+                                }
+                            } else if velocity.y > 0.0 {
+                                SwipeDirection::Down
+                            } else {
+                                SwipeDirection::Up
+                            };
+                            emit(Gesture::Swipe(direction));
+                        }
+                    }
+
+                    if self.fingers.is_empty() {
+                        self.panning = false;
+                    }
+
+                    // TapUp
+                    emit(Gesture::TapUp);
+                }
+            }
+        }
+    }
+}
+
+/// Detect complex touch gestures such as [`Gesture::DoubleTap`], [`Gesture::Pinch`] or [`Gesture::Swipe`].
+///
+/// Trackpad pinches and rotations are also emitted as [`Gesture::Pinch`] and [`Gesture::Rotate`].
 ///
 /// # Example
 ///
@@ -64,99 +396,84 @@ type EventsQueue = VecDeque<(Instant, TouchEvent)>;
 /// ```
 #[allow(non_snake_case)]
 pub fn GestureArea(props: GestureAreaProps) -> Element {
-    let event_emitter = use_coroutine(
-        |mut rx: UnboundedReceiver<(Instant, TouchEvent)>| async move {
-            let mut touch_events = VecDeque::<(Instant, TouchEvent)>::new();
-
-            while let Some(new_event) = rx.next().await {
-                touch_events.push_back(new_event);
-
-                // Keep the touch events queue under a certain size
-                if touch_events.len() > MAX_EVENTS_QUEUE {
-                    touch_events.pop_front();
+    let event_emitter = use_coroutine(|mut rx: UnboundedReceiver<GestureEvent>| async move {
+        let mut touch_gestures = TouchGestures::default();
+        let mut trackpad_scale = 1.0;
+        let mut trackpad_angle = 0.0;
+
+        while let Some(gesture_event) = rx.next().await {
+            match gesture_event {
+                GestureEvent::Touch(time, event) => {
+                    touch_gestures.process(time, event, |gesture| props.ongesture.call(gesture));
                 }
-
-                // Find the first event with the `target_phase` that happened before the `start_time`
-                let find_previous_event = |start_time: &Instant,
-                                           events: &EventsQueue,
-                                           target_phase: TouchPhase|
-                 -> Option<(Instant, TouchEvent)> {
-                    let mut start = false;
-                    for (time, event) in events.iter().rev() {
-                        if time == start_time {
-                            start = true;
-                            continue;
-                        }
-                        if event.phase == target_phase && start {
-                            return Some((*time, event.clone()));
-                        }
+                GestureEvent::LongPress => {
+                    if touch_gestures.is_long_press() {
+                        props.ongesture.call(Gesture::LongPress);
                     }
-                    None
-                };
-
-                // Process the most recent event
-                let event = touch_events.iter().last();
-
-                if let Some((time, event)) = event {
-                    let phase = event.get_touch_phase();
-
-                    match phase {
-                        TouchPhase::Started => {
-                            // TapDown
-                            props.ongesture.call(Gesture::TapDown);
-
-                            let last_ended_event =
-                                find_previous_event(time, &touch_events, TouchPhase::Ended);
-                            let last_started_event =
-                                find_previous_event(time, &touch_events, TouchPhase::Started);
-
-                            // DoubleTap
-                            if let Some(((ended_time, ended_event), (started_time, _))) =
-                                last_ended_event.zip(last_started_event)
-                            {
-                                // Has the latest `touchend` event went too far?
-                                let is_ended_close = event
-                                    .get_screen_coordinates()
-                                    .distance_to(ended_event.get_screen_coordinates())
-                                    < DOUBLE_TAP_DISTANCE;
-                                // Is the latest `touchend` mature enough?
-                                let is_ended_mature =
-                                    ended_time.elapsed().as_millis() >= DOUBLE_TAP_MIN;
-
-                                // Hast the latest `touchstart` event expired?
-                                let is_started_recent =
-                                    started_time.elapsed().as_millis() <= DOUBLE_TAP_TIMEOUT;
-
-                                if is_ended_close && is_ended_mature && is_started_recent {
-                                    props.ongesture.call(Gesture::DoubleTap);
-                                }
-                            }
-                        }
-                        TouchPhase::Ended => {
-                            // TapUp
-                            props.ongesture.call(Gesture::TapUp);
-                        }
-                        _ => {}
+                }
+                GestureEvent::Pinch(event) => {
+                    if event.get_phase() == TouchPhase::Started {
+                        trackpad_scale = 1.0;
                     }
+                    trackpad_scale *= 1.0 + event.get_delta();
+                    props.ongesture.call(Gesture::Pinch {
+                        scale: trackpad_scale,
+                        focal_point: event.get_element_coordinates(),
+                    });
+                }
+                GestureEvent::Rotation(event) => {
+                    if event.get_phase() == TouchPhase::Started {
+                        trackpad_angle = 0.0;
+                    }
+                    // Trackpads rotate counterclockwise with positive deltas
+                    trackpad_angle -= event.get_delta() as f64;
+                    props.ongesture.call(Gesture::Rotate {
+                        angle: trackpad_angle,
+                        focal_point: event.get_element_coordinates(),
+                    });
                 }
             }
-        },
-    );
+        }
+    });
 
     let ontouchcancel = move |e: TouchEvent| {
-        event_emitter.send((Instant::now(), e));
+        event_emitter.send(GestureEvent::Touch(
+            Instant::now(),
+            e.data().as_ref().clone(),
+        ));
     };
 
     let ontouchend = move |e: TouchEvent| {
-        event_emitter.send((Instant::now(), e));
+        event_emitter.send(GestureEvent::Touch(
+            Instant::now(),
+            e.data().as_ref().clone(),
+        ));
     };
 
     let ontouchmove = move |e: TouchEvent| {
-        event_emitter.send((Instant::now(), e));
+        event_emitter.send(GestureEvent::Touch(
+            Instant::now(),
+            e.data().as_ref().clone(),
+        ));
     };
 
     let ontouchstart = move |e: TouchEvent| {
-        event_emitter.send((Instant::now(), e));
+        event_emitter.send(GestureEvent::Touch(
+            Instant::now(),
+            e.data().as_ref().clone(),
+        ));
+    };
+
+    let onlongpress = move |_: MouseEvent| {
+        event_emitter.send(GestureEvent::LongPress);
+    };
+
+    let onpinch = move |e: PinchEvent| {
+        event_emitter.send(GestureEvent::Pinch(e));
+    };
+
+    let onrotation = move |e: RotationEvent| {
+        event_emitter.send(GestureEvent::Rotation(e));
     };
 
     rsx!(
@@ -165,6 +482,9 @@ pub fn GestureArea(props: GestureAreaProps) -> Element {
             ontouchend: ontouchend,
             ontouchmove: ontouchmove,
             ontouchstart: ontouchstart,
+            onlongpress,
+            onpinch,
+            onrotation,
             {props.children}
         }
     )
@@ -172,7 +492,10 @@ pub fn GestureArea(props: GestureAreaProps) -> Element {
 
 #[cfg(test)]
 mod test {
-    use std::time::Duration;
+    use std::time::{
+        Duration,
+        Instant,
+    };
 
     use freya::prelude::*;
     use freya_testing::prelude::*;
@@ -309,4 +632,196 @@ mod test {
 
         assert_eq!(utils.root().get(1).get(0).text(), Some("TapUp"));
     }
+
+    fn touch(
+        name: EventName,
+        phase: TouchPhase,
+        location: (f64, f64),
+        finger_id: u64,
+    ) -> PlatformEvent {
+        PlatformEvent::Touch {
+            name,
+            location: location.into(),
+            phase,
+            finger_id,
+            force: None,
+        }
+    }
+
+    /// Simulates `Pinch` and `Rotate` gestures with two fingers.
+    #[tokio::test]
+    pub async fn pinch_rotate() {
+        fn pinch_rotate_app() -> Element {
+            let mut pinch = use_signal(|| "EMPTY".to_string());
+            let mut rotate = use_signal(|| "EMPTY".to_string());
+
+            let ongesture = move |e: Gesture| match e {
+                Gesture::Pinch { scale, focal_point } => {
+                    pinch.set(format!("{scale} {}x{}", focal_point.x, focal_point.y))
+                }
+                Gesture::Rotate { angle, .. } => rotate.set(format!("{angle}")),
+                _ => {}
+            };
+
+            rsx!(
+                GestureArea {
+                    ongesture,
+                    rect {
+                        width: "100%",
+                        height: "100%",
+                    }
+                }
+                label {
+                    "{pinch}"
+                }
+                label {
+                    "{rotate}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(pinch_rotate_app);
+        utils.wait_for_update().await;
+
+        utils.push_event(touch(
+            EventName::TouchStart,
+            TouchPhase::Started,
+            (100.0, 100.0),
+            0,
+        ));
+        utils.wait_for_update().await;
+        utils.push_event(touch(
+            EventName::TouchStart,
+            TouchPhase::Started,
+            (200.0, 100.0),
+            1,
+        ));
+        utils.wait_for_update().await;
+
+        // Rotate the second finger around the first one
+        utils.push_event(touch(
+            EventName::TouchMove,
+            TouchPhase::Moved,
+            (100.0, 200.0),
+            1,
+        ));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("1 100x150"));
+        assert_eq!(utils.root().get(2).get(0).text(), Some("90"));
+
+        // Move the second finger away from the first one
+        utils.push_event(touch(
+            EventName::TouchMove,
+            TouchPhase::Moved,
+            (100.0, 300.0),
+            1,
+        ));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("2 100x200"));
+        assert_eq!(utils.root().get(2).get(0).text(), Some("90"));
+    }
+
+    /// Simulates `Pan` and `Swipe` gestures with one finger, at the given times.
+    #[test]
+    pub fn pan_swipe() {
+        use freya_elements::events::TouchData;
+
+        use crate::gesture_area::{
+            Gesture,
+            SwipeDirection,
+            TouchGestures,
+        };
+
+        let process = |events: &[(u64, TouchPhase, (f64, f64))]| {
+            let start = Instant::now();
+            let mut touch_gestures = TouchGestures::default();
+            let mut gestures = Vec::new();
+            for (millis, phase, location) in events {
+                touch_gestures.process(
+                    start + Duration::from_millis(*millis),
+                    TouchData::new((*location).into(), (*location).into(), 0, *phase, None),
+                    |gesture| gestures.push(gesture),
+                );
+            }
+            gestures
+        };
+
+        let pan = Gesture::Pan {
+            delta: (0.0, 50.0).into(),
+            velocity: (0.0, 400.0).into(),
+        };
+
+        // The first movement is too short to be a pan
+        let gestures = process(&[
+            (0, TouchPhase::Started, (100.0, 100.0)),
+            (125, TouchPhase::Moved, (105.0, 100.0)),
+            (250, TouchPhase::Moved, (105.0, 150.0)),
+            (300, TouchPhase::Ended, (105.0, 150.0)),
+        ]);
+        assert_eq!(
+            gestures,
+            [
+                Gesture::TapDown,
+                pan.clone(),
+                Gesture::Swipe(SwipeDirection::Down),
+                Gesture::TapUp
+            ]
+        );
+
+        // The finger stopped for too long before being lifted
+        let gestures = process(&[
+            (0, TouchPhase::Started, (100.0, 100.0)),
+            (125, TouchPhase::Moved, (105.0, 100.0)),
+            (250, TouchPhase::Moved, (105.0, 150.0)),
+            (400, TouchPhase::Ended, (105.0, 150.0)),
+        ]);
+        assert_eq!(gestures, [Gesture::TapDown, pan, Gesture::TapUp]);
+    }
+
+    /// Simulates a trackpad pinch.
+    #[tokio::test]
+    pub async fn trackpad_pinch() {
+        fn trackpad_pinch_app() -> Element {
+            let mut value = use_signal(|| "EMPTY".to_string());
+
+            let ongesture = move |e: Gesture| {
+                if let Gesture::Pinch { scale, .. } = e {
+                    value.set(format!("{scale}"))
+                }
+            };
+
+            rsx!(
+                GestureArea {
+                    ongesture,
+                    rect {
+                        width: "100%",
+                        height: "100%",
+                    }
+                }
+                label {
+                    "{value}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(trackpad_pinch_app);
+        utils.wait_for_update().await;
+
+        for (delta, phase) in [(0.5, TouchPhase::Started), (1.0, TouchPhase::Moved)] {
+            utils.push_event(PlatformEvent::Pinch {
+                name: EventName::Pinch,
+                cursor: (100.0, 100.0).into(),
+                delta,
+                phase,
+            });
+            utils.wait_for_update().await;
+            utils.wait_for_update().await;
+        }
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("3"));
+    }
 }
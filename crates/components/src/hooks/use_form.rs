@@ -1,9 +1,11 @@
 // This hook is in freya-components instead of freya-hooks because it uses some component props.
 
 use std::{
+    cell::RefCell,
     collections::HashMap,
     fmt::Display,
     hash::Hash,
+    rc::Rc,
 };
 
 use dioxus::prelude::*;
@@ -16,13 +18,16 @@ use crate::{
 
 type SubmitCallback<Id> = Box<dyn Fn(&HashMap<Id, String>)>;
 
+type FieldValidator = Rc<dyn Fn(&str) -> bool>;
+
 /// Form controller
 ///
-/// Use [`Self::input()`] to register inputs
+/// Use [`Self::input()`] or [`Self::validated_input()`] to register inputs
 /// And [`Self::submit()`] to register a submitter button
 #[derive(Clone)]
 pub struct UseForm<Id: Hash + Eq + 'static> {
     data: Signal<HashMap<Id, String>>,
+    validators: Rc<RefCell<HashMap<Id, FieldValidator>>>,
     onsubmit: Signal<SubmitCallback<Id>>,
 }
 
@@ -41,17 +46,65 @@ impl<Id: Clone + Hash + Eq + Display> UseForm<Id> {
             value,
             placeholder: Some(placeholder),
             auto_focus: false,
+            max_length: None,
+            onvalidate: None,
+            mask: None,
+            numeric: None,
+            error: false,
         }
     }
 
+    /// Register an Input component whose value must pass the given validator.
+    ///
+    /// The Input is displayed in an error state once edited with an invalid value,
+    /// and the form won't be submitted until all the validated inputs are valid.
+    pub fn validated_input(
+        &self,
+        id: Id,
+        validator: impl Fn(&str) -> bool + 'static,
+    ) -> InputProps {
+        let is_touched = self.data.read().contains_key(&id);
+        let mut props = self.input(id.clone());
+        props.error = is_touched && !validator(&props.value);
+        self.validators.borrow_mut().insert(id, Rc::new(validator));
+        props
+    }
+
+    /// Check if all the validated inputs have valid values.
+    pub fn is_valid(&self) -> bool {
+        self.invalid_fields().is_empty()
+    }
+
+    fn invalid_fields(&self) -> Vec<Id> {
+        let data = self.data.peek();
+        self.validators
+            .borrow()
+            .iter()
+            .filter(|(id, validator)| {
+                !validator(data.get(id).map(String::as_str).unwrap_or_default())
+            })
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
     /// Register a Button component
     pub fn submit(&self) -> ButtonProps {
         let submit = self.onsubmit;
-        let data = self.data;
+        let form = self.clone();
+        let mut data = self.data;
         ButtonProps {
             theme: None,
             onpress: Some(EventHandler::new(move |_| {
-                (submit.peek())(&data.read());
+                let invalid_fields = form.invalid_fields();
+                if invalid_fields.is_empty() {
+                    (submit.peek())(&data.read());
+                } else {
+                    // Mark the invalid fields as edited so their errors are shown
+                    let mut data = data.write();
+                    for id in invalid_fields {
+                        data.entry(id).or_default();
+                    }
+                }
             })),
             children: None,
             onclick: None,
@@ -65,6 +118,7 @@ pub fn use_form<Id: Hash + Eq + Clone>(
 ) -> UseForm<Id> {
     use_hook(|| UseForm {
         data: Signal::new(HashMap::default()),
+        validators: Rc::default(),
         onsubmit: Signal::new(Box::new(onsubmit)),
     })
 }
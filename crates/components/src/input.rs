@@ -1,8 +1,19 @@
+use std::{
+    borrow::Cow,
+    cell::{
+        Ref,
+        RefCell,
+    },
+    rc::Rc,
+};
+
 use dioxus::prelude::*;
 use freya_elements::{
     elements as dioxus_elements,
     events::{
         keyboard::Key,
+        AccessibilityAction,
+        AccessibilityActionEvent,
         KeyboardData,
         MouseEvent,
     },
@@ -37,6 +48,212 @@ impl InputMode {
     }
 }
 
+/// Mask used to format the text of an [`Input`] as it is typed.
+///
+/// In the pattern, `#` accepts a digit, `A` accepts a letter and `*` accepts a letter or a digit.
+/// Any other character is a literal that gets inserted automatically.
+#[derive(Clone, PartialEq, Debug)]
+pub struct InputMask(Cow<'static, str>);
+
+impl InputMask {
+    /// Create a new [`InputMask`] from a pattern, e.g `"(###) ###-####"`.
+    pub fn new(pattern: impl Into<Cow<'static, str>>) -> Self {
+        Self(pattern.into())
+    }
+
+    /// Phone number mask, `(###) ###-####`.
+    pub fn phone() -> Self {
+        Self::new("(###) ###-####")
+    }
+
+    /// Date mask, `##/##/####`.
+    pub fn date() -> Self {
+        Self::new("##/##/####")
+    }
+
+    /// IPv4 address mask, `###.###.###.###`.
+    pub fn ipv4() -> Self {
+        Self::new("###.###.###.###")
+    }
+
+    /// Get the pattern of this mask.
+    pub fn pattern(&self) -> &str {
+        &self.0
+    }
+
+    fn accepts(slot: char, ch: char) -> Option<bool> {
+        match slot {
+            '#' => Some(ch.is_ascii_digit()),
+            'A' => Some(ch.is_alphabetic()),
+            '*' => Some(ch.is_alphanumeric()),
+            _ => None,
+        }
+    }
+
+    /// Format the given text with this mask, returning the formatted text
+    /// and where the given cursor (in chars) ends up in it.
+    pub fn apply(&self, text: &str, cursor: usize) -> (String, usize) {
+        let mut chars = text
+            .chars()
+            .enumerate()
+            .filter(|(_, ch)| ch.is_alphanumeric())
+            .peekable();
+        let mut result = String::new();
+        let mut result_len = 0;
+        let mut new_cursor = 0;
+
+        for slot in self.0.chars() {
+            if chars.peek().is_none() {
+                break;
+            }
+
+            if Self::accepts(slot, ' ').is_none() {
+                // Literal characters are inserted automatically
+                result.push(slot);
+                result_len += 1;
+                continue;
+            }
+
+            // Consume raw characters until one fits in this slot
+            for (idx, ch) in chars.by_ref() {
+                if Self::accepts(slot, ch) == Some(true) {
+                    result.push(ch);
+                    result_len += 1;
+                    if idx < cursor {
+                        new_cursor = result_len;
+                    }
+                    break;
+                }
+            }
+        }
+
+        (result, new_cursor)
+    }
+}
+
+/// Numeric-only configuration for the [`Input`].
+///
+/// Only numbers are accepted and the value can be incremented or decremented with the arrow keys.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct InputNumeric {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub step: f64,
+}
+
+impl Default for InputNumeric {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            step: 1.0,
+        }
+    }
+}
+
+impl InputNumeric {
+    /// Create a [`InputNumeric`] with no bounds and a step of `1`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the minimum value.
+    pub fn with_min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Specify the maximum value.
+    pub fn with_max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Specify the step used by the arrow keys.
+    pub fn with_step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Check if the text is a number, or the beginning of one, e.g `-` or `4.`.
+    pub fn accepts(&self, text: &str) -> bool {
+        let allow_negative = self.min.map(|min| min < 0.0).unwrap_or(true);
+        let digits = match text.strip_prefix('-') {
+            Some(digits) if allow_negative => digits,
+            Some(_) => return false,
+            None => text,
+        };
+        let mut dots = 0;
+        digits.chars().all(|ch| {
+            if ch == '.' {
+                dots += 1;
+                dots == 1
+            } else {
+                ch.is_ascii_digit()
+            }
+        })
+    }
+
+    /// Check if the text is a number within the bounds.
+    pub fn in_range(&self, text: &str) -> bool {
+        match text.parse::<f64>() {
+            Ok(value) => {
+                self.min.map(|min| value >= min).unwrap_or(true)
+                    && self.max.map(|max| value <= max).unwrap_or(true)
+            }
+            Err(_) => text.is_empty(),
+        }
+    }
+
+    /// Increment (or decrement with a negative `steps`) the value of the text.
+    pub fn step_value(&self, text: &str, steps: f64) -> String {
+        let value = text.parse::<f64>().unwrap_or_default() + self.step * steps;
+        let value = match (self.min, self.max) {
+            (Some(min), _) if value < min => min,
+            (_, Some(max)) if value > max => max,
+            _ => value,
+        };
+        let decimals = self
+            .step
+            .to_string()
+            .split_once('.')
+            .map(|(_, decimals)| decimals.len())
+            .unwrap_or_default();
+        format!("{value:.decimals$}")
+    }
+}
+
+/// Passed to the `onvalidate` handler of [`Input`] to accept or reject the new text.
+#[derive(Clone)]
+pub struct InputValidator {
+    valid: Rc<RefCell<bool>>,
+    text: Rc<RefCell<String>>,
+}
+
+impl InputValidator {
+    pub fn new(text: String) -> Self {
+        Self {
+            valid: Rc::new(RefCell::new(true)),
+            text: Rc::new(RefCell::new(text)),
+        }
+    }
+
+    /// The new text that is being validated.
+    pub fn text(&self) -> Ref<String> {
+        self.text.borrow()
+    }
+
+    /// Mark the new text as valid or invalid. Invalid edits are discarded.
+    pub fn set_valid(&self, is_valid: bool) {
+        *self.valid.borrow_mut() = is_valid;
+    }
+
+    /// Check if the new text is valid.
+    pub fn is_valid(&self) -> bool {
+        *self.valid.borrow()
+    }
+}
+
 /// Indicates the current status of the Input.
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub enum InputStatus {
@@ -64,10 +281,24 @@ pub struct InputProps {
     /// Automatically focus this Input upon creation. Default `false`.
     #[props(default = false)]
     pub auto_focus: bool,
+    /// Maximum amount of characters allowed.
+    pub max_length: Option<usize>,
+    /// Handler to validate the new text before applying it. Rejected edits are discarded.
+    pub onvalidate: Option<EventHandler<InputValidator>>,
+    /// Format the text as it is typed, e.g a phone number or a date.
+    pub mask: Option<InputMask>,
+    /// Only accept numbers, optionally within some bounds.
+    pub numeric: Option<InputNumeric>,
+    /// Display the Input in an error state. Default `false`.
+    #[props(default = false)]
+    pub error: bool,
 }
 
 /// Small box to edit text.
 ///
+/// The text can be constrained with `max_length`, `mask`, `numeric` and `onvalidate`,
+/// edits that don't satisfy them are discarded.
+///
 /// # Styling
 /// Inherits the [`InputTheme`](freya_hooks::InputTheme) theme.
 ///
@@ -91,6 +322,21 @@ pub struct InputProps {
 ///     )
 /// }
 /// ```
+///
+/// # Numeric example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut value = use_signal(|| "1".to_string());
+///
+///     rsx!(Input {
+///         value: value.read().clone(),
+///         numeric: InputNumeric::new().with_min(0.0).with_max(10.0),
+///         onchange: move |e| value.set(e)
+///     })
+/// }
+/// ```
 #[allow(non_snake_case)]
 pub fn Input(
     InputProps {
@@ -100,6 +346,11 @@ pub fn Input(
         mode,
         placeholder,
         auto_focus,
+        max_length,
+        onvalidate,
+        mask,
+        numeric,
+        error,
     }: InputProps,
 ) -> Element {
     let platform = use_platform();
@@ -124,11 +375,100 @@ pub fn Input(
         }
     });
 
+    let ona11yaction = {
+        to_owned![mask];
+        move |e: AccessibilityActionEvent| match e.get_action() {
+            AccessibilityAction::Default => {
+                e.stop_propagation();
+                focus.focus();
+            }
+            AccessibilityAction::SetValue(text) => {
+                e.stop_propagation();
+                let cursor = text.chars().count();
+                let constrained = constrain_text(
+                    text.clone(),
+                    cursor,
+                    max_length,
+                    numeric,
+                    mask.as_ref(),
+                    onvalidate,
+                );
+                if let Some((new_text, new_cursor)) = constrained {
+                    let mut editor = editable.editor_mut().write();
+                    editor.set(&new_text);
+                    editor.set_cursor_pos(new_cursor);
+                    drop(editor);
+                    onchange.call(new_text);
+                }
+            }
+            _ => {}
+        }
+    };
+
     let onkeydown = move |e: Event<KeyboardData>| {
-        if e.data.key != Key::Enter && e.data.key != Key::Tab {
-            e.stop_propagation();
-            editable.process_event(&EditableEvent::KeyDown(e.data));
-            onchange.call(editable.editor().peek().to_string());
+        if e.data.key == Key::Enter || e.data.key == Key::Tab {
+            return;
+        }
+        e.stop_propagation();
+
+        // Increment or decrement numeric inputs with the arrow keys
+        if let Some(numeric) = numeric {
+            let steps = match e.data.key {
+                Key::ArrowUp => Some(1.0),
+                Key::ArrowDown => Some(-1.0),
+                _ => None,
+            };
+            if let Some(steps) = steps {
+                let text = numeric.step_value(&editable.editor().peek().to_string(), steps);
+                let mut editor = editable.editor_mut().write();
+                editor.set(&text);
+                editor.set_cursor_pos(text.chars().count());
+                drop(editor);
+                onchange.call(text);
+                return;
+            }
+        }
+
+        let (prev_text, prev_cursor) = {
+            let editor = editable.editor().peek();
+            (editor.to_string(), editor.cursor_pos())
+        };
+
+        editable.process_event(&EditableEvent::KeyDown(e.data));
+
+        let (text, cursor) = {
+            let editor = editable.editor().peek();
+            (editor.to_string(), editor.cursor_pos())
+        };
+
+        if text == prev_text {
+            return;
+        }
+
+        let constrained = constrain_text(
+            text.clone(),
+            cursor,
+            max_length,
+            numeric,
+            mask.as_ref(),
+            onvalidate,
+        );
+
+        match constrained {
+            Some((new_text, new_cursor)) => {
+                if new_text != text {
+                    let mut editor = editable.editor_mut().write();
+                    editor.set(&new_text);
+                    editor.set_cursor_pos(new_cursor);
+                }
+                onchange.call(new_text);
+            }
+            None => {
+                // Discard the edit
+                let mut editor = editable.editor_mut().write();
+                editor.set(&prev_text);
+                editor.set_cursor_pos(prev_cursor);
+            }
         }
     };
 
@@ -181,22 +521,32 @@ pub fn Input(
     } else {
         (theme.background, "none".to_string())
     };
+    let is_error = error || numeric.is_some_and(|numeric| !numeric.in_range(&value));
     let InputTheme {
         border_fill,
+        error_border_fill,
         width,
         margin,
         corner_radius,
         font_theme,
         placeholder_font_theme,
+        error_font_theme,
         shadow,
         ..
     } = theme;
 
     let color = if display_placeholder {
         placeholder_font_theme.color
+    } else if is_error {
+        error_font_theme.color
     } else {
         font_theme.color
     };
+    let border_fill = if is_error {
+        error_border_fill
+    } else {
+        border_fill
+    };
 
     let text = match (mode, placeholder) {
         (_, Some(placeholder)) if display_placeholder => placeholder,
@@ -220,6 +570,7 @@ pub fn Input(
             a11y_role: "textInput",
             a11y_auto_focus: "{auto_focus}",
             onkeydown,
+            ona11yaction,
             onkeyup,
             paragraph {
                 margin: "8 12",
@@ -243,6 +594,40 @@ pub fn Input(
     )
 }
 
+/// Apply the constraints of an [`Input`] to an edited text.
+/// Returns `None` if the edit must be discarded.
+fn constrain_text(
+    text: String,
+    cursor: usize,
+    max_length: Option<usize>,
+    numeric: Option<InputNumeric>,
+    mask: Option<&InputMask>,
+    onvalidate: Option<EventHandler<InputValidator>>,
+) -> Option<(String, usize)> {
+    let (text, cursor) = match mask {
+        Some(mask) => mask.apply(&text, cursor),
+        None => (text, cursor),
+    };
+
+    if max_length.is_some_and(|max_length| text.chars().count() > max_length) {
+        return None;
+    }
+
+    if numeric.is_some_and(|numeric| !numeric.accepts(&text)) {
+        return None;
+    }
+
+    if let Some(onvalidate) = onvalidate {
+        let validator = InputValidator::new(text.clone());
+        onvalidate.call(validator.clone());
+        if !validator.is_valid() {
+            return None;
+        }
+    }
+
+    Some((text, cursor))
+}
+
 #[cfg(test)]
 mod test {
     use freya::prelude::*;
@@ -294,5 +679,106 @@ mod test {
 
         // Check that "d" has been written into the input.
         assert_eq!(text.get(0).text(), Some("Hello, World"));
+
+        // Replace the text with an assistive technology
+        let a11y_id = root.get(0).state().accessibility.a11y_id.unwrap();
+        utils
+            .request_a11y_action(a11y_id, AccessibilityAction::SetValue("Hi".to_string()))
+            .await;
+
+        assert_eq!(text.get(0).text(), Some("Hi"));
+    }
+
+    #[tokio::test]
+    pub async fn input_constraints() {
+        fn input_app() -> Element {
+            let mut value = use_signal(|| "12".to_string());
+
+            rsx!(Input {
+                value: value.read().clone(),
+                max_length: 3,
+                numeric: InputNumeric::new().with_max(200.0).with_step(50.0),
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            },)
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let text = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Focus the input in the end of the text
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (115., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Letters are discarded
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("a".to_string()),
+            code: Code::KeyA,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(text.get(0).text(), Some("12"));
+
+        // Numbers are accepted
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("3".to_string()),
+            code: Code::Digit3,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(text.get(0).text(), Some("123"));
+
+        // The max length is respected
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("4".to_string()),
+            code: Code::Digit4,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(text.get(0).text(), Some("123"));
+
+        // Increment with the arrow keys, clamped to the max
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::ArrowUp,
+            code: Code::ArrowUp,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(text.get(0).text(), Some("173"));
+
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::ArrowUp,
+            code: Code::ArrowUp,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(text.get(0).text(), Some("200"));
+    }
+
+    #[test]
+    pub fn input_mask() {
+        let mask = InputMask::phone();
+
+        assert_eq!(mask.apply("5", 1), ("(5".to_string(), 2));
+        assert_eq!(mask.apply("(555)1", 6), ("(555) 1".to_string(), 7));
+        assert_eq!(
+            mask.apply("55512345678", 11),
+            ("(555) 123-4567".to_string(), 14)
+        );
+        assert_eq!(InputMask::date().apply("1a2", 3), ("12".to_string(), 2));
     }
 }
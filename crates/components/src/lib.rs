@@ -29,14 +29,17 @@ mod scroll_views;
 mod sidebar;
 mod slider;
 mod snackbar;
+mod spatial_navigation;
 mod svg;
 mod switch;
 mod table;
 mod tabs;
+mod text_area;
 mod theme;
 mod tile;
 mod tooltip;
 mod tree;
+mod virtual_editor;
 mod window_drag_area;
 
 pub use accordion::*;
@@ -67,11 +70,14 @@ pub use scroll_views::*;
 pub use sidebar::*;
 pub use slider::*;
 pub use snackbar::*;
+pub use spatial_navigation::*;
 pub use switch::*;
 pub use table::*;
 pub use tabs::*;
+pub use text_area::*;
 pub use theme::*;
 pub use tile::*;
 pub use tooltip::*;
 pub use tree::*;
+pub use virtual_editor::*;
 pub use window_drag_area::*;
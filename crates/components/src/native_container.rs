@@ -1,8 +1,18 @@
 use dioxus::prelude::*;
-use freya_core::prelude::EventMessage;
+use freya_core::prelude::{
+    AccessibilityFocusStrategy,
+    EventMessage,
+    EventName,
+    PlatformEvent,
+};
 use freya_elements::{
     elements as dioxus_elements,
-    events::KeyboardEvent,
+    events::{
+        GamepadButton,
+        GamepadEvent,
+        KeyboardEvent,
+        PreventDefault,
+    },
 };
 use freya_hooks::{
     use_init_native_platform,
@@ -16,8 +26,15 @@ pub fn NativeContainer(children: Element) -> Element {
     let platform = use_platform();
 
     let onglobalkeydown = move |e: KeyboardEvent| {
+        // Shortcuts have priority over the focus navigation
+        let used_by_shortcut = native_platform.shortcuts.process_keydown(&e);
+
         let allowed_to_navigate = native_platform.navigation_mark.peek().allowed();
-        if e.key == Key::Tab && allowed_to_navigate {
+        if e.key == Key::Tab
+            && allowed_to_navigate
+            && !used_by_shortcut
+            && !e.is_default_prevented()
+        {
             if e.modifiers.contains(Modifiers::SHIFT) {
                 platform
                     .send(EventMessage::FocusPrevAccessibilityNode)
@@ -32,10 +49,68 @@ pub fn NativeContainer(children: Element) -> Element {
         }
     };
 
+    let onglobalgamepadbuttondown = move |e: GamepadEvent| {
+        native_platform.gamepad.process_button_down(&e);
+
+        match e.get_button() {
+            // The D-pad moves the focus spatially
+            Some(
+                button @ (GamepadButton::DPadUp
+                | GamepadButton::DPadDown
+                | GamepadButton::DPadLeft
+                | GamepadButton::DPadRight),
+            ) => {
+                let strategy = match button {
+                    GamepadButton::DPadUp => AccessibilityFocusStrategy::Up,
+                    GamepadButton::DPadDown => AccessibilityFocusStrategy::Down,
+                    GamepadButton::DPadLeft => AccessibilityFocusStrategy::Left,
+                    _ => AccessibilityFocusStrategy::Right,
+                };
+                platform
+                    .send(EventMessage::FocusAccessibilityNodeWithStrategy(strategy))
+                    .unwrap();
+            }
+            // The south button presses the focused element
+            Some(GamepadButton::South) => {
+                platform
+                    .send(EventMessage::PlatformEvent(PlatformEvent::Keyboard {
+                        name: EventName::KeyDown,
+                        key: Key::Enter,
+                        code: Code::Enter,
+                        modifiers: Modifiers::empty(),
+                    }))
+                    .unwrap();
+            }
+            _ => {}
+        }
+    };
+
+    let onglobalgamepadbuttonup = move |e: GamepadEvent| {
+        native_platform.gamepad.process_button_up(&e);
+
+        if e.get_button() == Some(GamepadButton::South) {
+            platform
+                .send(EventMessage::PlatformEvent(PlatformEvent::Keyboard {
+                    name: EventName::KeyUp,
+                    key: Key::Enter,
+                    code: Code::Enter,
+                    modifiers: Modifiers::empty(),
+                }))
+                .unwrap();
+        }
+    };
+
+    let onglobalgamepadaxis = move |e: GamepadEvent| {
+        native_platform.gamepad.process_axis(&e);
+    };
+
     rsx!(rect {
         width: "100%",
         height: "100%",
         onglobalkeydown,
+        onglobalgamepadbuttondown,
+        onglobalgamepadbuttonup,
+        onglobalgamepadaxis,
         {children}
     })
 }
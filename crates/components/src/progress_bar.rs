@@ -52,6 +52,10 @@ pub fn ProgressBar(
             width: "{width}",
             height: "{height}",
             padding: "2",
+            a11y_role: "progressIndicator",
+            a11y_numeric_value: "{progress}",
+            a11y_min_numeric_value: "0",
+            a11y_max_numeric_value: "100",
             rect {
                 corner_radius: "999",
                 width: "100%",
@@ -83,6 +83,8 @@ pub fn Radio(
             corner_radius: "99",
             rect {
                 a11y_id: focus.attribute(),
+                a11y_role: "radioButton",
+                a11y_checked: "{selected}",
                 width: "18",
                 height: "18",
                 border: "2 solid {fill}",
@@ -150,6 +150,30 @@ pub fn get_corrected_scroll_position(
     }
 }
 
+/// Get the scroll position that makes the target visible in the viewport,
+/// `target_start` and `viewport_start` are relative to the same origin.
+#[doc(hidden)]
+pub fn get_scroll_position_into_view(
+    target_start: f32,
+    target_size: f32,
+    viewport_start: f32,
+    viewport_size: f32,
+    scroll_position: f32,
+) -> f32 {
+    let target_end = target_start + target_size;
+    let viewport_end = viewport_start + viewport_size;
+
+    if target_start < viewport_start || target_size > viewport_size {
+        // Align the start of the target with the start of the viewport
+        scroll_position + (viewport_start - target_start)
+    } else if target_end > viewport_end {
+        // Align the end of the target with the end of the viewport
+        scroll_position - (target_end - viewport_end)
+    } else {
+        scroll_position
+    }
+}
+
 pub fn manage_key_event(
     e: KeyboardEvent,
     (mut x, mut y): (f32, f32),
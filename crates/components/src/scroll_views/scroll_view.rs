@@ -3,6 +3,8 @@ use freya_elements::{
     elements as dioxus_elements,
     events::{
         keyboard::Key,
+        AccessibilityAction,
+        AccessibilityActionEvent,
         KeyboardEvent,
         MouseEvent,
         WheelEvent,
@@ -21,6 +23,7 @@ use crate::{
     get_corrected_scroll_position,
     get_scroll_position_from_cursor,
     get_scroll_position_from_wheel,
+    get_scroll_position_into_view,
     get_scrollbar_pos_and_size,
     is_scrollbar_visible,
     manage_key_event,
@@ -327,6 +330,40 @@ pub fn ScrollView(
         }
     };
 
+    // Scroll the target of the action into view, it keeps propagating so outer scroll views can do the same
+    let ona11yaction = move |e: AccessibilityActionEvent| {
+        if e.get_action() != &AccessibilityAction::ScrollIntoView {
+            return;
+        }
+        let target_area = e.get_target_area();
+
+        let scroll_position_y = get_scroll_position_into_view(
+            target_area.min_y(),
+            target_area.height(),
+            size.area.min_y(),
+            size.area.height(),
+            corrected_scrolled_y,
+        );
+        let scroll_position_x = get_scroll_position_into_view(
+            target_area.min_x(),
+            target_area.width(),
+            size.area.min_x(),
+            size.area.width(),
+            corrected_scrolled_x,
+        );
+
+        scrolled_y.set(get_corrected_scroll_position(
+            size.inner.height,
+            size.area.height(),
+            scroll_position_y,
+        ) as i32);
+        scrolled_x.set(get_corrected_scroll_position(
+            size.inner.width,
+            size.area.width(),
+            scroll_position_x,
+        ) as i32);
+    };
+
     // Mark the Y axis scrollbar as the one being dragged
     let onmousedown_y = move |e: MouseEvent| {
         let coordinates = e.get_element_coordinates();
@@ -381,6 +418,7 @@ pub fn ScrollView(
             onglobalmousemove: onmousemove,
             onglobalkeydown,
             onglobalkeyup,
+            ona11yaction,
             a11y_id,
             rect {
                 direction: "vertical",
@@ -490,6 +528,54 @@ mod test {
         assert!(content.get(3).is_visible()); // 4. 600 -> 800, 800 > 300
     }
 
+    #[tokio::test]
+    pub async fn scroll_view_scroll_into_view() {
+        fn scroll_view_scroll_into_view_app() -> Element {
+            let focus = use_focus();
+
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "200",
+                        width: "200",
+                    },
+                    rect {
+                        height: "200",
+                        width: "200",
+                    },
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        a11y_id: focus.attribute(),
+                        height: "200",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_scroll_into_view_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        assert!(content.get(0).is_visible());
+        assert!(!content.get(3).is_visible());
+
+        // Scroll the last item into view with an assistive technology
+        let a11y_id = content.get(3).state().accessibility.a11y_id.unwrap();
+        utils
+            .request_a11y_action(a11y_id, AccessibilityAction::ScrollIntoView)
+            .await;
+
+        // Scrolled 300 pixels, just enough to show the last item
+        assert!(!content.get(0).is_visible());
+        assert!(content.get(3).is_visible());
+        assert_eq!(content.get(3).area().unwrap().max_y(), 500.);
+    }
+
     #[tokio::test]
     pub async fn scroll_view_scrollbar() {
         fn scroll_view_scrollbar_app() -> Element {
@@ -2,6 +2,8 @@ use dioxus::prelude::*;
 use freya_elements::{
     elements as dioxus_elements,
     events::{
+        AccessibilityAction,
+        AccessibilityActionEvent,
         MouseEvent,
         WheelEvent,
     },
@@ -162,6 +164,18 @@ pub fn Slider(
         onmoved.call(percentage);
     };
 
+    let ona11yaction = move |e: AccessibilityActionEvent| {
+        let percentage = match e.get_action() {
+            AccessibilityAction::Increment => value + 5.0,
+            AccessibilityAction::Decrement => value - 5.0,
+            AccessibilityAction::SetNumericValue(value) => *value,
+            _ => return,
+        };
+        e.stop_propagation();
+
+        onmoved.call(percentage.clamp(0.0, 100.0));
+    };
+
     let inner_width = (size.area.width() - 15.0) * (value / 100.0) as f32;
     let border = if focus.is_selected() {
         format!("2 solid {}", theme.border_fill)
@@ -177,6 +191,12 @@ pub fn Slider(
             onmousedown,
             onglobalclick: onclick,
             a11y_id,
+            a11y_role: "slider",
+            a11y_numeric_value: "{value}",
+            a11y_min_numeric_value: "0",
+            a11y_max_numeric_value: "100",
+            a11y_numeric_value_step: "5",
+            ona11yaction,
             onmouseenter,
             onglobalmousemove: onmousemove,
             onmouseleave,
@@ -252,6 +272,11 @@ mod test {
 
         assert_eq!(label.get(0).text(), Some("50"));
 
+        let accessibility = utils.root().get(0).state().accessibility.clone();
+        assert_eq!(accessibility.a11y_numeric_value, Some(50.));
+        assert_eq!(accessibility.a11y_min_numeric_value, Some(0.));
+        assert_eq!(accessibility.a11y_max_numeric_value, Some(100.));
+
         utils.push_event(PlatformEvent::Mouse {
             name: EventName::MouseMove,
             cursor: (250.0, 7.0).into(),
@@ -270,5 +295,23 @@ mod test {
         utils.wait_for_update().await;
 
         assert_eq!(label.get(0).text(), Some("100"));
+        assert_eq!(
+            utils.root().get(0).state().accessibility.a11y_numeric_value,
+            Some(100.)
+        );
+
+        // Move it with an assistive technology
+        let a11y_id = utils.root().get(0).state().accessibility.a11y_id.unwrap();
+        utils
+            .request_a11y_action(a11y_id, AccessibilityAction::Decrement)
+            .await;
+
+        assert_eq!(label.get(0).text(), Some("95"));
+
+        utils
+            .request_a11y_action(a11y_id, AccessibilityAction::SetNumericValue(20.))
+            .await;
+
+        assert_eq!(label.get(0).text(), Some("20"));
     }
 }
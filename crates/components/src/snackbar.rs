@@ -100,6 +100,8 @@ pub fn SnackBarBox(children: Element, theme: Option<SnackBarThemeWith>) -> Eleme
             padding: "10",
             color: "{color}",
             direction: "horizontal",
+            a11y_role: "status",
+            a11y_live: "polite",
             {children}
         }
     )
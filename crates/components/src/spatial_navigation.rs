@@ -0,0 +1,250 @@
+use dioxus::prelude::*;
+use freya_core::prelude::{
+    AccessibilityFocusStrategy,
+    EventMessage,
+};
+use freya_elements::{
+    elements as dioxus_elements,
+    events::{
+        KeyboardEvent,
+        PreventDefault,
+    },
+};
+use freya_hooks::use_platform;
+
+/// Properties for the [`SpatialNavigation`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct SpatialNavigationProps {
+    /// Inner children for the SpatialNavigation.
+    children: Element,
+    /// Width of the container. Default `auto`.
+    #[props(default = "auto".to_string(), into)]
+    width: String,
+    /// Height of the container. Default `auto`.
+    #[props(default = "auto".to_string(), into)]
+    height: String,
+    /// Direction of the container. Default `vertical`.
+    #[props(default = "vertical".to_string(), into)]
+    direction: String,
+    /// Keep the focus inside this container, useful for dialogs. Default `false`.
+    #[props(default = false)]
+    trap_focus: bool,
+}
+
+/// Move the focus with the arrow keys to the closest focusable element in that direction,
+/// based on the layout. Only applies while the focus is inside this container.
+///
+/// Elements inside can keep the arrow keys for themselves with `prevent_default` in their `onkeydown`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         SpatialNavigation {
+///             direction: "horizontal",
+///             Button {
+///                 label { "Left" }
+///             }
+///             Button {
+///                 label { "Right" }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn SpatialNavigation(
+    SpatialNavigationProps {
+        children,
+        width,
+        height,
+        direction,
+        trap_focus,
+    }: SpatialNavigationProps,
+) -> Element {
+    let platform = use_platform();
+
+    let onkeydown = move |e: KeyboardEvent| {
+        if e.is_default_prevented() {
+            return;
+        }
+
+        let strategy = match e.key {
+            Key::ArrowUp => AccessibilityFocusStrategy::Up,
+            Key::ArrowDown => AccessibilityFocusStrategy::Down,
+            Key::ArrowLeft => AccessibilityFocusStrategy::Left,
+            Key::ArrowRight => AccessibilityFocusStrategy::Right,
+            _ => return,
+        };
+
+        // Outer containers must not move the focus again
+        e.stop_propagation();
+
+        platform
+            .send(EventMessage::FocusAccessibilityNodeWithStrategy(strategy))
+            .ok();
+    };
+
+    rsx!(
+        rect {
+            width,
+            height,
+            direction,
+            a11y_focus_trap: "{trap_focus}",
+            onkeydown,
+            {children}
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    fn key_down(key: Key, code: Code) -> PlatformEvent {
+        PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key,
+            code,
+            modifiers: Modifiers::default(),
+        }
+    }
+
+    #[tokio::test]
+    pub async fn spatial_navigation() {
+        #[allow(non_snake_case)]
+        #[component]
+        fn Cell(name: String) -> Element {
+            let mut focus = use_focus();
+
+            rsx!(
+                rect {
+                    a11y_id: focus.attribute(),
+                    width: "50",
+                    height: "50",
+                    onclick: move |_| focus.focus(),
+                    label {
+                        "{name} {focus.is_focused()}"
+                    }
+                }
+            )
+        }
+
+        fn spatial_navigation_app() -> Element {
+            rsx!(
+                SpatialNavigation {
+                    direction: "horizontal",
+                    SpatialNavigation {
+                        Cell { name: "a" }
+                        Cell { name: "c" }
+                    }
+                    SpatialNavigation {
+                        Cell { name: "b" }
+                        Cell { name: "d" }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(spatial_navigation_app);
+
+        let root = utils.root().get(0);
+        let cell = |column: usize, row: usize| root.get(column).get(row).get(0).get(0);
+        let is_focused = |column: usize, row: usize| {
+            cell(column, row)
+                .text()
+                .map(|text| text.ends_with("true"))
+                .unwrap_or_default()
+        };
+
+        // Focus `a`
+        utils.click_cursor((5., 5.)).await;
+        utils.wait_for_update().await;
+        assert!(is_focused(0, 0));
+
+        // `a` -> `b`
+        utils.push_event(key_down(Key::ArrowRight, Code::ArrowRight));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert!(is_focused(1, 0));
+
+        // Nothing to the right of `b`
+        utils.push_event(key_down(Key::ArrowRight, Code::ArrowRight));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert!(is_focused(1, 0));
+
+        // `b` -> `d`
+        utils.push_event(key_down(Key::ArrowDown, Code::ArrowDown));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert!(is_focused(1, 1));
+
+        // `d` -> `c`
+        utils.push_event(key_down(Key::ArrowLeft, Code::ArrowLeft));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert!(is_focused(0, 1));
+    }
+
+    #[tokio::test]
+    pub async fn focus_trap() {
+        #[allow(non_snake_case)]
+        fn Item() -> Element {
+            let mut focus = use_focus();
+
+            rsx!(
+                rect {
+                    a11y_id: focus.attribute(),
+                    width: "50",
+                    height: "50",
+                    label {
+                        "{focus.is_focused()}"
+                    }
+                }
+            )
+        }
+
+        fn focus_trap_app() -> Element {
+            rsx!(
+                Item {}
+                SpatialNavigation {
+                    trap_focus: true,
+                    Item {}
+                    Item {}
+                }
+            )
+        }
+
+        let mut utils = launch_test(focus_trap_app);
+
+        let root = utils.root();
+        let is_focused = |item: &TestNode| item.get(0).get(0).text() == Some("true");
+        let outside = root.get(0);
+        let first = root.get(1).get(0);
+        let second = root.get(1).get(1);
+
+        let tab = || key_down(Key::Tab, Code::Tab);
+
+        // The focus goes straight into the trap
+        utils.push_event(tab());
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert!(is_focused(&first));
+
+        utils.push_event(tab());
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert!(is_focused(&second));
+
+        // And it never leaves it
+        utils.push_event(tab());
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert!(is_focused(&first));
+        assert!(!is_focused(&outside));
+    }
+}
@@ -172,6 +172,8 @@ pub fn Switch(props: SwitchProps) -> Element {
             onglobalkeydown,
             onclick,
             a11y_id,
+            a11y_role: "switch",
+            a11y_checked: "{props.enabled}",
             offset_x: "{offset_x}",
             main_align: "center",
             rect {
@@ -215,11 +217,19 @@ mod test {
 
         // Default is false
         assert_eq!(label.get(0).text(), Some("false"));
+        assert_eq!(
+            utils.root().get(0).state().accessibility.a11y_checked,
+            Some(Toggled::False)
+        );
 
         utils.click_cursor((15., 15.)).await;
 
         // Check if after clicking it is now enabled
         assert_eq!(label.get(0).text(), Some("true"));
+        assert_eq!(
+            utils.root().get(0).state().accessibility.a11y_checked,
+            Some(Toggled::True)
+        );
 
         utils.click_cursor((15., 15.)).await;
 
@@ -144,6 +144,7 @@ pub fn Tab(
             height: "{height}",
             overflow: "clip",
             a11y_role:"tab",
+            a11y_selected: "{is_active}",
             color: "{font_theme.color}",
             background: "{background}",
             text_align: "center",
@@ -255,6 +256,7 @@ pub fn BottomTab(children: Element, theme: Option<BottomTabThemeWith>) -> Elemen
             height: "{height}",
             overflow: "clip",
             a11y_role:"tab",
+            a11y_selected: "{is_active}",
             color: "{font_theme.color}",
             background: "{background}",
             text_align: "center",
@@ -0,0 +1,452 @@
+use dioxus::prelude::*;
+use freya_common::NodeReferenceLayout;
+use freya_elements::{
+    elements as dioxus_elements,
+    events::{
+        keyboard::Key,
+        KeyboardData,
+        MouseEvent,
+        WheelEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_editable,
+    use_focus,
+    use_node_signal,
+    use_platform,
+    EditableConfig,
+    EditableEvent,
+    EditableMode,
+    TextAreaTheme,
+    TextAreaThemeWith,
+    TextEditor,
+    UseEditable,
+};
+use winit::window::CursorIcon;
+
+use crate::{
+    get_corrected_scroll_position,
+    get_scroll_position_from_wheel,
+};
+
+/// Indicates the current status of the TextArea.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum TextAreaStatus {
+    /// Default state.
+    #[default]
+    Idle,
+    /// Mouse is hovering the text area.
+    Hovering,
+}
+
+/// Properties for the [`TextArea`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct TextAreaProps {
+    /// Theme override.
+    pub theme: Option<TextAreaThemeWith>,
+    /// Text to show for when there is no value
+    pub placeholder: Option<String>,
+    /// Current value of the TextArea
+    pub value: String,
+    /// Handler for the `onchange` event.
+    pub onchange: EventHandler<String>,
+    /// Show a gutter with the line numbers. Default `false`.
+    #[props(default = false)]
+    pub show_line_numbers: bool,
+    /// Automatically focus this TextArea upon creation. Default `false`.
+    #[props(default = false)]
+    pub auto_focus: bool,
+}
+
+/// Multi-line box to edit text.
+///
+/// It grows with its content until the `max_height` of its theme, from there on it scrolls
+/// and keeps the cursor in view while typing.
+///
+/// # Styling
+/// Inherits the [`TextAreaTheme`](freya_hooks::TextAreaTheme) theme.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut value = use_signal(String::new);
+///
+///     rsx!(TextArea {
+///         value: value.read().clone(),
+///         show_line_numbers: true,
+///         onchange: move |e| { value.set(e) }
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn TextArea(
+    TextAreaProps {
+        theme,
+        placeholder,
+        value,
+        onchange,
+        show_line_numbers,
+        auto_focus,
+    }: TextAreaProps,
+) -> Element {
+    let platform = use_platform();
+    let mut status = use_signal(TextAreaStatus::default);
+    let mut editable = use_editable(
+        || EditableConfig::new(value.to_string()),
+        EditableMode::SingleLineMultipleEditors,
+    );
+    let theme = use_applied_theme!(&theme, text_area);
+    let mut focus = use_focus();
+    let mut scroll_y = use_signal(|| 0);
+    let mut follow_cursor = use_signal(|| false);
+    let (viewport_ref, viewport) = use_node_signal();
+
+    let is_focused = focus.is_focused();
+    let display_placeholder = value.is_empty() && placeholder.is_some() && !is_focused;
+    let placeholder = placeholder.unwrap_or_default();
+
+    if &value != editable.editor().read().rope() {
+        editable.editor_mut().write().set(&value);
+    }
+
+    use_drop(move || {
+        if *status.peek() == TextAreaStatus::Hovering {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let onkeydown = move |e: Event<KeyboardData>| {
+        if e.data.key != Key::Tab {
+            e.stop_propagation();
+            editable.process_event(&EditableEvent::KeyDown(e.data));
+            follow_cursor.set(true);
+            onchange.call(editable.editor().peek().to_string());
+        }
+    };
+
+    let onkeyup = move |e: Event<KeyboardData>| {
+        e.stop_propagation();
+        editable.process_event(&EditableEvent::KeyUp(e.data));
+    };
+
+    let onwheel = move |e: WheelEvent| {
+        let viewport = viewport.peek();
+        let corrected_scroll_y = get_corrected_scroll_position(
+            viewport.inner.height,
+            viewport.area.height(),
+            *scroll_y.peek() as f32,
+        );
+        let new_scroll_y = get_scroll_position_from_wheel(
+            e.get_delta_y() as f32,
+            viewport.inner.height,
+            viewport.area.height(),
+            corrected_scroll_y,
+        );
+
+        // Only scroll when there is still area to scroll
+        if *scroll_y.peek() != new_scroll_y {
+            e.stop_propagation();
+            scroll_y.set(new_scroll_y);
+        }
+    };
+
+    let onmousedown = move |_: MouseEvent| {
+        focus.focus();
+    };
+
+    let onmouseenter = move |_| {
+        platform.set_cursor(CursorIcon::Text);
+        *status.write() = TextAreaStatus::Hovering;
+    };
+
+    let onmouseleave = move |_| {
+        platform.set_cursor(CursorIcon::default());
+        *status.write() = TextAreaStatus::default();
+    };
+
+    let onglobalclick = move |_| match *status.read() {
+        TextAreaStatus::Idle if focus.is_focused() => {
+            focus.unfocus();
+            editable.process_event(&EditableEvent::Click);
+        }
+        TextAreaStatus::Hovering => {
+            editable.process_event(&EditableEvent::Click);
+        }
+        _ => {}
+    };
+
+    let a11y_id = focus.attribute();
+    let cursor_reference = editable.cursor_attr();
+
+    let TextAreaTheme {
+        background,
+        hover_background,
+        border_fill,
+        focus_border_fill,
+        shadow,
+        width,
+        min_height,
+        max_height,
+        margin,
+        padding,
+        corner_radius,
+        line_numbers_background,
+        font_theme,
+        placeholder_font_theme,
+        line_numbers_font_theme,
+    } = theme;
+
+    let background = if is_focused || *status.read() == TextAreaStatus::Hovering {
+        hover_background
+    } else {
+        background
+    };
+    let border_fill = if is_focused {
+        focus_border_fill
+    } else {
+        border_fill
+    };
+
+    let corrected_scroll_y = {
+        let viewport = viewport.read();
+        get_corrected_scroll_position(
+            viewport.inner.height,
+            viewport.area.height(),
+            *scroll_y.read() as f32,
+        )
+    };
+
+    let len_lines = editable.editor().read().len_lines();
+    let gutter_width = len_lines.to_string().len() * 10 + 12;
+
+    rsx!(
+        rect {
+            width: "{width}",
+            color: "{font_theme.color}",
+            background: "{background}",
+            border: "1 solid {border_fill}",
+            shadow: "{shadow}",
+            corner_radius: "{corner_radius}",
+            margin: "{margin}",
+            cursor_reference,
+            a11y_id,
+            a11y_role: "multilineTextInput",
+            a11y_auto_focus: "{auto_focus}",
+            onkeydown,
+            onkeyup,
+            rect {
+                reference: viewport_ref,
+                width: "100%",
+                min_height: "{min_height}",
+                max_height: "{max_height}",
+                overflow: "clip",
+                padding: "{padding}",
+                offset_y: "{corrected_scroll_y}",
+                onglobalclick,
+                onmouseenter,
+                onmouseleave,
+                onmousedown,
+                onwheel,
+                if display_placeholder {
+                    label {
+                        color: "{placeholder_font_theme.color}",
+                        "{placeholder}"
+                    }
+                } else {
+                    for line_index in 0..len_lines {
+                        TextAreaLine {
+                            key: "{line_index}",
+                            line_index,
+                            editable,
+                            is_focused,
+                            show_line_numbers,
+                            gutter_width,
+                            color: font_theme.color.to_string(),
+                            line_numbers_color: line_numbers_font_theme.color.to_string(),
+                            line_numbers_background: line_numbers_background.to_string(),
+                            follow_cursor,
+                            scroll_y,
+                            viewport,
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn TextAreaLine(
+    line_index: usize,
+    editable: UseEditable,
+    is_focused: bool,
+    show_line_numbers: bool,
+    gutter_width: usize,
+    color: String,
+    line_numbers_color: String,
+    line_numbers_background: String,
+    follow_cursor: Signal<bool>,
+    scroll_y: Signal<i32>,
+    viewport: ReadOnlySignal<NodeReferenceLayout>,
+) -> Element {
+    let mut editable = editable;
+    let mut follow_cursor = follow_cursor;
+    let mut scroll_y = scroll_y;
+    let (line_ref, line_layout) = use_node_signal();
+
+    // Scroll this line into view if the cursor was moved into it
+    use_effect(move || {
+        let line_layout = line_layout.read();
+        if !*follow_cursor.read()
+            || editable.editor().read().cursor_row() != line_index
+            || line_layout.area.height() == 0.
+        {
+            return;
+        }
+
+        let viewport = viewport.peek();
+        let corrected_scroll_y = get_corrected_scroll_position(
+            viewport.inner.height,
+            viewport.area.height(),
+            *scroll_y.peek() as f32,
+        );
+        let (line_area, viewport_area) = (line_layout.area, viewport.area);
+
+        if line_area.min_y() < viewport_area.min_y() {
+            scroll_y.set((corrected_scroll_y + viewport_area.min_y() - line_area.min_y()) as i32);
+        } else if line_area.max_y() > viewport_area.max_y() {
+            scroll_y.set((corrected_scroll_y - line_area.max_y() + viewport_area.max_y()) as i32);
+        }
+
+        follow_cursor.set(false);
+    });
+
+    let editor = editable.editor().read();
+    let Some(line) = editor.line(line_index) else {
+        return None;
+    };
+    let text = line.text.trim_end_matches(['\n', '\r']);
+
+    // Only show the cursor in the active line
+    let cursor_index = if is_focused && editor.cursor_row() == line_index {
+        editor.visible_cursor_col().to_string()
+    } else {
+        "none".to_string()
+    };
+
+    let highlights = editable.highlights_attr(line_index);
+
+    let onmousedown = move |e: MouseEvent| {
+        editable.process_event(&EditableEvent::MouseDown(e.data, line_index));
+    };
+
+    let onmousemove = move |e: MouseEvent| {
+        editable.process_event(&EditableEvent::MouseMove(e.data, line_index));
+    };
+
+    rsx!(
+        rect {
+            reference: line_ref,
+            width: "100%",
+            direction: "horizontal",
+            if show_line_numbers {
+                rect {
+                    width: "{gutter_width}",
+                    height: "fill",
+                    background: "{line_numbers_background}",
+                    padding: "0 6",
+                    label {
+                        width: "100%",
+                        text_align: "right",
+                        color: "{line_numbers_color}",
+                        "{line_index + 1}"
+                    }
+                }
+            }
+            paragraph {
+                width: "fill",
+                margin: "0 0 0 6",
+                cursor_id: "{line_index}",
+                cursor_index: "{cursor_index}",
+                cursor_mode: "editable",
+                cursor_color: "{color}",
+                highlights,
+                onmousedown,
+                onmousemove,
+                text {
+                    "{text}"
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn text_area() {
+        fn text_area_app() -> Element {
+            let mut value = use_signal(|| "Hello\nWorl".to_string());
+
+            rsx!(TextArea {
+                value: value.read().clone(),
+                show_line_numbers: true,
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            })
+        }
+
+        let mut utils = launch_test(text_area_app);
+        let root = utils.root();
+        let content = root.get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Every line is rendered separately, with its line number
+        assert_eq!(content.get(0).get(0).get(0).get(0).text(), Some("1"));
+        assert_eq!(content.get(0).get(1).get(0).get(0).text(), Some("Hello"));
+        assert_eq!(content.get(1).get(0).get(0).get(0).text(), Some("2"));
+        assert_eq!(content.get(1).get(1).get(0).get(0).text(), Some("Worl"));
+
+        // Focus the text area in the end of the second line
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (250., 35.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_ne!(utils.focus_id(), ACCESSIBILITY_ROOT_ID);
+
+        // Write "d"
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("d".to_string()),
+            code: Code::KeyD,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(content.get(1).get(1).get(0).get(0).text(), Some("World"));
+
+        // Break the line
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Enter,
+            code: Code::Enter,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(content.children_ids().len(), 3);
+    }
+}
@@ -0,0 +1,298 @@
+use dioxus::prelude::*;
+use freya_elements::{
+    elements as dioxus_elements,
+    events::{
+        keyboard::Key,
+        KeyboardEvent,
+        MouseEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_focus,
+    use_node_signal,
+    EditableEvent,
+    TextAreaThemeWith,
+    TextEditor,
+    UseEditable,
+    UseEditableSearch,
+};
+
+use crate::{
+    get_corrected_scroll_position,
+    use_scroll_controller,
+    ScrollConfig,
+    VirtualScrollView,
+};
+
+/// Properties for the [`VirtualEditor`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct VirtualEditorProps {
+    /// Editable content, created with [`use_editable`](freya_hooks::use_editable) in
+    /// [`EditableMode::SingleLineMultipleEditors`](freya_hooks::EditableMode::SingleLineMultipleEditors) mode.
+    pub editable: UseEditable,
+    /// Theme override, it shares the theme with the [`TextArea`](crate::TextArea).
+    pub theme: Option<TextAreaThemeWith>,
+    /// Width of the VirtualEditor. Default to `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the VirtualEditor. Default to `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Height of every line. Default to `25`.
+    #[props(default = 25.0)]
+    pub line_height: f32,
+    /// Show a gutter with the line numbers. Default `false`.
+    #[props(default = false)]
+    pub show_line_numbers: bool,
+    /// Search created with [`use_editable_search`](freya_hooks::use_editable_search) for the same editable,
+    /// its matches get highlighted.
+    pub search: Option<UseEditableSearch>,
+}
+
+/// Text editor for large documents.
+///
+/// Only the lines that are visible get a `paragraph`, the [`Rope`](freya_hooks::Rope) of the
+/// editable stays as the source of truth and every line uses its index as `cursor_id`,
+/// so cursors and selections are mapped across lines no matter if they are rendered or not.
+///
+/// # Styling
+/// Inherits the [`TextAreaTheme`](freya_hooks::TextAreaTheme) theme.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let editable = use_editable(
+///         || EditableConfig::new("Hello, World!\n".repeat(50000)),
+///         EditableMode::SingleLineMultipleEditors,
+///     );
+///
+///     rsx!(VirtualEditor {
+///         editable,
+///         show_line_numbers: true
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn VirtualEditor(
+    VirtualEditorProps {
+        mut editable,
+        theme,
+        width,
+        height,
+        line_height,
+        show_line_numbers,
+        search,
+    }: VirtualEditorProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, text_area);
+    let mut focus = use_focus();
+    let mut scroll_controller = use_scroll_controller(ScrollConfig::default);
+    let (node_ref, size) = use_node_signal();
+    let cursor_row = use_memo(move || editable.editor().read().cursor_row());
+
+    let len_lines = editable.editor().read().len_lines();
+    let gutter_width = len_lines.to_string().len() * 10 + 12;
+    let is_focused = focus.is_focused();
+
+    // Keep the cursor line in view, no matter if it was moved by the keyboard or by a search
+    use_effect(use_reactive(&line_height, move |line_height| {
+        let cursor_row = *cursor_row.read();
+        let viewport_size = size.peek().area.height();
+        if viewport_size == 0. {
+            return;
+        }
+
+        let (_, scrolled_y) = scroll_controller.into();
+        let inner_size = line_height + line_height * editable.editor().peek().len_lines() as f32;
+        let scrolled_y =
+            -get_corrected_scroll_position(inner_size, viewport_size, *scrolled_y.peek() as f32);
+        let line_start = cursor_row as f32 * line_height;
+        let line_end = line_start + line_height;
+
+        if line_start < scrolled_y {
+            scroll_controller.scroll_to_y(-line_start as i32);
+        } else if line_end > scrolled_y + viewport_size {
+            scroll_controller.scroll_to_y(-(line_end - viewport_size) as i32);
+        }
+    }));
+
+    let onkeydown = move |e: KeyboardEvent| {
+        if e.data.key == Key::Tab {
+            return;
+        }
+        e.stop_propagation();
+        editable.process_event(&EditableEvent::KeyDown(e.data));
+    };
+
+    let onkeyup = move |e: KeyboardEvent| {
+        e.stop_propagation();
+        editable.process_event(&EditableEvent::KeyUp(e.data));
+    };
+
+    let onglobalclick = move |_: MouseEvent| {
+        editable.process_event(&EditableEvent::Click);
+    };
+
+    let a11y_id = focus.attribute();
+    let cursor_reference = editable.cursor_attr();
+    let cursor_color = theme.font_theme.color.to_string();
+    let line_numbers_color = theme.line_numbers_font_theme.color;
+    let line_numbers_background = theme.line_numbers_background;
+
+    rsx!(
+        rect {
+            width: "{width}",
+            height: "{height}",
+            background: "{theme.background}",
+            color: "{theme.font_theme.color}",
+            reference: node_ref,
+            cursor_reference,
+            a11y_id,
+            a11y_role: "multilineTextInput",
+            onkeydown,
+            onkeyup,
+            onglobalclick,
+            VirtualScrollView {
+                length: len_lines,
+                item_size: line_height,
+                scroll_with_arrows: false,
+                cache_elements: false,
+                scroll_controller,
+                builder: move |line_index, _: &Option<()>| {
+                    let editor = editable.editor().read();
+                    let text = editor
+                        .line(line_index)
+                        .map(|line| line.text.trim_end_matches(['\n', '\r']).to_string())
+                        .unwrap_or_default();
+
+                    // Only show the cursor in the active line
+                    let cursor_index = if is_focused && editor.cursor_row() == line_index {
+                        editor.visible_cursor_col().to_string()
+                    } else {
+                        "none".to_string()
+                    };
+
+                    let highlights = match search {
+                        Some(search) => search.highlights_attr(line_index),
+                        None => editable.highlights_attr(line_index),
+                    };
+
+                    let onmousedown = move |e: MouseEvent| {
+                        focus.focus();
+                        editable.process_event(&EditableEvent::MouseDown(e.data, line_index));
+                    };
+
+                    let onmousemove = move |e: MouseEvent| {
+                        editable.process_event(&EditableEvent::MouseMove(e.data, line_index));
+                    };
+
+                    rsx!(
+                        rect {
+                            key: "{line_index}",
+                            width: "100%",
+                            height: "{line_height}",
+                            direction: "horizontal",
+                            if show_line_numbers {
+                                rect {
+                                    width: "{gutter_width}",
+                                    height: "100%",
+                                    main_align: "center",
+                                    padding: "0 6",
+                                    background: "{line_numbers_background}",
+                                    label {
+                                        width: "100%",
+                                        text_align: "right",
+                                        color: "{line_numbers_color}",
+                                        "{line_index + 1}"
+                                    }
+                                }
+                            }
+                            paragraph {
+                                width: "fill",
+                                height: "100%",
+                                main_align: "center",
+                                margin: "0 0 0 6",
+                                max_lines: "1",
+                                cursor_id: "{line_index}",
+                                cursor_index: "{cursor_index}",
+                                cursor_mode: "editable",
+                                cursor_color: "{cursor_color}",
+                                highlights,
+                                onmousedown,
+                                onmousemove,
+                                text {
+                                    "{text}"
+                                }
+                            }
+                        }
+                    )
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn virtual_editor() {
+        fn virtual_editor_app() -> Element {
+            let editable = use_editable(
+                || EditableConfig::new("Hello, World!\n".repeat(1000)),
+                EditableMode::SingleLineMultipleEditors,
+            );
+
+            rsx!(VirtualEditor {
+                editable,
+                line_height: 50.0,
+            })
+        }
+
+        let mut utils = launch_test(virtual_editor_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Only the visible lines are rendered
+        assert_eq!(content.children_ids().len(), 11);
+        assert_eq!(
+            content.get(0).get(1).get(0).get(0).text(),
+            Some("Hello, World!")
+        );
+
+        // Focus the editor in the first line
+        utils.click_cursor((50., 25.)).await;
+        utils.wait_for_update().await;
+
+        // Move the cursor past the visible lines
+        for _ in 0..12 {
+            utils.push_event(PlatformEvent::Keyboard {
+                name: EventName::KeyDown,
+                key: Key::ArrowDown,
+                code: Code::ArrowDown,
+                modifiers: Modifiers::default(),
+            });
+            utils.wait_for_update().await;
+        }
+
+        // Write in the line 13
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("a".to_string()),
+            code: Code::KeyA,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        // The editor has scrolled to keep the cursor line in view
+        assert_eq!(content.children_ids().len(), 11);
+        let cursor_line = content.get(9).get(1).get(0).get(0);
+        assert!(cursor_line.text().unwrap().contains('a'));
+    }
+}
@@ -1,4 +1,10 @@
 mod tree;
+use accesskit::{
+    Action,
+    ActionData,
+    ActionRequest,
+};
+use freya_elements::events::AccessibilityAction;
 use freya_native_core::{
     node::NodeType,
     real_dom::NodeImmutable,
@@ -12,11 +18,48 @@ use crate::{
 };
 
 /// Strategy for the next Accessibility Node to be focused.
-#[derive(PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum AccessibilityFocusStrategy {
+    /// Next Node in the tree order.
     Forward,
+    /// Previous Node in the tree order.
     Backward,
-    // We could add more strategies in the future
+    /// Closest Node above, based on the layout.
+    Up,
+    /// Closest Node below, based on the layout.
+    Down,
+    /// Closest Node to the left, based on the layout.
+    Left,
+    /// Closest Node to the right, based on the layout.
+    Right,
+}
+
+impl AccessibilityFocusStrategy {
+    /// Check if this strategy moves the focus spatially instead of in the tree order.
+    pub fn is_spatial(&self) -> bool {
+        matches!(self, Self::Up | Self::Down | Self::Left | Self::Right)
+    }
+}
+
+/// Map an action requested by an assistive technology to the action sent to the elements.
+/// Returns `None` for the actions that are not sent to the elements, e.g `Focus`.
+pub fn accessibility_action_from_request(request: &ActionRequest) -> Option<AccessibilityAction> {
+    match (request.action, &request.data) {
+        (Action::Default, _) => Some(AccessibilityAction::Default),
+        (Action::Increment, _) => Some(AccessibilityAction::Increment),
+        (Action::Decrement, _) => Some(AccessibilityAction::Decrement),
+        (Action::Expand, _) => Some(AccessibilityAction::Expand),
+        (Action::Collapse, _) => Some(AccessibilityAction::Collapse),
+        (Action::ScrollIntoView, _) => Some(AccessibilityAction::ScrollIntoView),
+        (Action::ShowContextMenu, _) => Some(AccessibilityAction::ShowContextMenu),
+        (Action::SetValue, Some(ActionData::Value(value))) => {
+            Some(AccessibilityAction::SetValue(value.to_string()))
+        }
+        (Action::SetValue, Some(ActionData::NumericValue(value))) => {
+            Some(AccessibilityAction::SetNumericValue(*value))
+        }
+        _ => None,
+    }
 }
 
 /// Shortcut functions to retrieve Acessibility info from a Dioxus Node
@@ -1,6 +1,9 @@
-use std::sync::{
-    Arc,
-    Mutex,
+use std::{
+    cmp::Ordering,
+    sync::{
+        Arc,
+        Mutex,
+    },
 };
 
 use accesskit::{
@@ -24,6 +27,7 @@ use freya_engine::prelude::{
     TextDecorationStyle,
 };
 use freya_native_core::{
+    events::EventName,
     node::NodeType,
     prelude::NodeImmutable,
     tags::TagName,
@@ -42,7 +46,10 @@ use rustc_hash::{
     FxHashSet,
 };
 use torin::{
-    prelude::LayoutNode,
+    prelude::{
+        Area,
+        LayoutNode,
+    },
     torin::Torin,
 };
 
@@ -57,6 +64,9 @@ use crate::dom::{
 
 pub const ACCESSIBILITY_ROOT_ID: AccessibilityId = AccessibilityId(0);
 
+/// How much a misalignment in the cross axis is penalized when navigating spatially.
+const SPATIAL_CROSS_AXIS_WEIGHT: f32 = 2.0;
+
 pub type SharedAccessibilityTree = Arc<Mutex<AccessibilityTree>>;
 
 pub struct AccessibilityTree {
@@ -242,12 +252,17 @@ impl AccessibilityTree {
     }
 
     /// Focus a Node given the strategy.
+    ///
+    /// The focus is kept inside the closest focus trap (`a11y_focus_trap`) of the focused Node,
+    /// or moved inside the last focus trap if it's outside of all of them.
     pub fn set_focus_on_next_node(
         &mut self,
         stragegy: AccessibilityFocusStrategy,
         rdom: &DioxusDOM,
+        layout: &Torin<NodeId>,
     ) -> (TreeUpdate, NodeId) {
         let mut nodes = Vec::new();
+        let mut focus_traps = Vec::new();
 
         rdom.traverse_depth_first_advanced(|node_ref| {
             if !node_ref.node_type().is_element() {
@@ -256,10 +271,15 @@ impl AccessibilityTree {
 
             let accessibility_id = node_ref.get_accessibility_id();
 
-            if let Some(accessibility_id) = accessibility_id {
-                let accessibility_state = node_ref.get::<AccessibilityNodeState>().unwrap();
-                if accessibility_state.a11y_focusable.is_enabled() {
-                    nodes.push((accessibility_id, node_ref.id()))
+            if let Some(accessibility_state) = node_ref.get::<AccessibilityNodeState>() {
+                if accessibility_state.a11y_focus_trap {
+                    focus_traps.push(node_ref.id());
+                }
+
+                if let Some(accessibility_id) = accessibility_id {
+                    if accessibility_state.a11y_focusable.is_enabled() {
+                        nodes.push((accessibility_id, node_ref.id()))
+                    }
                 }
             }
 
@@ -272,33 +292,75 @@ impl AccessibilityTree {
             true
         });
 
+        let focused_node_id = self.focused_node_id();
+
+        // Only the Nodes inside the focus trap can be focused
+        let focus_trap = focus_traps
+            .iter()
+            .rev()
+            .find(|focus_trap| {
+                focused_node_id
+                    .map(|node_id| is_descendant_of(rdom, node_id, **focus_trap))
+                    .unwrap_or_default()
+            })
+            .or(focus_traps.last());
+        if let Some(focus_trap) = focus_trap {
+            nodes.retain(|(_, node_id)| is_descendant_of(rdom, *node_id, *focus_trap));
+        }
+
         let node_index = nodes
             .iter()
             .enumerate()
             .find(|(_, (accessibility_id, _))| *accessibility_id == self.focused_id)
             .map(|(i, _)| i);
 
-        let target_node = if stragegy == AccessibilityFocusStrategy::Forward {
+        let target_node = match stragegy {
             // Find the next Node
-            if let Some(node_index) = node_index {
-                if node_index == nodes.len() - 1 {
-                    nodes.first()
+            AccessibilityFocusStrategy::Forward => {
+                if let Some(node_index) = node_index {
+                    if node_index == nodes.len() - 1 {
+                        nodes.first()
+                    } else {
+                        nodes.get(node_index + 1)
+                    }
                 } else {
-                    nodes.get(node_index + 1)
+                    nodes.first()
                 }
-            } else {
-                nodes.first()
             }
-        } else {
             // Find the previous Node
-            if let Some(node_index) = node_index {
-                if node_index == 0 {
+            AccessibilityFocusStrategy::Backward => {
+                if let Some(node_index) = node_index {
+                    if node_index == 0 {
+                        nodes.last()
+                    } else {
+                        nodes.get(node_index - 1)
+                    }
+                } else {
                     nodes.last()
+                }
+            }
+            // Find the closest Node in the given direction
+            _ => {
+                let focused_area = node_index
+                    .and(focused_node_id)
+                    .and_then(|node_id| layout.get(node_id))
+                    .map(|layout_node| layout_node.area);
+                if let Some(focused_area) = focused_area {
+                    nodes
+                        .iter()
+                        .filter(|(accessibility_id, _)| *accessibility_id != self.focused_id)
+                        .filter_map(|node| {
+                            let area = layout.get(node.1)?.area;
+                            let distance = spatial_distance(stragegy, &focused_area, &area)?;
+                            Some((node, distance))
+                        })
+                        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+                        .map(|(node, _)| node)
+                        // Keep the focus if there is no Node in that direction
+                        .or(node_index.and_then(|node_index| nodes.get(node_index)))
                 } else {
-                    nodes.get(node_index - 1)
+                    nodes.first()
                 }
-            } else {
-                nodes.last()
             }
         };
 
@@ -466,6 +528,52 @@ impl AccessibilityTree {
             builder.set_name(name.to_owned());
         }
 
+        // Set live region
+        if let Some(live) = node_accessibility.a11y_live {
+            builder.set_live(live);
+        }
+
+        // Set states
+        if let Some(checked) = node_accessibility.a11y_checked {
+            builder.set_toggled(checked);
+        }
+        if let Some(expanded) = node_accessibility.a11y_expanded {
+            builder.set_expanded(expanded);
+        }
+        if let Some(selected) = node_accessibility.a11y_selected {
+            builder.set_selected(selected);
+        }
+        if node_accessibility.a11y_disabled {
+            builder.set_disabled();
+        }
+
+        // Set numeric value and range
+        if let Some(value) = node_accessibility.a11y_numeric_value {
+            builder.set_numeric_value(value);
+        }
+        if let Some(min) = node_accessibility.a11y_min_numeric_value {
+            builder.set_min_numeric_value(min);
+        }
+        if let Some(max) = node_accessibility.a11y_max_numeric_value {
+            builder.set_max_numeric_value(max);
+        }
+        if let Some(step) = node_accessibility.a11y_numeric_value_step {
+            builder.set_numeric_value_step(step);
+        }
+
+        // Set relationships
+        if let Some(labelled_by) = node_accessibility.a11y_labelled_by {
+            builder.set_labelled_by(vec![labelled_by]);
+        }
+        if let Some(described_by) = node_accessibility.a11y_described_by {
+            builder.set_described_by(vec![described_by]);
+        }
+
+        // Set keyboard shortcut
+        if let Some(keyboard_shortcut) = &node_accessibility.a11y_keyboard_shortcut {
+            builder.set_keyboard_shortcut(keyboard_shortcut.to_owned());
+        }
+
         // Set role
         if let Some(role) = node_accessibility.a11y_role {
             builder.set_role(role);
@@ -473,6 +581,39 @@ impl AccessibilityTree {
         // Set root role
         if node_ref.id() == node_ref.real_dom().root_id() {
             builder.set_role(Role::Window);
+        } else {
+            builder.add_action(Action::ScrollIntoView);
+        }
+
+        // Set the actions sent to the elements through `ona11yaction`, only if they handle them
+        let handles_actions = node_ref
+            .real_dom()
+            .is_node_listening(&node_ref.id(), &EventName::A11yAction);
+        if handles_actions {
+            if node_accessibility.a11y_focusable.is_enabled() && !node_accessibility.a11y_disabled {
+                builder.add_action(Action::Default);
+            }
+            if node_accessibility.a11y_expanded.is_some() {
+                builder.add_action(Action::Expand);
+                builder.add_action(Action::Collapse);
+            }
+            // Only adjustable roles can change their value, e.g not a progress bar
+            if node_accessibility.a11y_numeric_value.is_some()
+                && matches!(
+                    node_accessibility.a11y_role,
+                    Some(Role::Slider | Role::SpinButton)
+                )
+            {
+                builder.add_action(Action::Increment);
+                builder.add_action(Action::Decrement);
+                builder.add_action(Action::SetValue);
+            }
+            if matches!(
+                node_accessibility.a11y_role,
+                Some(Role::TextInput | Role::MultilineTextInput)
+            ) {
+                builder.add_action(Action::SetValue);
+            }
         }
 
         builder.build()
@@ -494,3 +635,62 @@ fn skia_color_to_rgba_u32(color: Color) -> u32 {
         | ((color.b() as u32) << 16)
         | (((color.g() as u32) << 8) + (color.r() as u32))
 }
+
+/// Check if a Node is the given ancestor or one of its descendants.
+fn is_descendant_of(rdom: &DioxusDOM, node_id: NodeId, ancestor_id: NodeId) -> bool {
+    let tree = rdom.tree_ref();
+    let mut current_node = Some(node_id);
+    while let Some(node_id) = current_node {
+        if node_id == ancestor_id {
+            return true;
+        }
+        current_node = tree.parent_id(node_id);
+    }
+    false
+}
+
+/// Distance between the areas when moving from `from` to `to` in the direction of the strategy,
+/// `None` if `to` is not in that direction.
+/// The first value is the penalized distance and the second one is used to break ties.
+fn spatial_distance(
+    strategy: AccessibilityFocusStrategy,
+    from: &Area,
+    to: &Area,
+) -> Option<(f32, f32)> {
+    // Space between two segments, 0 if they overlap
+    let gap = |a_start: f32, a_end: f32, b_start: f32, b_end: f32| {
+        (b_start - a_end).max(a_start - b_end).max(0.0)
+    };
+
+    let from_center = from.center();
+    let to_center = to.center();
+
+    let (main_distance, cross_distance, cross_offset) = match strategy {
+        AccessibilityFocusStrategy::Up if to_center.y < from_center.y => (
+            from.min_y() - to.max_y(),
+            gap(from.min_x(), from.max_x(), to.min_x(), to.max_x()),
+            to_center.x - from_center.x,
+        ),
+        AccessibilityFocusStrategy::Down if to_center.y > from_center.y => (
+            to.min_y() - from.max_y(),
+            gap(from.min_x(), from.max_x(), to.min_x(), to.max_x()),
+            to_center.x - from_center.x,
+        ),
+        AccessibilityFocusStrategy::Left if to_center.x < from_center.x => (
+            from.min_x() - to.max_x(),
+            gap(from.min_y(), from.max_y(), to.min_y(), to.max_y()),
+            to_center.y - from_center.y,
+        ),
+        AccessibilityFocusStrategy::Right if to_center.x > from_center.x => (
+            to.min_x() - from.max_x(),
+            gap(from.min_y(), from.max_y(), to.min_y(), to.max_y()),
+            to_center.y - from_center.y,
+        ),
+        _ => return None,
+    };
+
+    Some((
+        main_distance.max(0.0) + cross_distance * SPATIAL_CROSS_AXIS_WEIGHT,
+        cross_offset.abs(),
+    ))
+}
@@ -0,0 +1,100 @@
+use std::sync::{
+    atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+    Arc,
+    Mutex,
+};
+
+use tokio::sync::broadcast;
+
+/// Close requests of a Window, the platform only closes the Window by itself if nobody is listening to them.
+#[derive(Clone)]
+pub struct CloseRequests {
+    listeners: Arc<AtomicUsize>,
+    sender: broadcast::Sender<CloseRequest>,
+}
+
+impl Default for CloseRequests {
+    fn default() -> Self {
+        Self {
+            listeners: Arc::default(),
+            sender: broadcast::channel(5).0,
+        }
+    }
+}
+
+impl CloseRequests {
+    /// Check if somebody is listening to the close requests.
+    pub fn is_intercepted(&self) -> bool {
+        self.listeners.load(Ordering::Relaxed) > 0
+    }
+
+    /// Notify the listeners that the Window was requested to close.
+    pub fn request(&self) {
+        let request = CloseRequest {
+            state: Arc::new(Mutex::new(CloseRequestState {
+                pending: self.listeners.load(Ordering::Relaxed),
+                vetoed: false,
+            })),
+        };
+        self.sender.send(request).ok();
+    }
+
+    /// Listen to the close requests, the Window will not close by itself until the listener is dropped.
+    pub fn listen(&self) -> CloseRequestsListener {
+        self.listeners.fetch_add(1, Ordering::Relaxed);
+        CloseRequestsListener {
+            receiver: self.sender.subscribe(),
+            listeners: self.listeners.clone(),
+        }
+    }
+}
+
+/// Receives the close requests of a Window. Created with [`CloseRequests::listen`].
+pub struct CloseRequestsListener {
+    receiver: broadcast::Receiver<CloseRequest>,
+    listeners: Arc<AtomicUsize>,
+}
+
+impl CloseRequestsListener {
+    /// Wait for the next close request.
+    pub async fn recv(&mut self) -> Option<CloseRequest> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(request) => return Some(request),
+                Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+impl Drop for CloseRequestsListener {
+    fn drop(&mut self) {
+        self.listeners.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+struct CloseRequestState {
+    pending: usize,
+    vetoed: bool,
+}
+
+/// Request to close a Window, it must be resolved by every listener.
+#[derive(Clone)]
+pub struct CloseRequest {
+    state: Arc<Mutex<CloseRequestState>>,
+}
+
+impl CloseRequest {
+    /// Resolve the request on behalf of a listener, allowing or preventing the Window to close.
+    /// Returns `true` if this was the last pending listener and none of them prevented closing.
+    pub fn resolve(&self, allow: bool) -> bool {
+        let mut state = self.state.lock().unwrap();
+        state.pending = state.pending.saturating_sub(1);
+        state.vetoed |= !allow;
+        state.pending == 0 && !state.vetoed
+    }
+}
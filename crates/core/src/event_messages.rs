@@ -1,4 +1,9 @@
-use dioxus_core::Template;
+use std::any::Any;
+
+use dioxus_core::{
+    Element,
+    Template,
+};
 use torin::prelude::{
     Area,
     CursorPoint,
@@ -7,9 +12,15 @@ use uuid::Uuid;
 use winit::window::{
     CursorIcon,
     Window,
+    WindowId,
 };
 
-use crate::prelude::PlatformEvent;
+use crate::prelude::{
+    AccessibilityFocusStrategy,
+    FontOperation,
+    PlatformEvent,
+    WindowConfig,
+};
 
 pub struct TextGroupMeasurement {
     pub text_id: Uuid,
@@ -18,6 +29,23 @@ pub struct TextGroupMeasurement {
     pub cursor_selection: Option<(CursorPoint, CursorPoint)>,
 }
 
+/// Activation of a native menu item, e.g from the tray icon menu or the app menu bar.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MenuEvent {
+    /// ID of the activated menu item.
+    pub id: String,
+}
+
+/// Secondary Window to open, with its own VirtualDOM.
+pub struct NewWindow {
+    /// Root component of the Window.
+    pub app: fn() -> Element,
+    /// Configuration of the Window.
+    pub window_config: WindowConfig,
+    /// Values provided as root contexts to the Window, e.g a `SyncSignal` shared with other Windows.
+    pub contexts: Vec<Box<dyn Any + Send + Sync>>,
+}
+
 /// Custom EventLoop messages
 pub enum EventMessage {
     /// Update the given template
@@ -42,16 +70,31 @@ pub enum EventMessage {
     FocusNextAccessibilityNode,
     /// Focus the previous accessibility Node
     FocusPrevAccessibilityNode,
-    /// Close the whole app
+    /// Focus an accessibility Node with the given strategy, e.g spatially
+    FocusAccessibilityNodeWithStrategy(AccessibilityFocusStrategy),
+    /// Close the whole app, or only the Window when sent to a secondary Window
     ExitApp,
+    /// Open a secondary Window
+    NewWindow(NewWindow),
+    /// Message for a specific Window, the others go to the main Window
+    ToWindow(WindowId, Box<EventMessage>),
     /// Callback to access the Window.
     WithWindow(Box<dyn FnOnce(&Window) + Send + Sync>),
     /// Raw platform event, this are low level events.
     PlatformEvent(PlatformEvent),
+    /// A native menu item was activated, it is sent to every Window
+    MenuEvent(MenuEvent),
+    /// The tray icon was clicked
+    TrayIconClicked,
+    /// Change the fonts of the app, it applies to every Window
+    UpdateFonts(FontOperation),
 }
 
 impl From<accesskit_winit::Event> for EventMessage {
     fn from(value: accesskit_winit::Event) -> Self {
-        Self::Accessibility(value.window_event)
+        Self::ToWindow(
+            value.window_id,
+            Box::new(Self::Accessibility(value.window_event)),
+        )
     }
 }
@@ -3,24 +3,42 @@ use std::{
     rc::Rc,
 };
 
+use dioxus_core::ElementId;
 use freya_elements::{
     elements::PlatformEventData,
     events::{
         pointer::PointerType,
+        AccessibilityActionData,
+        EventPropagation,
         FileData,
+        GamepadData,
         KeyboardData,
         MouseData,
+        PinchData,
+        PointerCaptureRequest,
         PointerData,
+        RotationData,
         TouchData,
         WheelData,
     },
 };
-use freya_native_core::NodeId;
+use freya_native_core::{
+    prelude::{
+        NodeImmutable,
+        NodeImmutableDioxusExt,
+    },
+    NodeId,
+};
+use rustc_hash::FxHashMap;
 use torin::prelude::*;
 
 use super::event_name::EventName;
 use crate::{
-    events::PlatformEvent,
+    dom::DioxusDOM,
+    events::{
+        NodesState,
+        PlatformEvent,
+    },
     prelude::PotentialEvent,
 };
 
@@ -28,6 +46,8 @@ use crate::{
 #[derive(Debug, Clone, PartialEq)]
 pub struct DomEvent {
     pub name: EventName,
+    /// Name of the platform event this event was created from, e.g `mouseup` for `click`.
+    pub source: EventName,
     pub node_id: NodeId,
     pub data: DomEventData,
     pub bubbles: bool,
@@ -89,6 +109,7 @@ impl DomEvent {
                 Self {
                     node_id,
                     name,
+                    source: name,
                     data: event_data,
                     bubbles,
                     layer,
@@ -97,10 +118,63 @@ impl DomEvent {
             PlatformEvent::Wheel { scroll, .. } => Self {
                 node_id,
                 name,
+                source: name,
                 data: DomEventData::Wheel(WheelData::new(scroll.x, scroll.y)),
                 bubbles,
                 layer,
             },
+            PlatformEvent::Pinch {
+                cursor,
+                delta,
+                phase,
+                ..
+            } => {
+                let screen_coordinates = cursor / scale_factor;
+                let element_x =
+                    (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+
+                Self {
+                    node_id,
+                    name,
+                    source: name,
+                    data: DomEventData::Pinch(PinchData::new(
+                        screen_coordinates,
+                        (element_x, element_y).into(),
+                        delta,
+                        phase,
+                    )),
+                    bubbles,
+                    layer,
+                }
+            }
+            PlatformEvent::Rotation {
+                cursor,
+                delta,
+                phase,
+                ..
+            } => {
+                let screen_coordinates = cursor / scale_factor;
+                let element_x =
+                    (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+
+                Self {
+                    node_id,
+                    name,
+                    source: name,
+                    data: DomEventData::Rotation(RotationData::new(
+                        screen_coordinates,
+                        (element_x, element_y).into(),
+                        delta,
+                        phase,
+                    )),
+                    bubbles,
+                    layer,
+                }
+            }
             PlatformEvent::Keyboard {
                 ref key,
                 code,
@@ -109,10 +183,21 @@ impl DomEvent {
             } => Self {
                 node_id,
                 name,
+                source: name,
                 data: DomEventData::Keyboard(KeyboardData::new(key.clone(), code, modifiers)),
                 bubbles,
                 layer,
             },
+            PlatformEvent::Gamepad {
+                gamepad_id, input, ..
+            } => Self {
+                node_id,
+                name,
+                source: name,
+                data: DomEventData::Gamepad(GamepadData::new(gamepad_id, input)),
+                bubbles,
+                layer,
+            },
             PlatformEvent::Touch {
                 location,
                 finger_id,
@@ -146,24 +231,74 @@ impl DomEvent {
                 Self {
                     node_id,
                     name,
+                    source: name,
                     data: event_data,
                     bubbles,
                     layer,
                 }
             }
             PlatformEvent::File {
-                name, file_path, ..
+                cursor, file_paths, ..
             } => {
-                let event_data = DomEventData::File(FileData { file_path });
+                let screen_coordinates = cursor / scale_factor;
+                let element_x =
+                    (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+
+                let event_data = DomEventData::File(FileData::new(
+                    file_paths,
+                    screen_coordinates,
+                    (element_x, element_y).into(),
+                ));
 
                 Self {
                     node_id,
                     name,
+                    source: name,
                     data: event_data,
                     bubbles,
                     layer,
                 }
             }
+            PlatformEvent::Accessibility { action, .. } => Self {
+                node_id,
+                name,
+                source: name,
+                data: DomEventData::Accessibility(AccessibilityActionData::new(
+                    action,
+                    node_area.unwrap_or_default() / scale_factor as f32,
+                )),
+                bubbles,
+                layer,
+            },
+        }
+    }
+
+    /// Set the click count of the current clicks sequence, only to the events related to pressing the pointer.
+    pub fn set_click_count(&mut self, click_count: u32) {
+        if !self.name.has_click_count() {
+            return;
+        }
+
+        match &mut self.data {
+            DomEventData::Mouse(data) => data.click_count = click_count,
+            DomEventData::Pointer(data) => data.click_count = click_count,
+            _ => {}
+        }
+    }
+
+    /// Set the name of the platform event this event was created from.
+    pub fn with_source(mut self, source: EventName) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Set the area of the Node targeted by an accessibility action,
+    /// as the event might be handled by one of its ancestors.
+    pub fn set_target_area(&mut self, target_area: Area, scale_factor: f64) {
+        if let DomEventData::Accessibility(data) = &mut self.data {
+            data.target_area = target_area / scale_factor as f32;
         }
     }
 }
@@ -173,21 +308,127 @@ impl DomEvent {
 pub enum DomEventData {
     Mouse(MouseData),
     Keyboard(KeyboardData),
+    Gamepad(GamepadData),
     Wheel(WheelData),
+    Pinch(PinchData),
+    Rotation(RotationData),
     Touch(TouchData),
     Pointer(PointerData),
     File(FileData),
+    Accessibility(AccessibilityActionData),
 }
 
 impl DomEventData {
-    pub fn any(self) -> Rc<dyn Any> {
-        match self {
-            DomEventData::Mouse(m) => Rc::new(PlatformEventData::new(Box::new(m))),
-            DomEventData::Keyboard(k) => Rc::new(PlatformEventData::new(Box::new(k))),
-            DomEventData::Wheel(w) => Rc::new(PlatformEventData::new(Box::new(w))),
-            DomEventData::Touch(t) => Rc::new(PlatformEventData::new(Box::new(t))),
-            DomEventData::Pointer(p) => Rc::new(PlatformEventData::new(Box::new(p))),
-            DomEventData::File(fd) => Rc::new(PlatformEventData::new(Box::new(fd))),
+    pub fn any(self, propagation: Rc<EventPropagation>) -> Rc<dyn Any> {
+        let event: Box<dyn Any> = match self {
+            DomEventData::Mouse(m) => Box::new(m),
+            DomEventData::Keyboard(k) => Box::new(k),
+            DomEventData::Gamepad(g) => Box::new(g),
+            DomEventData::Wheel(w) => Box::new(w),
+            DomEventData::Pinch(p) => Box::new(p),
+            DomEventData::Rotation(r) => Box::new(r),
+            DomEventData::Touch(t) => Box::new(t),
+            DomEventData::Pointer(p) => Box::new(p),
+            DomEventData::File(fd) => Box::new(fd),
+            DomEventData::Accessibility(a) => Box::new(a),
+        };
+        Rc::new(PlatformEventData::new(event).with_propagation(propagation))
+    }
+}
+
+/// Emit a batch of [`DomEvent`]s, `handle_event` should forward them to the VirtualDOM.
+///
+/// The events created from the same platform event (e.g `mouseup`, `pointerup` and `click` from a `mouseup`)
+/// share the same [`EventPropagation`], so once a listener (e.g a capture listener) stops it,
+/// only the global listeners will receive the remaining events created from that platform event.
+///
+/// Pointer events are dispatched to one listening element at a time, from the target to the root if they bubble,
+/// so the pointer capture changes requested by the listeners are applied to the [`NodesState`]
+/// for the Node of the listener that requested them.
+pub fn emit_dom_events(
+    events: Vec<DomEvent>,
+    rdom: &DioxusDOM,
+    nodes_state: &mut NodesState,
+    mut handle_event: impl FnMut(&'static str, Rc<dyn Any>, ElementId, bool),
+) {
+    let mut propagations = FxHashMap::<EventName, Rc<EventPropagation>>::default();
+
+    for event in events {
+        let propagation = propagations.entry(event.source).or_default().clone();
+
+        if propagation.is_propagation_stopped() && !event.name.is_global() {
+            continue;
+        }
+
+        if event.name.is_pointer() {
+            emit_pointer_event(event, rdom, nodes_state, &propagation, &mut handle_event);
+            continue;
+        }
+
+        let Some(element_id) = rdom.get(event.node_id).and_then(|node| node.mounted_id()) else {
+            continue;
+        };
+
+        handle_event(
+            event.name.into(),
+            event.data.any(propagation),
+            element_id,
+            event.bubbles,
+        );
+    }
+}
+
+/// Dispatch a pointer event to each of its listeners, one at a time, until the propagation is stopped.
+fn emit_pointer_event(
+    event: DomEvent,
+    rdom: &DioxusDOM,
+    nodes_state: &mut NodesState,
+    propagation: &Rc<EventPropagation>,
+    handle_event: &mut impl FnMut(&'static str, Rc<dyn Any>, ElementId, bool),
+) {
+    let listeners = std::iter::successors(Some(event.node_id), |node_id| {
+        if event.bubbles {
+            rdom.get(*node_id).and_then(|node| node.parent_id())
+        } else {
+            None
+        }
+    })
+    .filter(|node_id| rdom.is_node_listening(node_id, &event.name));
+
+    for node_id in listeners {
+        let Some(element_id) = rdom.get(node_id).and_then(|node| node.mounted_id()) else {
+            continue;
+        };
+
+        propagation.start_dispatch(element_id);
+        handle_event(
+            event.name.into(),
+            event.data.clone().any(propagation.clone()),
+            element_id,
+            event.bubbles,
+        );
+        let bubbled = propagation.finish_dispatch();
+
+        if let Some((request, requester_element_id)) = propagation.take_pointer_capture_request() {
+            // Only the listener of this element could handle the event
+            debug_assert_eq!(requester_element_id, element_id);
+            match request {
+                PointerCaptureRequest::Set => nodes_state.set_pointer_capture(node_id),
+                PointerCaptureRequest::Release => {
+                    if nodes_state.release_pointer_capture(node_id) {
+                        handle_event(
+                            EventName::LostPointerCapture.into(),
+                            event.data.clone().any(propagation.clone()),
+                            requester_element_id,
+                            EventName::LostPointerCapture.does_bubble(),
+                        );
+                    }
+                }
+            }
+        }
+
+        if !bubbled || propagation.is_propagation_stopped() {
+            break;
         }
     }
 }
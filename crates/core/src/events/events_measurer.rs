@@ -6,10 +6,12 @@ use freya_native_core::{
 };
 use freya_node_state::{
     Fill,
+    PointerEvents,
     StyleState,
     ViewportState,
 };
 use itertools::sorted;
+use torin::geometry::CursorPoint;
 
 pub use crate::events::{
     DomEvent,
@@ -31,30 +33,44 @@ pub fn process_events(
 
     focus_id: Option<NodeId>,
 ) {
-    // 1. Get global events created from the incoming events
+    // 1. Update the clicks sequence
+    let click_count = nodes_state.process_clicks(events, scale_factor);
+
+    // 2. Get global events created from the incoming events
     let global_events = measure_global_events(events);
 
-    // 2. Get potential events that could be emitted based on the elements layout and viewports
+    // 3. Get potential events that could be emitted based on the elements layout and viewports
     let potential_events = measure_potential_event_listeners(events, dom, scale_factor, focus_id);
 
-    // 3. Get what events can be actually emitted based on what elements are listening
+    // 4. Get what events can be actually emitted based on what elements are listening
     let mut dom_events = measure_dom_events(&potential_events, dom, scale_factor);
 
-    // 4. Get potential collateral events, e.g. mousemove -> mouseenter
-    let potential_collateral_events =
-        nodes_state.process_collateral(&potential_events, &mut dom_events, events);
+    // 5. Update the pressed nodes
+    nodes_state.process_pressed(&potential_events, &mut dom_events, events);
 
-    // 5. Get what collateral events can actually be emitted
-    let to_emit_dom_collateral_events =
-        measure_dom_events(&potential_collateral_events, dom, scale_factor);
+    // 6. Get the hover events given the top most node under the pointer, e.g. mousemove -> mouseenter
+    let mut hover_events =
+        measure_hover_events(events, &potential_events, dom, nodes_state, scale_factor);
 
-    let colateral_global_events = measure_colateral_global_events(&to_emit_dom_collateral_events);
+    let colateral_global_events = measure_colateral_global_events(&hover_events);
 
-    // 6. Join both the dom and colateral dom events and sort them
-    dom_events.extend(to_emit_dom_collateral_events);
+    // 7. Sort the dom events and join them after the hover events
     dom_events.sort_unstable();
+    hover_events.append(&mut dom_events);
+    let mut dom_events = hover_events;
+
+    // Double clicks are only emitted on the second click of a sequence
+    dom_events.retain(|event| event.name != EventName::DoubleClick || click_count == 2);
+
+    // 8. Send the pointer events to the Node capturing the pointer, if any
+    measure_pointer_capture_events(events, dom, nodes_state, &mut dom_events, scale_factor);
+
+    // 9. Get the capture events, these go before any other event
+    let mut capture_events = measure_capture_events(&potential_events, dom, scale_factor);
+    capture_events.append(&mut dom_events);
+    let mut dom_events = capture_events;
 
-    // 7. Emit the global events
+    // 10. Emit the global events
     measure_global_events_listeners(
         global_events,
         colateral_global_events,
@@ -63,10 +79,15 @@ pub fn process_events(
         scale_factor,
     );
 
-    // 8. Emit all the vents
+    // 11. Set the click count to the events data
+    for event in &mut dom_events {
+        event.set_click_count(click_count);
+    }
+
+    // 12. Emit all the vents
     event_emitter.send(dom_events).unwrap();
 
-    // 9. Clear the events queue
+    // 13. Clear the events queue
     events.clear();
 }
 
@@ -85,16 +106,17 @@ pub fn measure_colateral_global_events(events: &[DomEvent]) -> Vec<DomEvent> {
     global_events
 }
 
-/// Measure global events
-pub fn measure_global_events(events: &EventsQueue) -> Vec<PlatformEvent> {
+/// Measure global events, along with the name of the platform event they were created from
+pub fn measure_global_events(events: &EventsQueue) -> Vec<(EventName, PlatformEvent)> {
     let mut global_events = Vec::default();
     for event in events {
-        let Some(event_name) = event.get_name().get_global_event() else {
+        let source = event.get_name();
+        let Some(event_name) = source.get_global_event() else {
             continue;
         };
         let mut global_event = event.clone();
         global_event.set_name(event_name);
-        global_events.push(global_event);
+        global_events.push((source, global_event));
     }
     global_events
 }
@@ -118,7 +140,29 @@ pub fn measure_potential_event_listeners(
             let layout_node = layout.get(*node_id);
             if let Some(layout_node) = layout_node {
                 'events: for event in events.iter() {
-                    if let PlatformEvent::Keyboard { name, .. } = event {
+                    if let PlatformEvent::Accessibility { name, target, .. } = event {
+                        let node = rdom.get(*node_id).unwrap();
+                        if node.get_accessibility_id() == Some(*target) {
+                            // The ancestors of the target can also handle the action, e.g. to scroll it into view
+                            let mut nodes = Vec::new();
+                            let mut current_node = Some(*node_id);
+                            while let Some(current_node_id) = current_node {
+                                nodes.push(current_node_id);
+                                current_node = rdom.tree_ref().parent_id(current_node_id);
+                            }
+
+                            for node_id in nodes.into_iter().rev() {
+                                let event_data = PotentialEvent {
+                                    node_id,
+                                    layer: Some(*layer),
+                                    event: event.clone(),
+                                };
+                                potential_events.entry(*name).or_default().push(event_data);
+                            }
+                        }
+                    } else if let PlatformEvent::Keyboard { name, .. }
+                    | PlatformEvent::Gamepad { name, .. } = event
+                    {
                         if focus_id == Some(*node_id) {
                             let event_data = PotentialEvent {
                                 node_id: *node_id,
@@ -131,6 +175,8 @@ pub fn measure_potential_event_listeners(
                         let data = match event {
                             PlatformEvent::Mouse { name, cursor, .. } => Some((name, cursor)),
                             PlatformEvent::Wheel { name, cursor, .. } => Some((name, cursor)),
+                            PlatformEvent::Pinch { name, cursor, .. } => Some((name, cursor)),
+                            PlatformEvent::Rotation { name, cursor, .. } => Some((name, cursor)),
                             PlatformEvent::Touch { name, location, .. } => Some((name, location)),
                             PlatformEvent::File { name, cursor, .. } => Some((name, cursor)),
                             _ => None,
@@ -154,6 +200,11 @@ pub fn measure_potential_event_listeners(
                                 let node = rdom.get(*node_id).unwrap();
                                 let node_viewports = node.get::<ViewportState>().unwrap();
 
+                                // Nodes with `pointer_events: none` are transparent to the pointer
+                                if node_viewports.pointer_events == PointerEvents::None {
+                                    continue;
+                                }
+
                                 // Make sure the cursor is inside all the applicable viewports from the element
                                 for node_id in &node_viewports.viewports {
                                     let node_ref = rdom.get(*node_id).unwrap();
@@ -227,6 +278,13 @@ fn measure_dom_events(
     for (event_name, event_nodes) in potential_events {
         let collateral_events = event_name.get_collateral_events();
 
+        // The target is the top most node
+        let target_area = event_nodes.last().and_then(|potential_event| {
+            fdom.layout()
+                .get(potential_event.node_id)
+                .map(|layout_node| layout_node.visible_area())
+        });
+
         let mut valid_events: Vec<PotentialEvent> = Vec::new();
 
         // Iterate over the collateral events (including the source)
@@ -284,11 +342,15 @@ fn measure_dom_events(
             let layout = fdom.layout();
             let layout_node = layout.get(potential_event.node_id);
             if let Some(layout_node) = layout_node {
-                let event = DomEvent::new(
+                let mut event = DomEvent::new(
                     potential_event,
                     Some(layout_node.visible_area()),
                     scale_factor,
-                );
+                )
+                .with_source(*event_name);
+                if let Some(target_area) = target_area {
+                    event.set_target_area(target_area, scale_factor);
+                }
                 new_events.push(event);
             }
         }
@@ -297,15 +359,269 @@ fn measure_dom_events(
     new_events
 }
 
+/// Measure what capture events must be emitted, from the root to the target of every event
+fn measure_capture_events(
+    potential_events: &PotentialEvents,
+    fdom: &FreyaDOM,
+    scale_factor: f64,
+) -> Vec<DomEvent> {
+    let mut capture_events = Vec::new();
+    let rdom = fdom.rdom();
+    let layout = fdom.layout();
+
+    for (event_name, event_nodes) in potential_events {
+        // The target is the top most node
+        let Some(PotentialEvent {
+            node_id,
+            event,
+            layer,
+        }) = event_nodes.last()
+        else {
+            continue;
+        };
+
+        for collateral_event in event_name.get_collateral_events() {
+            let Some(capture_event) = collateral_event.get_capture_event() else {
+                continue;
+            };
+
+            // Find the listeners from the target to the root
+            let mut listeners = Vec::new();
+            let mut current_node = Some(*node_id);
+            while let Some(current_node_id) = current_node {
+                if rdom.is_node_listening(&current_node_id, &capture_event) {
+                    listeners.push(current_node_id);
+                }
+                current_node = rdom.tree_ref().parent_id(current_node_id);
+            }
+
+            for listener in listeners.into_iter().rev() {
+                let Some(layout_node) = layout.get(listener) else {
+                    continue;
+                };
+                let mut event = event.clone();
+                event.set_name(capture_event);
+                capture_events.push(
+                    DomEvent::new(
+                        PotentialEvent {
+                            node_id: listener,
+                            layer: *layer,
+                            event,
+                        },
+                        Some(layout_node.visible_area()),
+                        scale_factor,
+                    )
+                    .with_source(*event_name),
+                );
+            }
+        }
+    }
+
+    capture_events
+}
+
+/// Measure the hover events given the new top most Node under the pointer, e.g. `mouseenter` or `pointerover`.
+///
+/// A Node is hovered when it's the top most Node under the pointer or an ancestor of it,
+/// so overlapping Nodes or Nodes from lower layers are not hovered.
+fn measure_hover_events(
+    events: &EventsQueue,
+    potential_events: &PotentialEvents,
+    fdom: &FreyaDOM,
+    nodes_state: &mut NodesState,
+    scale_factor: f64,
+) -> Vec<DomEvent> {
+    // Only the last event that can change the hover state matters
+    let Some(event) = events
+        .iter()
+        .rev()
+        .find(|event| event.get_name().can_change_hover_state())
+    else {
+        return Vec::new();
+    };
+    let event_name = event.get_name();
+
+    let rdom = fdom.rdom();
+    let layout = fdom.layout();
+
+    // Find the path from the top most Node under the pointer to the root
+    let mut hovered_nodes = Vec::new();
+    if !event_name.clears_hover_state() {
+        let location = pointer_location(event);
+        let mut current_node = potential_events.get(&event_name).and_then(|nodes| {
+            nodes
+                .iter()
+                .rev()
+                .find(|potential_event| pointer_location(&potential_event.event) == location)
+                .map(|potential_event| potential_event.node_id)
+        });
+        while let Some(node_id) = current_node {
+            hovered_nodes.push(node_id);
+            current_node = rdom.tree_ref().parent_id(node_id);
+        }
+    }
+
+    let previous_hovered_nodes = nodes_state.set_hovered_nodes(hovered_nodes.clone());
+
+    let mut hover_events = Vec::new();
+    let mut emit = |node_id: NodeId, name: EventName| {
+        if !rdom.contains(node_id) || !rdom.is_node_listening(&node_id, &name) {
+            return false;
+        }
+        let Some(layout_node) = layout.get(node_id) else {
+            return false;
+        };
+        // Touches only emit the pointer variants
+        if !name.is_pointer() && !matches!(event, PlatformEvent::Mouse { .. }) {
+            return false;
+        }
+        let mut event = event.clone();
+        event.set_name(name);
+        hover_events.push(
+            DomEvent::new(
+                PotentialEvent {
+                    node_id,
+                    layer: None,
+                    event,
+                },
+                Some(layout_node.visible_area()),
+                scale_factor,
+            )
+            .with_source(event_name),
+        );
+        true
+    };
+
+    let target_changed = previous_hovered_nodes.first() != hovered_nodes.first();
+
+    // `pointerout` bubbles, so it's emitted to the deepest Node listening to it
+    if target_changed {
+        for node_id in &previous_hovered_nodes {
+            if emit(*node_id, EventName::PointerOut) {
+                break;
+            }
+        }
+    }
+
+    // Leave the Nodes that are no longer hovered, from the innermost to the outermost
+    for node_id in &previous_hovered_nodes {
+        if !hovered_nodes.contains(node_id) {
+            emit(*node_id, EventName::PointerLeave);
+            emit(*node_id, EventName::MouseLeave);
+        }
+    }
+
+    // `pointerover` bubbles, so it's emitted to the deepest Node listening to it
+    if target_changed {
+        for node_id in &hovered_nodes {
+            if emit(*node_id, EventName::PointerOver) {
+                break;
+            }
+        }
+    }
+
+    // Enter the Nodes newly hovered, from the outermost to the innermost
+    for node_id in hovered_nodes.iter().rev() {
+        if !previous_hovered_nodes.contains(node_id) {
+            emit(*node_id, EventName::PointerEnter);
+            emit(*node_id, EventName::MouseEnter);
+        }
+    }
+
+    hover_events
+}
+
+/// Get the location of the pointer in a pointer event.
+fn pointer_location(event: &PlatformEvent) -> Option<CursorPoint> {
+    match event {
+        PlatformEvent::Mouse { cursor, .. } => Some(*cursor),
+        PlatformEvent::Touch { location, .. } => Some(*location),
+        _ => None,
+    }
+}
+
+/// Redirect the pointer events to the Node capturing the pointer, releasing the capture once the pointer is up
+fn measure_pointer_capture_events(
+    events: &EventsQueue,
+    fdom: &FreyaDOM,
+    nodes_state: &mut NodesState,
+    dom_events: &mut Vec<DomEvent>,
+    scale_factor: f64,
+) {
+    let Some(node_id) = nodes_state.pointer_capture() else {
+        return;
+    };
+
+    let rdom = fdom.rdom();
+    let layout = fdom.layout();
+
+    // The Node capturing the pointer might have been removed
+    let Some(layout_node) = layout.get(node_id).filter(|_| rdom.contains(node_id)) else {
+        nodes_state.take_pointer_capture();
+        return;
+    };
+    let node_area = Some(layout_node.visible_area());
+
+    // Only the capturing Node receives these events
+    dom_events.retain(|event| !event.name.can_be_pointer_captured());
+
+    for event in events {
+        let event_name = event.get_name();
+
+        for collateral_event in event_name.get_collateral_events() {
+            if collateral_event.can_be_pointer_captured()
+                && rdom.is_node_listening(&node_id, &collateral_event)
+            {
+                let mut event = event.clone();
+                event.set_name(collateral_event);
+                dom_events.push(
+                    DomEvent::new(
+                        PotentialEvent {
+                            node_id,
+                            layer: None,
+                            event,
+                        },
+                        node_area,
+                        scale_factor,
+                    )
+                    .with_source(event_name),
+                );
+            }
+        }
+
+        if event_name.releases_pointer_capture() {
+            nodes_state.take_pointer_capture();
+
+            if rdom.is_node_listening(&node_id, &EventName::LostPointerCapture) {
+                let mut event = event.clone();
+                event.set_name(EventName::LostPointerCapture);
+                dom_events.push(
+                    DomEvent::new(
+                        PotentialEvent {
+                            node_id,
+                            layer: None,
+                            event,
+                        },
+                        node_area,
+                        scale_factor,
+                    )
+                    .with_source(event_name),
+                );
+            }
+            break;
+        }
+    }
+}
+
 /// Emit global events
 fn measure_global_events_listeners(
-    global_events: Vec<PlatformEvent>,
+    global_events: Vec<(EventName, PlatformEvent)>,
     global_colateral_events: Vec<DomEvent>,
     fdom: &FreyaDOM,
     to_emit_dom_events: &mut Vec<DomEvent>,
     scale_factor: f64,
 ) {
-    for global_event in global_events {
+    for (source, global_event) in global_events {
         let event_name = global_event.get_name();
         let listeners = fdom.rdom().get_listeners(&event_name);
 
@@ -318,7 +634,8 @@ fn measure_global_events_listeners(
                 },
                 None,
                 scale_factor,
-            );
+            )
+            .with_source(source);
             to_emit_dom_events.push(event)
         }
     }
@@ -1,7 +1,14 @@
 #![allow(clippy::type_complexity)]
 
+use std::time::{
+    Duration,
+    Instant,
+};
+
 use freya_native_core::NodeId;
-use rustc_hash::FxHashMap;
+use rustc_hash::FxHashSet;
+use torin::geometry::CursorPoint;
+use winit::event::MouseButton;
 
 use crate::{
     events::{
@@ -10,160 +17,229 @@ use crate::{
     },
     prelude::{
         EventName,
+        EventsQueue,
         PotentialEvent,
         PotentialEvents,
     },
 };
 
+/// Default maximum time between two presses for them to be part of the same clicks sequence,
+/// used when the platform one is unknown.
+#[cfg(target_os = "linux")]
+pub const DOUBLE_CLICK_DURATION: Duration = Duration::from_millis(400);
+
+/// Default maximum time between two presses for them to be part of the same clicks sequence,
+/// used when the platform one is unknown.
+#[cfg(not(target_os = "linux"))]
+pub const DOUBLE_CLICK_DURATION: Duration = Duration::from_millis(500);
+
+/// Maximum distance (in logical pixels) between two presses for them to be part of the same clicks sequence,
+/// and that the pointer can move while being pressed before a long press is discarded.
+pub const DOUBLE_CLICK_DISTANCE: f64 = 4.0;
+
+/// Time that a pointer must be kept pressed to emit a `longpress` event.
+pub const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+
+/// Last press of the pointer.
 #[derive(Clone, Debug)]
-struct NodeMetadata {
-    layer: Option<i16>,
+struct PressMetadata {
+    time: Instant,
+    cursor: CursorPoint,
+    button: Option<MouseButton>,
+    count: u32,
+    can_long_press: bool,
 }
 
 /// [`NodesState`] stores the nodes states given incoming events.
-#[derive(Default)]
 pub struct NodesState {
-    pressed_nodes: FxHashMap<NodeId, NodeMetadata>,
-    hovered_nodes: FxHashMap<NodeId, NodeMetadata>,
+    pressed_nodes: FxHashSet<NodeId>,
+    hovered_nodes: Vec<NodeId>,
+    pointer_capture: Option<NodeId>,
+    last_press: Option<PressMetadata>,
+    double_click_duration: Duration,
 }
 
-impl NodesState {
-    /// Update the node states given the new events and suggest potential collateral new events
-    pub fn process_collateral(
-        &mut self,
-        pontential_events: &PotentialEvents,
-        dom_events: &mut Vec<DomEvent>,
-        events: &[PlatformEvent],
-    ) -> PotentialEvents {
-        let mut potential_events = PotentialEvents::default();
-
-        // Any mouse press event at all
-        let recent_mouse_press_event = any_event_of(events, |e| e.was_cursor_pressed_or_released());
+impl Default for NodesState {
+    fn default() -> Self {
+        Self {
+            pressed_nodes: FxHashSet::default(),
+            hovered_nodes: Vec::default(),
+            pointer_capture: None,
+            last_press: None,
+            double_click_duration: DOUBLE_CLICK_DURATION,
+        }
+    }
+}
 
-        // Pressed Nodes
-        self.pressed_nodes.retain(|node_id, _| {
-            // Always unmark as pressed when there has been a new mouse down or click event
-            if recent_mouse_press_event.is_some() {
-                #[cfg(debug_assertions)]
-                tracing::info!("Unmarked as pressed {:?}", node_id);
+impl NodesState {
+    /// Set the maximum time between two presses for them to be part of the same clicks sequence.
+    pub fn set_double_click_duration(&mut self, double_click_duration: Duration) {
+        self.double_click_duration = double_click_duration;
+    }
 
-                // Remove the node from the list of pressed nodes
-                return false;
+    /// Update the clicks sequence given the new events, discarding the `longpress` events that are no longer valid.
+    ///
+    /// Returns the click count of the current clicks sequence.
+    pub fn process_clicks(&mut self, events: &mut EventsQueue, scale_factor: f64) -> u32 {
+        let max_distance = DOUBLE_CLICK_DISTANCE * scale_factor;
+        let now = Instant::now();
+
+        events.retain(|event| {
+            let (name, cursor, button) = match event {
+                PlatformEvent::Mouse {
+                    name,
+                    cursor,
+                    button,
+                } => (*name, *cursor, *button),
+                PlatformEvent::Touch { name, location, .. } => (*name, *location, None),
+                _ => return true,
+            };
+
+            match name {
+                EventName::MouseDown | EventName::TouchStart => {
+                    let count = match &self.last_press {
+                        Some(last_press)
+                            if last_press.button == button
+                                && now.duration_since(last_press.time)
+                                    <= self.double_click_duration
+                                && last_press.cursor.distance_to(cursor) <= max_distance =>
+                        {
+                            last_press.count + 1
+                        }
+                        _ => 1,
+                    };
+
+                    self.last_press = Some(PressMetadata {
+                        time: now,
+                        cursor,
+                        button,
+                        count,
+                        can_long_press: true,
+                    });
+                }
+                EventName::MouseMove | EventName::TouchMove => {
+                    if let Some(last_press) = &mut self.last_press {
+                        if last_press.cursor.distance_to(cursor) > max_distance {
+                            last_press.can_long_press = false;
+                        }
+                    }
+                }
+                EventName::LongPress => {
+                    // Only emit the long press if the pointer is still pressed and it has not moved
+                    let Some(last_press) = &mut self.last_press else {
+                        return false;
+                    };
+                    let is_valid = last_press.can_long_press
+                        && now.duration_since(last_press.time) >= LONG_PRESS_DURATION;
+                    last_press.can_long_press &= !is_valid;
+                    return is_valid;
+                }
+                name if name.releases_pointer_capture() || name == EventName::PointerUp => {
+                    if let Some(last_press) = &mut self.last_press {
+                        last_press.can_long_press = false;
+                    }
+                }
+                _ => {}
             }
 
             true
         });
 
-        // Any mouse movement event at all
-        let recent_mouse_movement_event = any_event_of(events, |e| e.was_cursor_moved());
-
-        // Hovered Nodes
-        self.hovered_nodes.retain(|node_id, metadata| {
-            // Check if a DOM event that moves the cursor in this Node will get emitted
-            let no_recently_hovered =
-                filter_dom_events_by(dom_events, node_id, |e| e.was_cursor_moved());
+        self.last_press
+            .as_ref()
+            .map(|last_press| last_press.count)
+            .unwrap_or_default()
+    }
 
-            if no_recently_hovered {
-                // If there has been a mouse movement but a DOM event was not emitted to this node, then we safely assume
-                // the user does no longer want to hover this Node
-                if let Some(PlatformEvent::Mouse { cursor, button, .. }) =
-                    recent_mouse_movement_event
-                {
-                    let events = potential_events.entry(EventName::MouseLeave).or_default();
-
-                    // Emit a MouseLeave event as the cursor was moved outside the Node bounds
-                    events.push(PotentialEvent {
-                        node_id: *node_id,
-                        layer: metadata.layer,
-                        event: PlatformEvent::Mouse {
-                            name: EventName::MouseLeave,
-                            cursor,
-                            button,
-                        },
-                    });
+    /// Capture the pointer in the given Node, it will receive all the pointer move and up events.
+    pub fn set_pointer_capture(&mut self, node_id: NodeId) {
+        #[cfg(debug_assertions)]
+        tracing::info!("Captured pointer in {:?}", node_id);
 
-                    #[cfg(debug_assertions)]
-                    tracing::info!("Unmarked as hovered {:?}", node_id);
+        self.pointer_capture = Some(node_id);
+    }
 
-                    // Remove the node from the list of hovered nodes
-                    return false;
-                }
-            }
+    /// Release the pointer capture if the given Node has it, returns whether it was released.
+    pub fn release_pointer_capture(&mut self, node_id: NodeId) -> bool {
+        if self.pointer_capture == Some(node_id) {
+            self.take_pointer_capture();
             true
-        });
+        } else {
+            false
+        }
+    }
 
-        // Update the state of the noves given the new events.
+    /// Get the Node capturing the pointer, if any.
+    pub fn pointer_capture(&self) -> Option<NodeId> {
+        self.pointer_capture
+    }
 
-        // We clone this here so events emitted in the same batch that mark an node
-        // as hovered or pressed will not affect the other events
-        let hovered_nodes = self.hovered_nodes.clone();
-        let pressed_nodes = self.pressed_nodes.clone();
+    pub(crate) fn take_pointer_capture(&mut self) -> Option<NodeId> {
+        #[cfg(debug_assertions)]
+        if let Some(node_id) = self.pointer_capture {
+            tracing::info!("Released pointer from {:?}", node_id);
+        }
 
-        for events in pontential_events.values() {
-            for PotentialEvent {
-                node_id,
-                event,
-                layer,
-            } in events
-            {
-                match event.get_name() {
-                    // Update hovered nodes state
-                    name if name.can_change_hover_state() => {
-                        let is_hovered = hovered_nodes.contains_key(node_id);
-
-                        // Mark the Node as hovered if it wasn't already
-                        if !is_hovered {
-                            self.hovered_nodes
-                                .insert(*node_id, NodeMetadata { layer: *layer });
-
-                            #[cfg(debug_assertions)]
-                            tracing::info!("Marked as hovered {:?}", node_id);
-                        }
+        self.pointer_capture.take()
+    }
 
-                        if name.is_enter() {
-                            // If the Node was already hovered, we don't need to emit an `enter` event again.
-                            if is_hovered {
-                                continue;
-                            }
-                        }
-                    }
+    /// Update the hovered Nodes, from the top most hovered Node to the root.
+    ///
+    /// Returns the previously hovered Nodes.
+    pub fn set_hovered_nodes(&mut self, hovered_nodes: Vec<NodeId>) -> Vec<NodeId> {
+        #[cfg(debug_assertions)]
+        if hovered_nodes.first() != self.hovered_nodes.first() {
+            tracing::info!("Hover target changed to {:?}", hovered_nodes.first());
+        }
 
-                    // Update pressed nodes state
-                    name if name.can_change_press_state() => {
-                        let is_pressed = pressed_nodes.contains_key(node_id);
+        std::mem::replace(&mut self.hovered_nodes, hovered_nodes)
+    }
 
-                        // Mark the Node as pressed if it wasn't already
-                        if !is_pressed {
-                            self.pressed_nodes
-                                .insert(*node_id, NodeMetadata { layer: *layer });
+    /// Get the hovered Nodes, from the top most hovered Node to the root.
+    pub fn hovered_nodes(&self) -> &[NodeId] {
+        &self.hovered_nodes
+    }
 
-                            #[cfg(debug_assertions)]
-                            tracing::info!("Marked as pressed {:?}", node_id);
-                        }
-                    }
-                    _ => {}
-                }
+    /// Update the pressed nodes given the new events
+    pub fn process_pressed(
+        &mut self,
+        pontential_events: &PotentialEvents,
+        dom_events: &mut Vec<DomEvent>,
+        events: &[PlatformEvent],
+    ) {
+        // Any mouse press event at all
+        let recent_mouse_press_event = any_event_of(events, |e| e.was_cursor_pressed_or_released());
+
+        // Always unmark as pressed when there has been a new mouse down or click event
+        if recent_mouse_press_event.is_some() {
+            #[cfg(debug_assertions)]
+            for node_id in &self.pressed_nodes {
+                tracing::info!("Unmarked as pressed {:?}", node_id);
             }
+
+            self.pressed_nodes.clear();
         }
 
-        dom_events.retain(|ev| {
-            match ev.name {
-                // Filter out enter events for nodes that were already hovered
-                _ if ev.name.is_enter() => !hovered_nodes.contains_key(&ev.node_id),
+        // We clone this here so events emitted in the same batch that mark an node
+        // as pressed will not affect the other events
+        let pressed_nodes = self.pressed_nodes.clone();
 
-                // Filter out press events for nodes that were already pressed
-                _ if ev.name.is_pressed() => !pressed_nodes.contains_key(&ev.node_id),
+        for events in pontential_events.values() {
+            for PotentialEvent { node_id, event, .. } in events {
+                // Mark the Node as pressed if it wasn't already
+                if event.get_name().can_change_press_state()
+                    && !self.pressed_nodes.contains(node_id)
+                {
+                    self.pressed_nodes.insert(*node_id);
 
-                _ => true,
+                    #[cfg(debug_assertions)]
+                    tracing::info!("Marked as pressed {:?}", node_id);
+                }
             }
-        });
-
-        // Order the events by their Nodes layer
-        for events in potential_events.values_mut() {
-            events.sort_by(|left, right| left.layer.cmp(&right.layer))
         }
 
-        potential_events
+        // Filter out press events for nodes that were already pressed
+        dom_events.retain(|ev| !ev.name.is_pressed() || !pressed_nodes.contains(&ev.node_id));
     }
 }
 
@@ -176,20 +252,3 @@ fn any_event_of(
         .find(|event| filter(event.get_name()))
         .cloned()
 }
-
-fn filter_dom_events_by(
-    events_to_emit: &[DomEvent],
-    node_id: &NodeId,
-    filter: impl Fn(EventName) -> bool,
-) -> bool {
-    events_to_emit
-        .iter()
-        .find_map(|event| {
-            if filter(event.name) && &event.node_id == node_id {
-                Some(false)
-            } else {
-                None
-            }
-        })
-        .unwrap_or(true)
-}
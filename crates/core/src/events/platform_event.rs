@@ -1,9 +1,13 @@
 use std::path::PathBuf;
 
-use freya_elements::events::keyboard::{
-    Code,
-    Key,
-    Modifiers,
+use freya_elements::events::{
+    keyboard::{
+        Code,
+        Key,
+        Modifiers,
+    },
+    AccessibilityAction,
+    GamepadInput,
 };
 use torin::prelude::*;
 use winit::event::{
@@ -12,7 +16,10 @@ use winit::event::{
     TouchPhase,
 };
 
-use crate::prelude::EventName;
+use crate::{
+    prelude::EventName,
+    types::AccessibilityId,
+};
 
 /// Events emitted in Freya.
 #[derive(Clone, Debug)]
@@ -29,6 +36,20 @@ pub enum PlatformEvent {
         scroll: CursorPoint,
         cursor: CursorPoint,
     },
+    /// A trackpad Pinch event.
+    Pinch {
+        name: EventName,
+        cursor: CursorPoint,
+        delta: f64,
+        phase: TouchPhase,
+    },
+    /// A trackpad Rotation event.
+    Rotation {
+        name: EventName,
+        cursor: CursorPoint,
+        delta: f32,
+        phase: TouchPhase,
+    },
     /// A Keyboard event.
     Keyboard {
         name: EventName,
@@ -36,6 +57,12 @@ pub enum PlatformEvent {
         code: Code,
         modifiers: Modifiers,
     },
+    /// A Gamepad event.
+    Gamepad {
+        name: EventName,
+        gamepad_id: usize,
+        input: GamepadInput,
+    },
     /// A Touch event.
     Touch {
         name: EventName,
@@ -44,11 +71,17 @@ pub enum PlatformEvent {
         phase: TouchPhase,
         force: Option<Force>,
     },
-    /// A File event.
+    /// A File event, with all the files dropped or hovered at once.
     File {
         name: EventName,
         cursor: CursorPoint,
-        file_path: Option<PathBuf>,
+        file_paths: Vec<PathBuf>,
+    },
+    /// An action requested by an assistive technology on the Node with the given accessibility ID.
+    Accessibility {
+        name: EventName,
+        target: AccessibilityId,
+        action: AccessibilityAction,
     },
 }
 
@@ -57,9 +90,13 @@ impl PlatformEvent {
         match self {
             Self::Mouse { name, .. } => *name,
             Self::Wheel { name, .. } => *name,
+            Self::Pinch { name, .. } => *name,
+            Self::Rotation { name, .. } => *name,
             Self::Keyboard { name, .. } => *name,
+            Self::Gamepad { name, .. } => *name,
             Self::Touch { name, .. } => *name,
             Self::File { name, .. } => *name,
+            Self::Accessibility { name, .. } => *name,
         }
     }
 
@@ -67,9 +104,13 @@ impl PlatformEvent {
         match self {
             Self::Mouse { name, .. } => *name = new_name,
             Self::Wheel { name, .. } => *name = new_name,
+            Self::Pinch { name, .. } => *name = new_name,
+            Self::Rotation { name, .. } => *name = new_name,
             Self::Keyboard { name, .. } => *name = new_name,
+            Self::Gamepad { name, .. } => *name = new_name,
             Self::Touch { name, .. } => *name = new_name,
             Self::File { name, .. } => *name = new_name,
+            Self::Accessibility { name, .. } => *name = new_name,
         }
     }
 }
@@ -0,0 +1,105 @@
+use freya_engine::prelude::{
+    FontCollection,
+    FontMgr,
+    Typeface,
+    TypefaceFontProvider,
+};
+
+/// Change to the fonts of the app made at runtime.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FontOperation {
+    /// Register a font under the given family name, e.g the different weights of a family can share the same name.
+    Register { family: String, data: Vec<u8> },
+    /// Unregister the fonts with the given family name.
+    Unregister(String),
+    /// Set the fallback font families, in order of preference.
+    SetFallbacks(Vec<String>),
+}
+
+/// Fonts of the app, registered at launch or at runtime.
+#[derive(Clone)]
+pub struct FontsRegistry {
+    system_font_mgr: FontMgr,
+    typefaces: Vec<(String, Typeface)>,
+    default_fonts: Vec<String>,
+    fallback_fonts: Vec<String>,
+}
+
+impl FontsRegistry {
+    pub fn new<'a>(
+        embedded_fonts: impl IntoIterator<Item = (&'a str, &'a [u8])>,
+        default_fonts: Vec<String>,
+        fallback_fonts: Vec<String>,
+    ) -> Self {
+        let mut registry = Self {
+            system_font_mgr: FontMgr::default(),
+            typefaces: Vec::new(),
+            default_fonts,
+            fallback_fonts,
+        };
+        for (family, data) in embedded_fonts {
+            registry.register(family, data);
+        }
+        registry
+    }
+
+    /// Register a font, returns `false` if the data is not a valid font.
+    pub fn register(&mut self, family: &str, data: &[u8]) -> bool {
+        let Some(typeface) = self.system_font_mgr.new_from_data(data, None) else {
+            tracing::warn!("Failed to register the font {family:?}, it's not a valid font");
+            return false;
+        };
+        self.typefaces.push((family.to_string(), typeface));
+        true
+    }
+
+    /// Unregister the fonts with the given family name, returns `false` if there were none.
+    pub fn unregister(&mut self, family: &str) -> bool {
+        let len = self.typefaces.len();
+        self.typefaces.retain(|(name, _)| name != family);
+        len != self.typefaces.len()
+    }
+
+    /// Apply a change, returns `true` if the fonts changed.
+    pub fn apply(&mut self, operation: &FontOperation) -> bool {
+        match operation {
+            FontOperation::Register { family, data } => self.register(family, data),
+            FontOperation::Unregister(family) => self.unregister(family),
+            FontOperation::SetFallbacks(families) => {
+                let changed = self.fallback_fonts != *families;
+                self.fallback_fonts.clone_from(families);
+                changed
+            }
+        }
+    }
+
+    /// Default fonts followed by the fallback fonts.
+    ///
+    /// Characters are looked up in each of these families in order, there is no
+    /// matching by script, so fonts covering the same characters must be ordered by preference.
+    pub fn default_fonts(&self) -> Vec<String> {
+        let mut fonts = self.default_fonts.clone();
+        for family in &self.fallback_fonts {
+            if !fonts.contains(family) {
+                fonts.push(family.clone());
+            }
+        }
+        fonts
+    }
+
+    /// Setup the given font collection with the registered and system fonts,
+    /// returns the font manager of the registered fonts.
+    pub fn setup_font_collection(&self, font_collection: &mut FontCollection) -> FontMgr {
+        let mut provider = TypefaceFontProvider::new();
+        for (family, typeface) in &self.typefaces {
+            provider.register_typeface(typeface.clone(), Some(family));
+        }
+
+        let font_mgr: FontMgr = provider.into();
+        font_collection.set_default_font_manager(self.system_font_mgr.clone(), None);
+        font_collection.set_dynamic_font_manager(font_mgr.clone());
+        // Paragraphs cache the fonts they resolved
+        font_collection.clear_caches();
+        font_mgr
+    }
+}
@@ -1,8 +1,10 @@
 pub mod accessibility;
+pub mod close_requests;
 pub mod dom;
 pub mod elements;
 pub mod event_messages;
 pub mod events;
+pub mod fonts;
 pub mod layout;
 pub mod node;
 pub mod platform_state;
@@ -10,14 +12,17 @@ pub mod plugins;
 pub mod render;
 pub mod style;
 pub mod types;
+pub mod window_config;
 
 pub mod prelude {
     pub use crate::{
         accessibility::*,
+        close_requests::*,
         dom::*,
         elements::*,
         event_messages::*,
         events::*,
+        fonts::*,
         layout::*,
         node::*,
         platform_state::*,
@@ -25,5 +30,6 @@ pub mod prelude {
         render::*,
         style::*,
         types::*,
+        window_config::*,
     };
 }
@@ -1,6 +1,7 @@
 use freya_engine::prelude::*;
 use freya_native_core::real_dom::NodeImmutable;
 use freya_node_state::{
+    AccessibilityNodeState,
     Border,
     CornerRadius,
     CursorState,
@@ -28,6 +29,7 @@ use crate::dom::DioxusNode;
 
 #[derive(Clone, PartialEq)]
 pub struct NodeState {
+    pub accessibility: AccessibilityNodeState,
     pub cursor: CursorState,
     pub font_style: FontStyleState,
     pub references: ReferencesState,
@@ -37,6 +39,11 @@ pub struct NodeState {
 }
 
 pub fn get_node_state(node: &DioxusNode) -> NodeState {
+    let accessibility = node
+        .get::<AccessibilityNodeState>()
+        .as_deref()
+        .cloned()
+        .unwrap_or_default();
     let cursor = node
         .get::<CursorState>()
         .as_deref()
@@ -69,6 +76,7 @@ pub fn get_node_state(node: &DioxusNode) -> NodeState {
         .unwrap_or_default();
 
     NodeState {
+        accessibility,
         cursor,
         font_style,
         references,
@@ -135,6 +143,10 @@ impl NodeState {
                 "text_overflow",
                 AttributeType::TextOverflow(&self.font_style.text_overflow),
             ),
+            (
+                "baseline_shift",
+                AttributeType::Text(self.font_style.baseline_shift.pretty()),
+            ),
             ("offset_x", AttributeType::Measure(self.size.offset_x.get())),
             ("offset_y", AttributeType::Measure(self.size.offset_y.get())),
             ("content", AttributeType::Content(&self.size.content)),
@@ -1,6 +1,12 @@
 use accesskit::NodeId as AccessibilityId;
-use torin::prelude::Size2D;
-use winit::window::Window;
+use torin::prelude::{
+    Point2D,
+    Size2D,
+};
+use winit::{
+    monitor::MonitorHandle,
+    window::Window,
+};
 
 /// State consumed by components and updated by the platform.
 #[derive(Clone, Debug)]
@@ -46,6 +52,12 @@ pub struct PlatformInformation {
     pub is_minimized: bool,
     pub is_maximized: bool,
     pub is_fullscreen: bool,
+    /// Position of the Window in the desktop, in physical pixels.
+    /// Not available in some platforms, e.g Wayland.
+    pub window_position: Option<Point2D>,
+    pub is_focused: bool,
+    /// Whether the Window is fully hidden, e.g behind other Windows or in another workspace.
+    pub is_occluded: bool,
 }
 
 impl PlatformInformation {
@@ -56,6 +68,12 @@ impl PlatformInformation {
             is_minimized: winit.is_minimized().unwrap_or_default(),
             is_maximized: winit.is_maximized(),
             is_fullscreen: winit.fullscreen().is_some(),
+            window_position: winit
+                .outer_position()
+                .ok()
+                .map(|position| Point2D::new(position.x as f32, position.y as f32)),
+            is_focused: winit.has_focus(),
+            is_occluded: false,
         }
     }
 
@@ -70,6 +88,43 @@ impl PlatformInformation {
             is_minimized,
             is_maximized,
             is_fullscreen,
+            window_position: None,
+            is_focused: true,
+            is_occluded: false,
         }
     }
 }
+
+/// Information about a monitor.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MonitorInfo {
+    pub name: Option<String>,
+    /// Position of the monitor in the desktop, in physical pixels.
+    pub position: Point2D,
+    /// Size of the monitor, in physical pixels.
+    pub size: Size2D,
+    pub scale_factor: f64,
+    pub is_primary: bool,
+}
+
+impl MonitorInfo {
+    pub fn from_winit(monitor: &MonitorHandle, primary_monitor: Option<&MonitorHandle>) -> Self {
+        let position = monitor.position();
+        let size = monitor.size();
+        Self {
+            name: monitor.name(),
+            position: Point2D::new(position.x as f32, position.y as f32),
+            size: Size2D::new(size.width as f32, size.height as f32),
+            scale_factor: monitor.scale_factor(),
+            is_primary: primary_monitor == Some(monitor),
+        }
+    }
+
+    /// Whether the given point in the desktop is inside this monitor.
+    pub fn contains(&self, point: Point2D) -> bool {
+        point.x >= self.position.x
+            && point.y >= self.position.y
+            && point.x < self.position.x + self.size.width
+            && point.y < self.position.y + self.size.height
+    }
+}
@@ -3,6 +3,7 @@ use freya_common::{
     Layers,
 };
 use freya_engine::prelude::{
+    Canvas,
     ClipOp,
     Color,
     FontCollection,
@@ -132,12 +133,60 @@ impl RenderPipeline<'_> {
             self.dirty_surface.canvas().clear(self.background);
         }
 
+        // Render the dirty nodes
+        let mut node_renderer = NodeRenderer {
+            canvas_area: self.canvas_area,
+            canvas: self.dirty_surface.canvas(),
+            rdom: self.rdom,
+            layout: self.layout,
+            font_collection: self.font_collection,
+            font_manager: self.font_manager,
+            default_fonts: self.default_fonts,
+            scale_factor: self.scale_factor,
+        };
+        #[allow(unused_variables)]
+        let painted = node_renderer.render_layers(rendering_layers, self.selected_node);
+
         #[cfg(debug_assertions)]
-        // Counter of painted nodes for debugging purposes
+        {
+            if painted > 0 {
+                tracing::info!("Painted {painted} nodes");
+            }
+        }
+
+        // Copy the dirty canvas into the main canvas
+        self.dirty_surface.canvas().restore();
+        self.surface.canvas().clear(self.background);
+        self.dirty_surface.draw(
+            self.surface.canvas(),
+            (0, 0),
+            SamplingOptions::default(),
+            None,
+        );
+
+        self.compositor_dirty_nodes.clear();
+    }
+}
+
+/// Renders nodes into a canvas, e.g the one of a surface or of a PDF document.
+pub struct NodeRenderer<'a> {
+    pub canvas_area: Area,
+    pub canvas: &'a Canvas,
+    pub rdom: &'a DioxusDOM,
+    pub layout: &'a Torin<NodeId>,
+    pub font_collection: &'a mut FontCollection,
+    pub font_manager: &'a FontMgr,
+    pub default_fonts: &'a [String],
+    pub scale_factor: f32,
+}
+
+impl NodeRenderer<'_> {
+    /// Render the nodes of the given layers, from the bottom layer to the top one.
+    /// Returns how many nodes were painted.
+    pub fn render_layers(&mut self, layers: &Layers, selected_node: Option<NodeId>) -> usize {
         let mut painted = 0;
 
-        // Render the dirty nodes
-        for (_, nodes) in sorted(rendering_layers.iter()) {
+        for (_, nodes) in sorted(layers.iter()) {
             'elements: for node_id in nodes {
                 let node_ref = self.rdom.get(*node_id).unwrap();
                 let node_viewports = node_ref.get::<ViewportState>().unwrap();
@@ -152,37 +201,17 @@ impl RenderPipeline<'_> {
                         }
                     }
 
-                    let render_wireframe = Some(node_id) == self.selected_node.as_ref();
+                    let render_wireframe = Some(*node_id) == selected_node;
 
                     // Render the element
                     self.render(node_ref, layout_node, render_wireframe);
 
-                    #[cfg(debug_assertions)]
-                    {
-                        painted += 1;
-                    }
+                    painted += 1;
                 }
             }
         }
 
-        #[cfg(debug_assertions)]
-        {
-            if painted > 0 {
-                tracing::info!("Painted {painted} nodes");
-            }
-        }
-
-        // Copy the dirty canvas into the main canvas
-        self.dirty_surface.canvas().restore();
-        self.surface.canvas().clear(self.background);
-        self.dirty_surface.draw(
-            self.surface.canvas(),
-            (0, 0),
-            SamplingOptions::default(),
-            None,
-        );
-
-        self.compositor_dirty_nodes.clear();
+        painted
     }
 
     pub fn render(
@@ -191,7 +220,6 @@ impl RenderPipeline<'_> {
         layout_node: &LayoutNode,
         render_wireframe: bool,
     ) {
-        let dirty_canvas = self.dirty_surface.canvas();
         let area = layout_node.visible_area();
         let node_type = &*node_ref.node_type();
         if let NodeType::Element(ElementNode { tag, .. }) = node_type {
@@ -199,7 +227,7 @@ impl RenderPipeline<'_> {
                 return;
             };
 
-            let initial_layer = dirty_canvas.save();
+            let initial_layer = self.canvas.save();
             let node_transform = &*node_ref.get::<TransformState>().unwrap();
 
             // Pass rotate effect to children
@@ -214,12 +242,12 @@ impl RenderPipeline<'_> {
                         y: area.min_y() + area.height() / 2.0,
                     }),
                 );
-                dirty_canvas.concat(&matrix);
+                self.canvas.concat(&matrix);
             }
 
             // Apply inherited opacity effects
             for opacity in &node_transform.opacities {
-                dirty_canvas.save_layer_alpha_f(
+                self.canvas.save_layer_alpha_f(
                     Rect::new(
                         self.canvas_area.min_x(),
                         self.canvas_area.min_y(),
@@ -236,7 +264,7 @@ impl RenderPipeline<'_> {
             // it will render the inner text spans on it's own, so if these spans overflow the paragraph,
             // It is the paragraph job to make sure they are clipped
             if !node_viewports.viewports.is_empty() && *tag == TagName::Paragraph {
-                element_utils.clip(layout_node, &node_ref, dirty_canvas, self.scale_factor);
+                element_utils.clip(layout_node, &node_ref, self.canvas, self.scale_factor);
             }
 
             for node_id in &node_viewports.viewports {
@@ -246,13 +274,13 @@ impl RenderPipeline<'_> {
                     continue;
                 };
                 let layout_node = self.layout.get(*node_id).unwrap();
-                element_utils.clip(layout_node, &node_ref, dirty_canvas, self.scale_factor);
+                element_utils.clip(layout_node, &node_ref, self.canvas, self.scale_factor);
             }
 
             element_utils.render(
                 layout_node,
                 &node_ref,
-                dirty_canvas,
+                self.canvas,
                 self.font_collection,
                 self.font_manager,
                 self.default_fonts,
@@ -260,10 +288,10 @@ impl RenderPipeline<'_> {
             );
 
             if render_wireframe {
-                wireframe_renderer::render_wireframe(dirty_canvas, &area);
+                wireframe_renderer::render_wireframe(self.canvas, &area);
             }
 
-            dirty_canvas.restore_to_count(initial_layer);
+            self.canvas.restore_to_count(initial_layer);
         }
     }
 }
@@ -243,6 +243,7 @@ pub fn create_paragraph(
 
     let node_children = node.children();
     let node_children_len = node_children.len();
+    let mut last_span_style = None;
 
     for text_span in node_children {
         if let NodeType::Element(ElementNode {
@@ -259,10 +260,22 @@ pub fn create_paragraph(
             if let NodeType::Text(text) = text_node_type {
                 paragraph_builder.add_text(text);
             }
+
+            last_span_style = font_style
+                .background
+                .is_some()
+                .then(|| (*font_style).clone());
         }
     }
 
     if is_rendering {
+        // Don't paint the background of the last span behind the extra space
+        if let Some(mut font_style) = last_span_style {
+            font_style.background = None;
+            let text_style = font_style.text_style(default_font_family, scale_factor, true);
+            paragraph_builder.push_style(&text_style);
+        }
+
         // This is very tricky, but it works! It allows freya to render the cursor at the end of a line.
         paragraph_builder.add_text(" ");
     }
@@ -1,7 +1,13 @@
-pub use accesskit::NodeId as AccessibilityId;
+pub use accesskit::{
+    Live,
+    NodeId as AccessibilityId,
+    Role,
+    Toggled,
+};
 use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 use tokio::sync::{
+    broadcast,
     mpsc::{
         UnboundedReceiver,
         UnboundedSender,
@@ -13,6 +19,7 @@ use crate::{
     events::DomEvent,
     prelude::{
         EventName,
+        MenuEvent,
         NativePlatformState,
         PlatformEvent,
         PotentialEvent,
@@ -25,6 +32,9 @@ pub type NativePlatformSender = watch::Sender<NativePlatformState>;
 /// Receive updates by the platform
 pub type NativePlatformReceiver = watch::Receiver<NativePlatformState>;
 
+/// Send the activations of native menu items to the VirtualDOM
+pub type MenuEventsSender = broadcast::Sender<MenuEvent>;
+
 /// Emit events to the VirtualDOM
 pub type EventEmitter = UnboundedSender<Vec<DomEvent>>;
 
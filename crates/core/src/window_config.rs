@@ -0,0 +1,194 @@
+use std::{
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+    time::Duration,
+};
+
+use freya_engine::prelude::Color;
+use winit::{
+    dpi::{
+        PhysicalPosition,
+        PhysicalSize,
+    },
+    window::{
+        Icon,
+        Window,
+        WindowAttributes,
+    },
+};
+
+pub type WindowBuilderHook = Box<dyn Fn(WindowAttributes) -> WindowAttributes + Send + Sync>;
+
+pub type WindowCallback = Arc<Box<fn(&mut Window)>>;
+
+/// Graphics backend used to render a Window.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GraphicsBackend {
+    /// Use OpenGL, or render with the CPU if OpenGL is not available.
+    #[default]
+    Auto,
+    /// Use OpenGL, an error is logged and the Window is rendered with the CPU if OpenGL is not available.
+    OpenGl,
+    /// Always render with the CPU, slower but works without a GPU.
+    Software,
+}
+
+/// Configuration for a Window.
+pub struct WindowConfig {
+    /// Size of the Window.
+    pub size: (f64, f64),
+    /// Minimum size of the Window.
+    pub min_size: Option<(f64, f64)>,
+    /// Maximum size of the Window.
+    pub max_size: Option<(f64, f64)>,
+    /// Position of the Window in the desktop, centered by the platform if not specified.
+    pub position: Option<(f64, f64)>,
+    /// Allow resizing the Window.
+    pub resizable: bool,
+    /// Keep the Window above the other Windows.
+    pub always_on_top: bool,
+    /// Enable Window decorations.
+    pub decorations: bool,
+    /// Title for the Window.
+    pub title: &'static str,
+    /// Make the Window transparent or not.
+    pub transparent: bool,
+    /// Background color of the Window.
+    pub background: Color,
+    /// The Icon of the Window.
+    pub icon: Option<Icon>,
+    /// Setup callback.
+    pub on_setup: Option<WindowCallback>,
+    /// Exit callback.
+    pub on_exit: Option<WindowCallback>,
+    /// Hook function called with the Window Attributes.
+    pub window_attributes_hook: Option<WindowBuilderHook>,
+    /// Graphics backend used to render the Window.
+    pub graphics_backend: GraphicsBackend,
+    /// File where the geometry of the Window is saved when closed and restored from when opened.
+    pub persisted_geometry: Option<PathBuf>,
+    /// Hide the Window instead of closing it when the user requests it, e.g to keep the app in the tray.
+    pub hide_on_close: bool,
+    /// Maximum time between two clicks of a double click, the one of the platform is used if not specified.
+    pub double_click_duration: Option<Duration>,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            size: (600.0, 600.0),
+            min_size: None,
+            max_size: None,
+            position: None,
+            resizable: true,
+            always_on_top: false,
+            decorations: true,
+            title: "Freya app",
+            transparent: false,
+            background: Color::WHITE,
+            icon: None,
+            on_setup: None,
+            on_exit: None,
+            window_attributes_hook: None,
+            graphics_backend: GraphicsBackend::default(),
+            persisted_geometry: None,
+            hide_on_close: false,
+            double_click_duration: None,
+        }
+    }
+}
+
+/// Position, size and state of a Window, in physical pixels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WindowGeometry {
+    pub position: PhysicalPosition<i32>,
+    pub size: PhysicalSize<u32>,
+    pub is_maximized: bool,
+}
+
+impl WindowGeometry {
+    /// Get the geometry of the Window in its normal state, so `None` if it is minimized or maximized,
+    /// as the position and size of a maximized Window are not the ones it must be restored with.
+    pub fn from_winit(window: &Window) -> Option<Self> {
+        if window.is_minimized().unwrap_or_default() || window.is_maximized() {
+            return None;
+        }
+        Some(Self {
+            position: window.outer_position().ok()?,
+            size: window.inner_size(),
+            is_maximized: false,
+        })
+    }
+
+    /// Load a geometry saved with [`WindowGeometry::save`].
+    pub fn load(path: impl AsRef<Path>) -> Option<Self> {
+        let content = fs::read_to_string(path).ok()?;
+        let mut values = content.trim().split(',');
+        let mut next = || {
+            values
+                .next()
+                .and_then(|value| value.trim().parse::<i64>().ok())
+        };
+
+        let position = PhysicalPosition::new(next()? as i32, next()? as i32);
+        let size = PhysicalSize::new(next()?.try_into().ok()?, next()?.try_into().ok()?);
+        let is_maximized = next()? == 1;
+
+        Some(Self {
+            position,
+            size,
+            is_maximized,
+        })
+    }
+
+    /// Save the geometry into a file, creating its parent directories if necessary.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(
+            path,
+            format!(
+                "{},{},{},{},{}",
+                self.position.x,
+                self.position.y,
+                self.size.width,
+                self.size.height,
+                self.is_maximized as u8
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn window_geometry_roundtrip() {
+        let path = env::temp_dir()
+            .join(format!("freya-window-geometry-test-{}", std::process::id()))
+            .join("geometry");
+        let geometry = WindowGeometry {
+            position: PhysicalPosition::new(-1920, 40),
+            size: PhysicalSize::new(800, 600),
+            is_maximized: true,
+        };
+
+        geometry.save(&path).unwrap();
+        assert_eq!(WindowGeometry::load(&path), Some(geometry));
+
+        fs::write(&path, "100,100,-5,600,0").unwrap();
+        assert_eq!(WindowGeometry::load(&path), None);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,221 @@
+use freya::prelude::*;
+use freya_core::accessibility::ACCESSIBILITY_ROOT_ID;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn capture_events_order() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<&str>::new);
+        let text = events.read().join(", ");
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                oncapturemousedown: move |_| events.write().push("capture parent"),
+                onmousedown: move |_| events.write().push("parent"),
+                rect {
+                    height: "100",
+                    width: "100",
+                    background: "red",
+                    oncapturemousedown: move |_| events.write().push("capture child"),
+                    onmousedown: move |_| events.write().push("child"),
+                }
+                label {
+                    "{text}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(1);
+
+    utils.click_cursor((50., 50.)).await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some("capture parent, capture child, child, parent")
+    );
+}
+
+#[tokio::test]
+pub async fn capture_stop_propagation() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<&str>::new);
+        let text = events.read().join(", ");
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                oncapturemousedown: move |e| {
+                    events.write().push("capture parent");
+                    e.stop_propagation();
+                },
+                onglobalmousedown: move |_| events.write().push("global"),
+                rect {
+                    height: "100",
+                    width: "100",
+                    background: "red",
+                    oncapturemousedown: move |_| events.write().push("capture child"),
+                    onmousedown: move |_| events.write().push("child"),
+                }
+                label {
+                    "{text}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(1);
+
+    utils.click_cursor((50., 50.)).await;
+
+    // Only the global listeners receive a stopped event
+    assert_eq!(label.get(0).text(), Some("capture parent, global"));
+}
+
+#[tokio::test]
+pub async fn capture_stop_propagation_batch() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<&str>::new);
+        let text = events.read().join(", ");
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                oncaptureclick: move |e| {
+                    events.write().push("capture click");
+                    e.stop_propagation();
+                },
+                onclick: move |_| events.write().push("click"),
+                rect {
+                    height: "100",
+                    width: "100",
+                    onmouseleave: move |_| events.write().push("leave"),
+                }
+                label {
+                    "{text}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(1);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseMove,
+        cursor: (50., 50.).into(),
+        button: None,
+    });
+    utils.wait_for_update().await;
+
+    // Leave the child and click in the same batch of events
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseMove,
+        cursor: (150., 150.).into(),
+        button: None,
+    });
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseUp,
+        cursor: (150., 150.).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // Stopping the click doesn't stop the events of the cursor movement
+    assert_eq!(label.get(0).text(), Some("capture click, leave"));
+}
+
+#[tokio::test]
+pub async fn prevent_default_focus_navigation() {
+    #[allow(non_snake_case)]
+    fn Child() -> Element {
+        let mut focus = use_focus();
+
+        rsx!(rect {
+            a11y_id: focus.attribute(),
+            width: "100%",
+            height: "50%",
+            onclick: move |_| focus.focus(),
+        })
+    }
+
+    fn app() -> Element {
+        let mut trap_focus = use_signal(|| true);
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                oncapturekeydown: move |e| {
+                    if e.key == Key::Tab && *trap_focus.read() {
+                        e.prevent_default();
+                    }
+                },
+                onglobalkeydown: move |e| {
+                    if e.key == Key::Escape {
+                        trap_focus.set(false);
+                    }
+                },
+                Child {},
+                Child {}
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        app,
+        TestingConfig {
+            size: (100.0, 100.0).into(),
+            ..TestingConfig::default()
+        },
+    );
+
+    // Focus the first child
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    let first_focus_id = utils.focus_id();
+    assert_ne!(first_focus_id, ACCESSIBILITY_ROOT_ID);
+
+    // The focus navigation is prevented
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Tab,
+        code: Code::Tab,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(utils.focus_id(), first_focus_id);
+
+    // Stop trapping the focus
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Escape,
+        code: Code::Escape,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+
+    // The focus navigation works again
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Tab,
+        code: Code::Tab,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_ne!(utils.focus_id(), first_focus_id);
+}
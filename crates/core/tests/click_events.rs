@@ -0,0 +1,123 @@
+use freya::prelude::*;
+use freya_core::events::{
+    DOUBLE_CLICK_DURATION,
+    LONG_PRESS_DURATION,
+};
+use freya_testing::prelude::*;
+use tokio::time::sleep;
+
+#[tokio::test]
+pub async fn double_click() {
+    fn app() -> Element {
+        let mut click_count = use_signal(|| 0);
+        let mut double_clicks = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick: move |e: MouseEvent| click_count.set(e.get_click_count()),
+                ondoubleclick: move |_| double_clicks += 1,
+                label {
+                    "{click_count}:{double_clicks}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(0).get(0);
+
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(label.get(0).text(), Some("1:0"));
+
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(label.get(0).text(), Some("2:1"));
+
+    // Triple clicks are not double clicks
+    utils.click_cursor((6., 5.)).await;
+    assert_eq!(label.get(0).text(), Some("3:1"));
+
+    // Far away clicks start a new sequence
+    utils.click_cursor((50., 50.)).await;
+    assert_eq!(label.get(0).text(), Some("1:1"));
+
+    // Late clicks start a new sequence
+    sleep(DOUBLE_CLICK_DURATION).await;
+    utils.click_cursor((50., 50.)).await;
+    assert_eq!(label.get(0).text(), Some("1:1"));
+}
+
+#[tokio::test]
+pub async fn long_press() {
+    fn app() -> Element {
+        let mut long_presses = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onlongpress: move |_| long_presses += 1,
+                label {
+                    "{long_presses}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(0).get(0);
+
+    let long_press = PlatformEvent::Mouse {
+        name: EventName::LongPress,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    };
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // Too early
+    utils.push_event(long_press.clone());
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("0"));
+
+    sleep(LONG_PRESS_DURATION).await;
+    utils.push_event(long_press.clone());
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1"));
+
+    // Only emitted once per press
+    utils.push_event(long_press.clone());
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseUp,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseMove,
+        cursor: (50.0, 50.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // The pointer moved while being pressed
+    sleep(LONG_PRESS_DURATION).await;
+    utils.push_event(long_press);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1"));
+}
@@ -55,7 +55,7 @@ pub async fn filedrop_events() {
     utils.push_event(PlatformEvent::File {
         name: EventName::GlobalFileHover,
         cursor: (5., 5.).into(),
-        file_path: None,
+        file_paths: vec![],
     });
 
     utils.wait_for_update().await;
@@ -65,7 +65,7 @@ pub async fn filedrop_events() {
     utils.push_event(PlatformEvent::File {
         name: EventName::FileDrop,
         cursor: (5., 5.).into(),
-        file_path: Some(PathBuf::from_str("/nice/path/right.rs").unwrap()),
+        file_paths: vec![PathBuf::from_str("/nice/path/right.rs").unwrap()],
     });
 
     utils.wait_for_update().await;
@@ -76,3 +76,49 @@ pub async fn filedrop_events() {
     );
     assert_eq!(root.get(0).style().background, Fill::Color(Color::BLUE));
 }
+
+#[tokio::test]
+pub async fn multiple_files_drop() {
+    fn multiple_files_drop_app() -> Element {
+        let mut hovered = use_signal(|| false);
+        let mut dropped = use_signal(String::new);
+
+        rsx!(
+            rect {
+                height: "50%",
+                width: "100%",
+                onfilehover: move |_| hovered.set(true),
+                onfiledrop: move |e| {
+                    let paths = e
+                        .get_file_paths()
+                        .iter()
+                        .map(|path| path.to_string_lossy().to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let coords = e.get_element_coordinates();
+                    dropped.set(format!("{paths} at {},{}", coords.x, coords.y));
+                },
+                label {
+                    "{hovered}"
+                }
+            }
+            label {
+                "{dropped}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(multiple_files_drop_app);
+
+    let root = utils.root();
+
+    // Hovering outside the element does not emit the element event
+    utils.hover_files((5., 400.), ["/a.rs"]).await;
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("false"));
+
+    utils.hover_files((5., 5.), ["/a.rs"]).await;
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("true"));
+
+    utils.drop_files((10., 20.), ["/a.rs", "/b.rs"]).await;
+    assert_eq!(root.get(1).get(0).text(), Some("/a.rs, /b.rs at 10,20"));
+}
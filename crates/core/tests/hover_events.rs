@@ -0,0 +1,178 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn overflowing_child_keeps_parent_hovered() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<&str>::new);
+        let text = events.read().join(", ");
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                onpointerenter: move |_| events.write().push("enter"),
+                onpointerleave: move |_| events.write().push("leave"),
+                rect {
+                    height: "50",
+                    width: "200",
+                    background: "red",
+                }
+            }
+            label {
+                "{text}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(1);
+
+    utils.move_cursor((50., 25.)).await;
+    assert_eq!(label.get(0).text(), Some("enter"));
+
+    // The cursor is outside the parent but still over its child
+    utils.move_cursor((150., 25.)).await;
+    assert_eq!(label.get(0).text(), Some("enter"));
+
+    utils.move_cursor((150., 75.)).await;
+    assert_eq!(label.get(0).text(), Some("enter, leave"));
+}
+
+#[tokio::test]
+pub async fn only_top_most_sibling_is_hovered() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<&str>::new);
+        let text = events.read().join(", ");
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                rect {
+                    position: "absolute",
+                    height: "100",
+                    width: "100",
+                    onmouseenter: move |_| events.write().push("bottom enter"),
+                }
+                rect {
+                    position: "absolute",
+                    height: "100",
+                    width: "100",
+                    layer: "-1",
+                    onmouseenter: move |_| events.write().push("top enter"),
+                    onmouseleave: move |_| events.write().push("top leave"),
+                }
+            }
+            label {
+                "{text}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(1);
+
+    utils.move_cursor((50., 50.)).await;
+    assert_eq!(label.get(0).text(), Some("top enter"));
+
+    utils.move_cursor((150., 150.)).await;
+    assert_eq!(label.get(0).text(), Some("top enter, top leave"));
+}
+
+#[tokio::test]
+pub async fn pointer_events_none() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<&str>::new);
+        let text = events.read().join(", ");
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                onmouseenter: move |_| events.write().push("enter"),
+                onclick: move |_| events.write().push("click"),
+                rect {
+                    position: "absolute",
+                    height: "100",
+                    width: "100",
+                    layer: "-1",
+                    background: "rgb(0, 0, 0, 50)",
+                    pointer_events: "none",
+                    onclick: move |_| events.write().push("overlay click"),
+                    rect {
+                        height: "50",
+                        width: "50",
+                        pointer_events: "auto",
+                        onclick: move |_| events.write().push("inner click"),
+                    }
+                }
+            }
+            label {
+                "{text}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(1);
+
+    // The overlay is transparent to the pointer
+    utils.move_cursor((75., 75.)).await;
+    utils.click_cursor((75., 75.)).await;
+    assert_eq!(label.get(0).text(), Some("enter, click"));
+
+    // Unless an inner element opts in again
+    utils.click_cursor((25., 25.)).await;
+    assert_eq!(
+        label.get(0).text(),
+        Some("enter, click, inner click, overlay click, click")
+    );
+}
+
+#[tokio::test]
+pub async fn pointer_over_and_out() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<&str>::new);
+        let text = events.read().join(", ");
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                onpointerover: move |_| events.write().push("over"),
+                onpointerout: move |_| events.write().push("out"),
+                onpointerenter: move |_| events.write().push("enter"),
+                rect {
+                    height: "50",
+                    width: "100",
+                }
+                rect {
+                    height: "50",
+                    width: "100",
+                }
+            }
+            label {
+                "{text}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(1);
+
+    utils.move_cursor((50., 25.)).await;
+    assert_eq!(label.get(0).text(), Some("over, enter"));
+
+    // Moving inside the same element does nothing
+    utils.move_cursor((60., 25.)).await;
+    assert_eq!(label.get(0).text(), Some("over, enter"));
+
+    // Moving between the children bubbles up to the parent
+    utils.move_cursor((50., 75.)).await;
+    assert_eq!(label.get(0).text(), Some("over, enter, out, over"));
+}
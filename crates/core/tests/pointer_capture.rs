@@ -0,0 +1,311 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn pointer_capture() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<String>::new);
+        let text = events.read().join(", ");
+
+        rsx!(
+            rect {
+                height: "50",
+                width: "100%",
+            }
+            rect {
+                height: "100",
+                width: "100",
+                background: "red",
+                onpointerdown: move |e| {
+                    e.set_pointer_capture();
+                    events.write().push("down".to_string());
+                },
+                onpointermove: move |e| {
+                    let coordinates = e.get_element_coordinates();
+                    events.write().push(format!("move {}x{}", coordinates.x, coordinates.y));
+                },
+                onpointerup: move |_| events.write().push("up".to_string()),
+                onlostpointercapture: move |_| events.write().push("lost".to_string()),
+            }
+            label {
+                "{text}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(2);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (50.0, 100.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // The pointer is outside the element but it's still captured
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseMove,
+        cursor: (300.0, 350.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("down, move 300x300"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseUp,
+        cursor: (400.0, 400.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("down, move 300x300, up, lost"));
+
+    // The capture was released
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseMove,
+        cursor: (300.0, 300.0).into(),
+        button: None,
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("down, move 300x300, up, lost"));
+}
+
+#[tokio::test]
+pub async fn release_pointer_capture() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<&str>::new);
+        let text = events.read().join(", ");
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                background: "red",
+                onpointerdown: move |e| {
+                    e.set_pointer_capture();
+                    events.write().push("down");
+                },
+                onpointermove: move |e| {
+                    e.release_pointer_capture();
+                    events.write().push("move");
+                },
+                onlostpointercapture: move |_| events.write().push("lost"),
+            }
+            label {
+                "{text}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(1);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (50.0, 50.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseMove,
+        cursor: (300.0, 300.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("down, move, lost"));
+
+    // The element no longer receives the pointer events from outside
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseMove,
+        cursor: (350.0, 300.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("down, move, lost"));
+}
+
+#[tokio::test]
+pub async fn pointer_capture_from_parent() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<&str>::new);
+        let text = events.read().join(", ");
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                onpointerdown: move |e| {
+                    e.set_pointer_capture();
+                    events.write().push("parent down");
+                },
+                onpointermove: move |_| events.write().push("parent move"),
+                onlostpointercapture: move |_| events.write().push("parent lost"),
+                rect {
+                    height: "50",
+                    width: "50",
+                    onpointerdown: move |_| events.write().push("child down"),
+                    onlostpointercapture: move |_| events.write().push("child lost"),
+                }
+            }
+            label {
+                "{text}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(1);
+
+    // The child is the target but the parent requests the capture
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (25.0, 25.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseMove,
+        cursor: (300.0, 300.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseUp,
+        cursor: (300.0, 300.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some("child down, parent down, parent move, parent lost")
+    );
+}
+
+#[tokio::test]
+pub async fn pointer_capture_stopped_propagation() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<&str>::new);
+        let text = events.read().join(", ");
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                onpointerdown: move |e| {
+                    e.set_pointer_capture();
+                    events.write().push("parent down");
+                },
+                onpointermove: move |_| events.write().push("parent move"),
+                rect {
+                    height: "50",
+                    width: "50",
+                    onpointerdown: move |e| {
+                        e.stop_propagation();
+                        events.write().push("child down");
+                    },
+                }
+            }
+            label {
+                "{text}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(1);
+
+    // The child stops the propagation so the parent never requests the capture
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (25.0, 25.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseMove,
+        cursor: (300.0, 300.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("child down"));
+}
+
+#[tokio::test]
+pub async fn pointer_capture_mouse_events() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::<&str>::new);
+        let text = events.read().join(", ");
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                onpointerdown: move |e| e.set_pointer_capture(),
+                onmousemove: move |_| events.write().push("move"),
+                onmouseup: move |_| events.write().push("up"),
+            }
+            rect {
+                height: "100",
+                width: "100",
+                onmousemove: move |_| events.write().push("other move"),
+                onmouseup: move |_| events.write().push("other up"),
+            }
+            label {
+                "{text}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(2);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (50.0, 50.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // The mouse events go to the element capturing the pointer, not the one under the cursor
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseMove,
+        cursor: (50.0, 150.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseUp,
+        cursor: (50.0, 150.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("move, up"));
+
+    // The capture was released
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseMove,
+        cursor: (50.0, 160.0).into(),
+        button: None,
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("move, up, other move"));
+}
@@ -17,3 +17,6 @@
 //! - `decoration_style`
 //! - `decoration_color`
 //! - `text_shadow`
+//! - `baseline_shift`
+//! - `font_features`
+//! - `pointer_events`
@@ -207,6 +207,8 @@ builder_constructors! {
         font_weight: String,
         #[doc = include_str!("_docs/attributes/font_width.md")]
         font_width: String,
+        #[doc = include_str!("_docs/attributes/font_features.md")]
+        font_features: String,
         #[doc = include_str!("_docs/attributes/main_align_cross_align.md")]
         main_align: String,
         cross_align: String,
@@ -226,6 +228,8 @@ builder_constructors! {
         position_left: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/pointer_events.md")]
+        pointer_events: String,
         #[doc = include_str!("_docs/attributes/content.md")]
         content: String,
         #[doc = include_str!("_docs/attributes/line_height.md")]
@@ -239,6 +243,25 @@ builder_constructors! {
         a11y_id: AccessibilityId,
         a11y_alt: String,
         a11y_focusable: String,
+        #[doc = include_str!("_docs/attributes/a11y_focus_trap.md")]
+        a11y_focus_trap: String,
+        #[doc = include_str!("_docs/attributes/a11y_live.md")]
+        a11y_live: String,
+        #[doc = include_str!("_docs/attributes/a11y_states.md")]
+        a11y_checked: String,
+        a11y_expanded: String,
+        a11y_selected: String,
+        a11y_disabled: String,
+        #[doc = include_str!("_docs/attributes/a11y_numeric_value.md")]
+        a11y_numeric_value: String,
+        a11y_min_numeric_value: String,
+        a11y_max_numeric_value: String,
+        a11y_numeric_value_step: String,
+        #[doc = include_str!("_docs/attributes/a11y_labelled_by_described_by.md")]
+        a11y_labelled_by: AccessibilityId,
+        a11y_described_by: AccessibilityId,
+        #[doc = include_str!("_docs/attributes/a11y_keyboard_shortcut.md")]
+        a11y_keyboard_shortcut: String,
         canvas_reference: String,
         layer: String,
         offset_y: String,
@@ -280,6 +303,8 @@ builder_constructors! {
         font_weight: String,
         #[doc = include_str!("_docs/attributes/font_width.md")]
         font_width: String,
+        #[doc = include_str!("_docs/attributes/font_features.md")]
+        font_features: String,
         #[doc = include_str!("_docs/attributes/text_align.md")]
         text_align: String,
         #[doc = include_str!("_docs/attributes/max_lines.md")]
@@ -304,6 +329,8 @@ builder_constructors! {
         margin: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/pointer_events.md")]
+        pointer_events: String,
 
         layer: String,
         a11y_auto_focus: String,
@@ -312,6 +339,18 @@ builder_constructors! {
         a11y_id: AccessibilityId,
         a11y_alt: String,
         a11y_focusable: String,
+        a11y_live: String,
+        a11y_checked: String,
+        a11y_expanded: String,
+        a11y_selected: String,
+        a11y_disabled: String,
+        a11y_numeric_value: String,
+        a11y_min_numeric_value: String,
+        a11y_max_numeric_value: String,
+        a11y_numeric_value_step: String,
+        a11y_labelled_by: AccessibilityId,
+        a11y_described_by: AccessibilityId,
+        a11y_keyboard_shortcut: String,
     };
     /// `paragraph` element let's you build texts with different styles.
     ///
@@ -360,6 +399,8 @@ builder_constructors! {
         font_weight: String,
         #[doc = include_str!("_docs/attributes/font_width.md")]
         font_width: String,
+        #[doc = include_str!("_docs/attributes/font_features.md")]
+        font_features: String,
         #[doc = include_str!("_docs/attributes/line_height.md")]
         line_height: String,
         #[doc = include_str!("_docs/attributes/letter_spacing.md")]
@@ -378,6 +419,8 @@ builder_constructors! {
         margin: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/pointer_events.md")]
+        pointer_events: String,
 
         layer: String,
         cursor_index: String,
@@ -391,6 +434,18 @@ builder_constructors! {
         a11y_id: AccessibilityId,
         a11y_alt: String,
         a11y_focusable: String,
+        a11y_live: String,
+        a11y_checked: String,
+        a11y_expanded: String,
+        a11y_selected: String,
+        a11y_disabled: String,
+        a11y_numeric_value: String,
+        a11y_min_numeric_value: String,
+        a11y_max_numeric_value: String,
+        a11y_numeric_value_step: String,
+        a11y_labelled_by: AccessibilityId,
+        a11y_described_by: AccessibilityId,
+        a11y_keyboard_shortcut: String,
         highlights: String,
         highlight_color: String,
         highlight_mode: String,
@@ -414,6 +469,8 @@ builder_constructors! {
         font_weight: String,
         #[doc = include_str!("_docs/attributes/font_width.md")]
         font_width: String,
+        #[doc = include_str!("_docs/attributes/font_features.md")]
+        font_features: String,
         #[doc = include_str!("_docs/attributes/line_height.md")]
         line_height: String,
         #[doc = include_str!("_docs/attributes/letter_spacing.md")]
@@ -426,6 +483,10 @@ builder_constructors! {
         decoration_style: String,
         #[doc = include_str!("_docs/attributes/decoration_color.md")]
         decoration_color: String,
+        #[doc = include_str!("_docs/attributes/text_background.md")]
+        background: String,
+        #[doc = include_str!("_docs/attributes/baseline_shift.md")]
+        baseline_shift: String,
     };
     /// `image` element let's you show an image.
     ///
@@ -454,6 +515,8 @@ builder_constructors! {
         rotate: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/pointer_events.md")]
+        pointer_events: String,
 
         image_data: String,
         image_reference: String,
@@ -462,6 +525,18 @@ builder_constructors! {
         a11y_role:String,
         a11y_id: AccessibilityId,
         a11y_alt: String,
+        a11y_live: String,
+        a11y_checked: String,
+        a11y_expanded: String,
+        a11y_selected: String,
+        a11y_disabled: String,
+        a11y_numeric_value: String,
+        a11y_min_numeric_value: String,
+        a11y_max_numeric_value: String,
+        a11y_numeric_value_step: String,
+        a11y_labelled_by: AccessibilityId,
+        a11y_described_by: AccessibilityId,
+        a11y_keyboard_shortcut: String,
     };
     /// `svg` element let's you display SVG code.
     ///
@@ -493,6 +568,8 @@ builder_constructors! {
         rotate: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/pointer_events.md")]
+        pointer_events: String,
 
         svg_data: String,
         svg_content: String,
@@ -502,11 +579,26 @@ builder_constructors! {
         a11y_id: AccessibilityId,
         a11y_alt: String,
         a11y_focusable: String,
+        a11y_live: String,
+        a11y_checked: String,
+        a11y_expanded: String,
+        a11y_selected: String,
+        a11y_disabled: String,
+        a11y_numeric_value: String,
+        a11y_min_numeric_value: String,
+        a11y_max_numeric_value: String,
+        a11y_numeric_value_step: String,
+        a11y_labelled_by: AccessibilityId,
+        a11y_described_by: AccessibilityId,
+        a11y_keyboard_shortcut: String,
     };
 }
 
 pub mod events {
-    use std::any::Any;
+    use std::{
+        any::Any,
+        rc::Rc,
+    };
 
     use crate::events::*;
 
@@ -533,11 +625,24 @@ pub mod events {
     #[doc(hidden)]
     pub struct PlatformEventData {
         event: Box<dyn Any>,
+        propagation: Option<Rc<EventPropagation>>,
     }
 
     impl PlatformEventData {
         pub fn new(event: Box<dyn Any>) -> Self {
-            Self { event }
+            Self {
+                event,
+                propagation: None,
+            }
+        }
+
+        pub fn with_propagation(mut self, propagation: Rc<EventPropagation>) -> Self {
+            self.propagation = Some(propagation);
+            self
+        }
+
+        pub fn propagation(&self) -> Option<Rc<EventPropagation>> {
+            self.propagation.clone()
         }
 
         pub fn downcast<T: 'static>(&self) -> Option<&T> {
@@ -568,7 +673,35 @@ pub mod events {
                     ::dioxus_core::Attribute::new(
                         stringify!($name),
     ::dioxus_core::AttributeValue::listener(move |e: ::dioxus_core::Event<PlatformEventData>| {
-                            _f(e.map(|e|e.into())).spawn();
+                            if let Some(result) = with_propagation(e.propagation(), || _f(e.map(|e|e.into()))) {
+                                result.spawn();
+                            }
+                        }),
+                        None,
+                        false,
+                    ).into()
+                }
+            )*
+        };
+    }
+
+    macro_rules! impl_capture_event {
+        (
+            $data:ty;
+            $(
+                $( #[$attr:meta] )*
+                $name:ident $(: $js_name:literal)?
+            )*
+        ) => {
+            $(
+                $( #[$attr] )*
+                #[inline]
+                pub fn $name<E: EventReturn<T>, T>(mut _f: impl FnMut(CaptureEvent<$data>) -> E + 'static) -> ::dioxus_core::Attribute {
+                    ::dioxus_core::Attribute::new(
+                        stringify!($name),
+    ::dioxus_core::AttributeValue::listener(move |e: ::dioxus_core::Event<PlatformEventData>| {
+                            let propagation = e.propagation().unwrap_or_default();
+                            _f(CaptureEvent::new(e.map(|e| e.into()).data, propagation)).spawn();
                         }),
                         None,
                         false,
@@ -589,6 +722,10 @@ pub mod events {
         onmiddleclick
         #[doc = include_str!("_docs/events/rightclick.md")]
         onrightclick
+        #[doc = include_str!("_docs/events/doubleclick.md")]
+        ondoubleclick
+        #[doc = include_str!("_docs/events/longpress.md")]
+        onlongpress
         #[doc = include_str!("_docs/events/mouseup.md")]
         onmouseup
         #[doc = include_str!("_docs/events/mousedown.md")]
@@ -612,6 +749,20 @@ pub mod events {
         onwheel
     ];
 
+    impl_event! [
+        PinchData;
+
+        #[doc = include_str!("_docs/events/pinch.md")]
+        onpinch
+    ];
+
+    impl_event! [
+        RotationData;
+
+        #[doc = include_str!("_docs/events/rotation.md")]
+        onrotation
+    ];
+
     impl_event! [
         KeyboardData;
 
@@ -625,6 +776,30 @@ pub mod events {
         onglobalkeyup
     ];
 
+    impl_event! [
+        AccessibilityActionData;
+
+        #[doc = include_str!("_docs/events/a11yaction.md")]
+        ona11yaction
+    ];
+
+    impl_event! [
+        GamepadData;
+
+        #[doc = include_str!("_docs/events/gamepadbuttondown.md")]
+        ongamepadbuttondown
+        #[doc = include_str!("_docs/events/gamepadbuttonup.md")]
+        ongamepadbuttonup
+        #[doc = include_str!("_docs/events/gamepadaxis.md")]
+        ongamepadaxis
+        #[doc = include_str!("_docs/events/globalgamepadbuttondown.md")]
+        onglobalgamepadbuttondown
+        #[doc = include_str!("_docs/events/globalgamepadbuttonup.md")]
+        onglobalgamepadbuttonup
+        #[doc = include_str!("_docs/events/globalgamepadaxis.md")]
+        onglobalgamepadaxis
+    ];
+
     impl_event! [
         TouchData;
 
@@ -649,10 +824,16 @@ pub mod events {
         onglobalpointerup
         #[doc = include_str!("_docs/events/pointermove.md")]
         onpointermove
+        #[doc = include_str!("_docs/events/pointerover.md")]
+        onpointerover
+        #[doc = include_str!("_docs/events/pointerout.md")]
+        onpointerout
         #[doc = include_str!("_docs/events/pointerenter.md")]
         onpointerenter
         #[doc = include_str!("_docs/events/pointerleave.md")]
         onpointerleave
+        #[doc = include_str!("_docs/events/lostpointercapture.md")]
+        onlostpointercapture
     ];
 
     impl_event! [
@@ -660,9 +841,47 @@ pub mod events {
 
         #[doc = include_str!("_docs/events/filedrop.md")]
         onfiledrop
+        #[doc = include_str!("_docs/events/filehover.md")]
+        onfilehover
         #[doc = include_str!("_docs/events/globalfilehover.md")]
         onglobalfilehover
         #[doc = include_str!("_docs/events/globalfilehovercancelled.md")]
         onglobalfilehovercancelled
     ];
+
+    impl_capture_event! [
+        MouseData;
+
+        #[doc = include_str!("_docs/events/capture.md")]
+        oncaptureclick
+        #[doc = include_str!("_docs/events/capture.md")]
+        oncapturemousedown
+        #[doc = include_str!("_docs/events/capture.md")]
+        oncapturemouseup
+    ];
+
+    impl_capture_event! [
+        WheelData;
+
+        #[doc = include_str!("_docs/events/capture.md")]
+        oncapturewheel
+    ];
+
+    impl_capture_event! [
+        PointerData;
+
+        #[doc = include_str!("_docs/events/capture.md")]
+        oncapturepointerdown
+        #[doc = include_str!("_docs/events/capture.md")]
+        oncapturepointerup
+    ];
+
+    impl_capture_event! [
+        KeyboardData;
+
+        #[doc = include_str!("_docs/events/capture.md")]
+        oncapturekeydown
+        #[doc = include_str!("_docs/events/capture.md")]
+        oncapturekeyup
+    ];
 }
@@ -1,15 +1,23 @@
+pub mod accessibility;
 pub mod file;
+pub mod gamepad;
+pub mod gesture;
 pub mod keyboard;
 pub mod mouse;
 pub mod pointer;
+pub mod propagation;
 pub mod touch;
 pub mod wheel;
 
+pub use accessibility::*;
 use dioxus_core::Event;
 pub use file::*;
+pub use gamepad::*;
+pub use gesture::*;
 pub use keyboard::*;
 pub use mouse::*;
 pub use pointer::*;
+pub use propagation::*;
 pub use touch::*;
 pub use wheel::*;
 
@@ -18,3 +26,7 @@ pub type MouseEvent = Event<MouseData>;
 pub type WheelEvent = Event<WheelData>;
 pub type TouchEvent = Event<TouchData>;
 pub type PointerEvent = Event<PointerData>;
+pub type PinchEvent = Event<PinchData>;
+pub type RotationEvent = Event<RotationData>;
+pub type GamepadEvent = Event<GamepadData>;
+pub type AccessibilityActionEvent = Event<AccessibilityActionData>;
@@ -0,0 +1,61 @@
+use torin::geometry::Area;
+
+use crate::definitions::PlatformEventData;
+
+/// Action requested by an assistive technology (e.g a screen reader) or an automation tool.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccessibilityAction {
+    /// Activate the element, e.g click a button.
+    Default,
+    /// Increment the value of the element, e.g of a slider.
+    Increment,
+    /// Decrement the value of the element, e.g of a slider.
+    Decrement,
+    /// Expand the content controlled by the element, e.g the options of a dropdown.
+    Expand,
+    /// Collapse the content controlled by the element.
+    Collapse,
+    /// Scroll the element into view.
+    ScrollIntoView,
+    /// Replace the text value of the element, e.g of an input.
+    SetValue(String),
+    /// Replace the numeric value of the element, e.g of a slider.
+    SetNumericValue(f64),
+    /// Show the context menu of the element.
+    ShowContextMenu,
+}
+
+/// Data of an Accessibility action event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityActionData {
+    pub action: AccessibilityAction,
+    pub target_area: Area,
+}
+
+impl AccessibilityActionData {
+    pub fn new(action: AccessibilityAction, target_area: Area) -> Self {
+        Self {
+            action,
+            target_area,
+        }
+    }
+}
+
+impl AccessibilityActionData {
+    /// Get the requested action.
+    pub fn get_action(&self) -> &AccessibilityAction {
+        &self.action
+    }
+
+    /// Get the area of the element targeted by the action,
+    /// it might be a descendant of the element listening to the event.
+    pub fn get_target_area(&self) -> Area {
+        self.target_area
+    }
+}
+
+impl From<&PlatformEventData> for AccessibilityActionData {
+    fn from(val: &PlatformEventData) -> Self {
+        val.downcast::<AccessibilityActionData>().cloned().unwrap()
+    }
+}
@@ -1,11 +1,47 @@
 use std::path::PathBuf;
 
+use torin::geometry::CursorPoint;
+
 use crate::definitions::PlatformEventData;
 
-/// Data of a Keyboard event.
+/// Data of a File event.
 #[derive(Debug, Clone, PartialEq)]
 pub struct FileData {
+    /// First of the files, if any.
     pub file_path: Option<PathBuf>,
+    pub file_paths: Vec<PathBuf>,
+    pub screen_coordinates: CursorPoint,
+    pub element_coordinates: CursorPoint,
+}
+
+impl FileData {
+    pub fn new(
+        file_paths: Vec<PathBuf>,
+        screen_coordinates: CursorPoint,
+        element_coordinates: CursorPoint,
+    ) -> Self {
+        Self {
+            file_path: file_paths.first().cloned(),
+            file_paths,
+            screen_coordinates,
+            element_coordinates,
+        }
+    }
+
+    /// Get all the files being dropped or hovered.
+    pub fn get_file_paths(&self) -> &[PathBuf] {
+        &self.file_paths
+    }
+
+    /// Get the cursor coordinates relative to the window bounds.
+    pub fn get_screen_coordinates(&self) -> CursorPoint {
+        self.screen_coordinates
+    }
+
+    /// Get the cursor coordinates relatives to the element bounds.
+    pub fn get_element_coordinates(&self) -> CursorPoint {
+        self.element_coordinates
+    }
 }
 
 impl From<&PlatformEventData> for FileData {
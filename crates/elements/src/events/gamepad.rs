@@ -0,0 +1,86 @@
+use crate::definitions::PlatformEventData;
+
+/// Button of a gamepad, named after its position in the standard layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamepadButton {
+    /// Bottom action button, e.g `A` in Xbox controllers.
+    South,
+    /// Right action button, e.g `B` in Xbox controllers.
+    East,
+    /// Top action button, e.g `Y` in Xbox controllers.
+    North,
+    /// Left action button, e.g `X` in Xbox controllers.
+    West,
+    LeftTrigger,
+    LeftTrigger2,
+    RightTrigger,
+    RightTrigger2,
+    Select,
+    Start,
+    Mode,
+    LeftThumb,
+    RightThumb,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    Unknown,
+}
+
+/// Axis of a gamepad, the values go from `-1.0` to `1.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    LeftZ,
+    RightStickX,
+    RightStickY,
+    RightZ,
+    DPadX,
+    DPadY,
+    Unknown,
+}
+
+/// Input that changed in a gamepad.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GamepadInput {
+    Button(GamepadButton),
+    Axis(GamepadAxis, f32),
+}
+
+/// Data of a Gamepad event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamepadData {
+    pub gamepad_id: usize,
+    pub input: GamepadInput,
+}
+
+impl GamepadData {
+    pub fn new(gamepad_id: usize, input: GamepadInput) -> Self {
+        Self { gamepad_id, input }
+    }
+}
+
+impl GamepadData {
+    /// Get the button, if this event was caused by a button.
+    pub fn get_button(&self) -> Option<GamepadButton> {
+        match self.input {
+            GamepadInput::Button(button) => Some(button),
+            GamepadInput::Axis(..) => None,
+        }
+    }
+
+    /// Get the axis and its new value, if this event was caused by an axis.
+    pub fn get_axis(&self) -> Option<(GamepadAxis, f32)> {
+        match self.input {
+            GamepadInput::Axis(axis, value) => Some((axis, value)),
+            GamepadInput::Button(_) => None,
+        }
+    }
+}
+
+impl From<&PlatformEventData> for GamepadData {
+    fn from(val: &PlatformEventData) -> Self {
+        val.downcast::<GamepadData>().cloned().unwrap()
+    }
+}
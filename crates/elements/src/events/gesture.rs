@@ -0,0 +1,106 @@
+use torin::geometry::CursorPoint;
+use winit::event::TouchPhase;
+
+use crate::definitions::PlatformEventData;
+
+/// Data of a trackpad Pinch event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PinchData {
+    pub screen_coordinates: CursorPoint,
+    pub element_coordinates: CursorPoint,
+    pub delta: f64,
+    pub phase: TouchPhase,
+}
+
+impl PinchData {
+    pub fn new(
+        screen_coordinates: CursorPoint,
+        element_coordinates: CursorPoint,
+        delta: f64,
+        phase: TouchPhase,
+    ) -> Self {
+        Self {
+            screen_coordinates,
+            element_coordinates,
+            delta,
+            phase,
+        }
+    }
+
+    /// Get the cursor coordinates relative to the window bounds.
+    pub fn get_screen_coordinates(&self) -> CursorPoint {
+        self.screen_coordinates
+    }
+
+    /// Get the cursor coordinates relatives to the element bounds.
+    pub fn get_element_coordinates(&self) -> CursorPoint {
+        self.element_coordinates
+    }
+
+    /// Get the scale delta of this event, positive values zoom in and negative values zoom out.
+    pub fn get_delta(&self) -> f64 {
+        self.delta
+    }
+
+    /// Get the phase of the gesture.
+    pub fn get_phase(&self) -> TouchPhase {
+        self.phase
+    }
+}
+
+impl From<&PlatformEventData> for PinchData {
+    fn from(val: &PlatformEventData) -> Self {
+        val.downcast::<PinchData>().cloned().unwrap()
+    }
+}
+
+/// Data of a trackpad Rotation event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RotationData {
+    pub screen_coordinates: CursorPoint,
+    pub element_coordinates: CursorPoint,
+    pub delta: f32,
+    pub phase: TouchPhase,
+}
+
+impl RotationData {
+    pub fn new(
+        screen_coordinates: CursorPoint,
+        element_coordinates: CursorPoint,
+        delta: f32,
+        phase: TouchPhase,
+    ) -> Self {
+        Self {
+            screen_coordinates,
+            element_coordinates,
+            delta,
+            phase,
+        }
+    }
+
+    /// Get the cursor coordinates relative to the window bounds.
+    pub fn get_screen_coordinates(&self) -> CursorPoint {
+        self.screen_coordinates
+    }
+
+    /// Get the cursor coordinates relatives to the element bounds.
+    pub fn get_element_coordinates(&self) -> CursorPoint {
+        self.element_coordinates
+    }
+
+    /// Get the rotation delta of this event in degrees, positive values rotate counterclockwise.
+    pub fn get_delta(&self) -> f32 {
+        self.delta
+    }
+
+    /// Get the phase of the gesture.
+    pub fn get_phase(&self) -> TouchPhase {
+        self.phase
+    }
+}
+
+impl From<&PlatformEventData> for RotationData {
+    fn from(val: &PlatformEventData) -> Self {
+        val.downcast::<RotationData>().cloned().unwrap()
+    }
+}
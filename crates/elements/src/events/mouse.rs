@@ -9,6 +9,7 @@ pub struct MouseData {
     pub screen_coordinates: CursorPoint,
     pub element_coordinates: CursorPoint,
     pub trigger_button: Option<MouseButton>,
+    pub click_count: u32,
 }
 
 impl MouseData {
@@ -21,6 +22,7 @@ impl MouseData {
             screen_coordinates,
             element_coordinates,
             trigger_button,
+            click_count: 0,
         }
     }
 }
@@ -40,6 +42,12 @@ impl MouseData {
     pub fn get_trigger_button(&self) -> Option<MouseButton> {
         self.trigger_button
     }
+
+    /// Get how many times the pointer was pressed in a row, e.g `2` in a double click.
+    /// It's `0` for events not related to pressing the pointer, such as moving it.
+    pub fn get_click_count(&self) -> u32 {
+        self.click_count
+    }
 }
 
 impl From<&PlatformEventData> for MouseData {
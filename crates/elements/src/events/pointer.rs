@@ -5,7 +5,13 @@ use winit::event::{
     TouchPhase,
 };
 
-use crate::definitions::PlatformEventData;
+use crate::{
+    definitions::PlatformEventData,
+    events::propagation::{
+        current_propagation,
+        PointerCaptureRequest,
+    },
+};
 
 /// The type of device that triggered a Pointer event.
 #[derive(Debug, Clone, PartialEq, Copy)]
@@ -26,6 +32,7 @@ pub struct PointerData {
     pub screen_coordinates: CursorPoint,
     pub element_coordinates: CursorPoint,
     pub pointer_type: PointerType,
+    pub click_count: u32,
 }
 
 impl PointerData {
@@ -38,6 +45,7 @@ impl PointerData {
             screen_coordinates,
             element_coordinates,
             pointer_type: point_type,
+            click_count: 0,
         }
     }
 }
@@ -57,6 +65,35 @@ impl PointerData {
     pub fn get_pointer_type(&self) -> PointerType {
         self.pointer_type
     }
+
+    /// Get how many times the pointer was pressed in a row, e.g `2` in a double click or double tap.
+    /// It's `0` for events not related to pressing the pointer, such as moving it.
+    pub fn get_click_count(&self) -> u32 {
+        self.click_count
+    }
+
+    /// Capture the pointer in the element of the listener handling this event, which might be
+    /// an ancestor of the element under the pointer if the event bubbled,
+    /// so it receives all the pointer and mouse move and up events even if the pointer leaves it.
+    ///
+    /// The capture is released after the pointer is up or with [`PointerData::release_pointer_capture`],
+    /// the element then receives a `lostpointercapture` event.
+    ///
+    /// Must be called while the listener runs, not after an `.await`.
+    pub fn set_pointer_capture(&self) {
+        if let Some(propagation) = current_propagation() {
+            propagation.request_pointer_capture(PointerCaptureRequest::Set);
+        }
+    }
+
+    /// Release the pointer capture of the element of the listener handling this event.
+    ///
+    /// Must be called while the listener runs, not after an `.await`.
+    pub fn release_pointer_capture(&self) {
+        if let Some(propagation) = current_propagation() {
+            propagation.request_pointer_capture(PointerCaptureRequest::Release);
+        }
+    }
 }
 
 impl From<&PlatformEventData> for PointerData {
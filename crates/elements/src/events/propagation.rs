@@ -0,0 +1,199 @@
+use std::{
+    cell::{
+        Cell,
+        RefCell,
+    },
+    ops::Deref,
+    rc::Rc,
+};
+
+use dioxus_core::{
+    ElementId,
+    Event,
+};
+
+/// Change of the pointer capture requested by a listener.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointerCaptureRequest {
+    /// Capture the pointer in the element of the listener that requested it.
+    Set,
+    /// Release the pointer capture of the element of the listener that requested it.
+    Release,
+}
+
+/// Dispatch of an event to the listener of a single element, see [`EventPropagation::start_dispatch`].
+#[derive(Clone, Copy, Debug)]
+struct Dispatch {
+    element_id: ElementId,
+    handled: bool,
+    bubbled: bool,
+}
+
+/// Propagation state of an event, shared by all the listeners it is dispatched to.
+#[derive(Default, Debug)]
+pub struct EventPropagation {
+    stopped: Cell<bool>,
+    default_prevented: Cell<bool>,
+    dispatch: Cell<Option<Dispatch>>,
+    pointer_capture: Cell<Option<(PointerCaptureRequest, ElementId)>>,
+}
+
+impl EventPropagation {
+    /// Stop the event, no other listener except the global ones will receive it.
+    pub fn stop_propagation(&self) {
+        self.stopped.set(true);
+    }
+
+    /// Check if the event was stopped.
+    pub fn is_propagation_stopped(&self) -> bool {
+        self.stopped.get()
+    }
+
+    /// Prevent the default action of the event.
+    pub fn prevent_default(&self) {
+        self.default_prevented.set(true);
+    }
+
+    /// Check if the default action of the event was prevented.
+    pub fn is_default_prevented(&self) -> bool {
+        self.default_prevented.get()
+    }
+
+    /// Only let the listener of the given element handle the event, so the element of the listener
+    /// requesting a pointer capture change is known.
+    ///
+    /// The listeners of its parent elements must be dispatched the event separately
+    /// if [`EventPropagation::finish_dispatch`] returns `true`.
+    pub fn start_dispatch(&self, element_id: ElementId) {
+        self.dispatch.set(Some(Dispatch {
+            element_id,
+            handled: false,
+            bubbled: false,
+        }));
+    }
+
+    /// Finish the dispatch started with [`EventPropagation::start_dispatch`],
+    /// returns whether the event bubbled up to the listener of a parent element.
+    pub fn finish_dispatch(&self) -> bool {
+        self.dispatch
+            .take()
+            .map(|dispatch| dispatch.bubbled)
+            .unwrap_or_default()
+    }
+
+    /// Request a change of the pointer capture from the listener being run,
+    /// it's ignored if the event is not being dispatched to a single element.
+    pub fn request_pointer_capture(&self, request: PointerCaptureRequest) {
+        if let Some(dispatch) = self.dispatch.get() {
+            self.pointer_capture
+                .set(Some((request, dispatch.element_id)));
+        }
+    }
+
+    /// Take the last pointer capture change requested, if any,
+    /// along with the element of the listener that requested it.
+    pub fn take_pointer_capture_request(&self) -> Option<(PointerCaptureRequest, ElementId)> {
+        self.pointer_capture.take()
+    }
+}
+
+thread_local! {
+    static CURRENT_PROPAGATION: RefCell<Option<Rc<EventPropagation>>> = const { RefCell::new(None) };
+}
+
+/// Run a listener giving it access to the propagation of the event it's handling.
+///
+/// Returns `None` without running it if the event bubbled up to it
+/// while being dispatched to the listener of another element.
+pub(crate) fn with_propagation<R>(
+    propagation: Option<Rc<EventPropagation>>,
+    listener: impl FnOnce() -> R,
+) -> Option<R> {
+    if let Some(propagation) = &propagation {
+        if let Some(mut dispatch) = propagation.dispatch.get() {
+            if dispatch.handled {
+                // The listener of the parent element is dispatched the event separately
+                dispatch.bubbled = true;
+                propagation.dispatch.set(Some(dispatch));
+                return None;
+            }
+            dispatch.handled = true;
+            propagation.dispatch.set(Some(dispatch));
+        }
+    }
+    let previous = CURRENT_PROPAGATION.replace(propagation);
+    let result = listener();
+    CURRENT_PROPAGATION.set(previous);
+    Some(result)
+}
+
+pub(crate) fn current_propagation() -> Option<Rc<EventPropagation>> {
+    CURRENT_PROPAGATION.with_borrow(|propagation| propagation.clone())
+}
+
+/// Control the default action of the event being handled, e.g the focus navigation when pressing `Tab`.
+///
+/// These methods must be called while the listener runs, not after an `.await`.
+pub trait PreventDefault {
+    /// Prevent the default action of this event.
+    fn prevent_default(&self);
+
+    /// Check if a previous listener prevented the default action of this event.
+    fn is_default_prevented(&self) -> bool;
+}
+
+impl<T: 'static> PreventDefault for Event<T> {
+    fn prevent_default(&self) {
+        if let Some(propagation) = current_propagation() {
+            propagation.prevent_default();
+        }
+    }
+
+    fn is_default_prevented(&self) -> bool {
+        current_propagation()
+            .map(|propagation| propagation.is_default_prevented())
+            .unwrap_or_default()
+    }
+}
+
+/// Event received by the capture listeners, e.g `oncapturekeydown`.
+///
+/// Capture listeners run from the root to the target, before any other listener of the event.
+pub struct CaptureEvent<T> {
+    data: Rc<T>,
+    propagation: Rc<EventPropagation>,
+}
+
+impl<T> CaptureEvent<T> {
+    pub fn new(data: Rc<T>, propagation: Rc<EventPropagation>) -> Self {
+        Self { data, propagation }
+    }
+
+    /// Get the data of the event.
+    pub fn data(&self) -> Rc<T> {
+        self.data.clone()
+    }
+
+    /// Stop the event, no other listener except the global ones will receive it.
+    pub fn stop_propagation(&self) {
+        self.propagation.stop_propagation();
+    }
+
+    /// Prevent the default action of the event.
+    pub fn prevent_default(&self) {
+        self.propagation.prevent_default();
+    }
+
+    /// Check if a previous listener prevented the default action of this event.
+    pub fn is_default_prevented(&self) -> bool {
+        self.propagation.is_default_prevented()
+    }
+}
+
+impl<T> Deref for CaptureEvent<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
@@ -454,6 +454,10 @@ impl TextStyle {
         unimplemented!("This is mocked")
     }
 
+    pub fn set_background_paint(&mut self, _paint: &Paint) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn clear_background_color(&mut self) -> &mut Self {
         unimplemented!("This is mocked")
     }
@@ -615,6 +619,7 @@ impl TextStyle {
     }
 }
 
+#[derive(Clone)]
 pub struct Typeface;
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
@@ -679,6 +684,10 @@ impl FontMgr {
     ) -> Option<Typeface> {
         unimplemented!("This is mocked")
     }
+
+    pub fn family_names(&self) -> std::iter::Empty<String> {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct FontFeature;
@@ -724,6 +733,10 @@ impl FontCollection {
     pub fn set_dynamic_font_manager(&mut self, _font_manager: impl Into<Option<FontMgr>>) {
         unimplemented!("This is mocked")
     }
+
+    pub fn clear_caches(&mut self) {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct Paragraph;
@@ -1226,6 +1239,15 @@ impl Image {
     pub fn from_encoded(_data: Data) -> Option<Self> {
         unimplemented!("This is mocked")
     }
+
+    pub fn encode<'a>(
+        &self,
+        _context: impl Into<Option<&'a mut DirectContext>>,
+        _format: EncodedImageFormat,
+        _quality: impl Into<Option<u32>>,
+    ) -> Option<Data> {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct Data;
@@ -1238,6 +1260,10 @@ impl Data {
     pub unsafe fn new_bytes(_bytes: &[u8]) -> Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(C)]
@@ -1447,6 +1473,65 @@ pub mod svg {
             unimplemented!("This is mocked")
         }
     }
+
+    pub struct Canvas;
+
+    impl Canvas {
+        pub fn new(_bounds: super::Rect, _flags: Option<()>) -> Self {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn end(self) -> super::Data {
+            unimplemented!("This is mocked")
+        }
+    }
+
+    impl std::ops::Deref for Canvas {
+        type Target = super::Canvas;
+
+        fn deref(&self) -> &Self::Target {
+            unimplemented!("This is mocked")
+        }
+    }
+}
+
+pub mod pdf {
+    use std::io;
+
+    use super::{
+        Canvas,
+        Rect,
+        Size,
+    };
+
+    pub struct Metadata;
+
+    pub struct Document<'a>(std::marker::PhantomData<&'a ()>);
+
+    pub fn new_document<'a>(
+        _writer: &'a mut impl io::Write,
+        _metadata: Option<&Metadata>,
+    ) -> Document<'a> {
+        unimplemented!("This is mocked")
+    }
+
+    impl<'a> Document<'a> {
+        pub fn begin_page(self, _size: impl Into<Size>, _content: Option<&Rect>) -> Self {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn canvas(&mut self) -> &Canvas {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn end_page(self) -> Self {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn close(self) {
+            unimplemented!("This is mocked")
+        }
+    }
 }
 
 #[repr(C)]
@@ -1500,10 +1585,66 @@ impl Surface {
     pub fn new_surface_with_dimensions(&mut self, dim: impl Into<ISize>) -> Option<Self> {
         unimplemented!("This is mocked")
     }
+
+    pub fn image_snapshot(&mut self) -> Image {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn width(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn height(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn read_pixels(
+        &mut self,
+        dst_info: &ImageInfo,
+        dst_pixels: &mut [u8],
+        dst_row_bytes: usize,
+        src_point: impl Into<IPoint>,
+    ) -> bool {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub fn raster_n32_premul(size: impl Into<ISize>) -> Option<Surface> {
+    unimplemented!("This is mocked")
+}
+
+pub struct ImageInfo;
+
+impl ImageInfo {
+    pub fn new(
+        dimensions: impl Into<ISize>,
+        ct: ColorType,
+        at: AlphaType,
+        cs: impl Into<Option<ColorSpace>>,
+    ) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(i32)]
+pub enum AlphaType {
+    Unknown = 0,
+    Opaque = 1,
+    Premul = 2,
+    Unpremul = 3,
 }
 
 pub struct ISize;
 
+pub struct IPoint;
+
+impl From<(i32, i32)> for IPoint {
+    fn from(source: (i32, i32)) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
 impl From<(i32, i32)> for ISize {
     fn from(source: (i32, i32)) -> Self {
         unimplemented!("This is mocked")
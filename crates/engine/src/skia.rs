@@ -24,6 +24,7 @@ pub use skia_safe::{
         set_resource_cache_total_bytes_limit,
     },
     path::ArcSize,
+    pdf,
     rrect::Corner,
     runtime_effect::Uniform,
     surfaces::raster_n32_premul,
@@ -55,6 +56,7 @@ pub use skia_safe::{
         TextStyle,
         TypefaceFontProvider,
     },
+    AlphaType,
     Bitmap,
     BlurStyle,
     Canvas,
@@ -0,0 +1,442 @@
+use std::{
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use dioxus_core::{
+    Element,
+    VirtualDom,
+};
+use freya_core::prelude::*;
+use freya_engine::prelude::{
+    pdf,
+    raster_n32_premul,
+    svg,
+    Canvas,
+    Color,
+    EncodedImageFormat,
+    FontCollection,
+    FontMgr,
+    Rect,
+};
+use freya_renderer::EmbeddedFonts;
+use tokio::{
+    sync::{
+        broadcast,
+        mpsc::{
+            unbounded_channel,
+            UnboundedReceiver,
+            UnboundedSender,
+        },
+        watch,
+    },
+    time::timeout,
+};
+use torin::geometry::{
+    Area,
+    Size2D,
+};
+
+use crate::launch::with_accessibility;
+
+/// Configuration for a [`HeadlessRenderer`].
+pub struct HeadlessConfig<'a> {
+    pub size: Size2D,
+    pub scale_factor: f64,
+    pub background: Color,
+    pub embedded_fonts: EmbeddedFonts<'a>,
+    pub default_fonts: Vec<String>,
+    pub fallback_fonts: Vec<String>,
+    pub idle_timeout: Duration,
+    pub max_wait: Duration,
+}
+
+impl Default for HeadlessConfig<'_> {
+    fn default() -> Self {
+        Self {
+            size: Size2D::new(500.0, 500.0),
+            scale_factor: 1.0,
+            background: Color::WHITE,
+            embedded_fonts: Vec::default(),
+            default_fonts: default_fonts(),
+            fallback_fonts: Vec::new(),
+            idle_timeout: Duration::from_millis(50),
+            max_wait: Duration::from_secs(5),
+        }
+    }
+}
+
+impl<'a> HeadlessConfig<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the logical size of the rendered area.
+    pub fn with_size(mut self, width: f32, height: f32) -> Self {
+        self.size = Size2D::new(width, height);
+        self
+    }
+
+    /// Specify the scale factor, the exported files will be `size * scale_factor` big.
+    pub fn with_scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Specify the color painted behind the app.
+    pub fn with_background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Embed a font.
+    pub fn with_font(mut self, font_name: &'a str, font: &'a [u8]) -> Self {
+        self.embedded_fonts.push((font_name, font));
+        self
+    }
+
+    /// Register a default font.
+    pub fn with_default_font(mut self, font_name: &str) -> Self {
+        self.default_fonts.push(font_name.to_string());
+        self
+    }
+
+    /// Register a fallback font, tried after the default fonts and the previous fallback fonts.
+    pub fn with_fallback_font(mut self, font_name: &str) -> Self {
+        self.fallback_fonts.push(font_name.to_string());
+        self
+    }
+
+    /// How long the app must stay without work to be considered ready.
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Maximum time to wait for the app to be ready, e.g for apps with endless animations.
+    pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+}
+
+/// Renders a component without a Window and exports it as an image or a document.
+///
+/// It must be used inside a Tokio runtime.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use freya::headless::*;
+/// fn app() -> Element {
+///     rsx!(
+///         rect {
+///             background: "rgb(35, 35, 35)",
+///             padding: "12",
+///             label { color: "white", "Hello, World!" }
+///         }
+///     )
+/// }
+///
+/// # async fn export() {
+/// let mut renderer = HeadlessRenderer::new(
+///     app,
+///     HeadlessConfig::new()
+///         .with_size(300.0, 200.0)
+///         .with_scale_factor(2.0),
+/// );
+/// renderer.wait_for_work().await;
+///
+/// let png = renderer.render_png().unwrap();
+/// let pdf = renderer.render_pdf();
+/// # }
+/// ```
+pub struct HeadlessRenderer {
+    vdom: VirtualDom,
+    sdom: SafeDOM,
+    font_collection: FontCollection,
+    font_mgr: FontMgr,
+    platform_event_receiver: UnboundedReceiver<EventMessage>,
+    // Kept alive so the hooks can still send messages
+    _platform_event_emitter: UnboundedSender<EventMessage>,
+    _platform_sender: NativePlatformSender,
+    ticker_sender: broadcast::Sender<()>,
+    size: Size2D,
+    scale_factor: f64,
+    background: Color,
+    default_fonts: Vec<String>,
+    idle_timeout: Duration,
+    max_wait: Duration,
+}
+
+impl HeadlessRenderer {
+    /// Mount the given component.
+    pub fn new(app: fn() -> Element, config: HeadlessConfig) -> Self {
+        let mut vdom = with_accessibility(app);
+        let sdom = SafeDOM::new(FreyaDOM::default());
+
+        let physical_size = config.size * config.scale_factor as f32;
+
+        let (platform_event_emitter, platform_event_receiver) = unbounded_channel();
+        let (platform_sender, platform_receiver) = watch::channel(NativePlatformState {
+            focused_id: ACCESSIBILITY_ROOT_ID,
+            preferred_theme: PreferredTheme::default(),
+            navigation_mode: NavigationMode::default(),
+            information: PlatformInformation::new(physical_size, false, false, false),
+            scale_factor: config.scale_factor,
+        });
+        let ticker_sender = broadcast::channel(5).0;
+
+        let fonts = FontsRegistry::new(
+            config.embedded_fonts,
+            config.default_fonts,
+            config.fallback_fonts,
+        );
+        let mut font_collection = FontCollection::new();
+        let font_mgr = fonts.setup_font_collection(&mut font_collection);
+
+        vdom.insert_any_root_context(Box::new(platform_event_emitter.clone()));
+        vdom.insert_any_root_context(Box::new(platform_receiver));
+        vdom.insert_any_root_context(Box::new(Arc::new(ticker_sender.subscribe())));
+        let accessibility_generator = sdom.get().accessibility_generator().clone();
+        vdom.insert_any_root_context(Box::new(accessibility_generator));
+
+        sdom.get_mut()
+            .init_dom(&mut vdom, config.scale_factor as f32);
+
+        let mut renderer = Self {
+            vdom,
+            sdom,
+            font_collection,
+            font_mgr,
+            platform_event_receiver,
+            _platform_event_emitter: platform_event_emitter,
+            _platform_sender: platform_sender,
+            ticker_sender,
+            size: config.size,
+            scale_factor: config.scale_factor,
+            background: config.background,
+            default_fonts: fonts.default_fonts(),
+            idle_timeout: config.idle_timeout,
+            max_wait: config.max_wait,
+        };
+
+        renderer.process_layout();
+
+        renderer
+    }
+
+    /// Size in pixels of the exported files.
+    pub fn physical_size(&self) -> Size2D {
+        self.size * self.scale_factor as f32
+    }
+
+    /// Wait until the app has no more pending work (e.g futures, animations)
+    /// for the configured idle time, or until the maximum wait time is reached.
+    pub async fn wait_for_work(&mut self) {
+        let deadline = Instant::now() + self.max_wait;
+
+        loop {
+            // Apps that never stop working, e.g with endless animations, are only waited until the deadline
+            if Instant::now() >= deadline {
+                break;
+            }
+
+            let idle_timeout = self
+                .idle_timeout
+                .min(deadline.saturating_duration_since(Instant::now()));
+
+            if timeout(idle_timeout, self.vdom.wait_for_work())
+                .await
+                .is_err()
+            {
+                break;
+            }
+
+            self.sdom
+                .get_mut()
+                .render_mutations(&mut self.vdom, self.scale_factor as f32);
+
+            while let Ok(ev) = self.platform_event_receiver.try_recv() {
+                if let EventMessage::RemeasureTextGroup(text_measurement) = ev {
+                    self.sdom
+                        .get()
+                        .measure_paragraphs(text_measurement, self.scale_factor);
+                }
+            }
+
+            self.ticker_sender.send(()).ok();
+        }
+
+        self.process_layout();
+    }
+
+    fn process_layout(&mut self) {
+        process_layout(
+            &self.sdom.get(),
+            Area::from_size(self.physical_size()),
+            &mut self.font_collection,
+            self.scale_factor as f32,
+            &self.default_fonts,
+        );
+    }
+
+    fn render_to_canvas(&mut self, canvas: &Canvas) {
+        canvas.clear(self.background);
+
+        let fdom = self.sdom.get();
+        let layers = fdom.layers();
+        let layout = fdom.layout();
+
+        NodeRenderer {
+            canvas_area: Area::from_size(self.physical_size()),
+            canvas,
+            rdom: fdom.rdom(),
+            layout: &layout,
+            font_collection: &mut self.font_collection,
+            font_manager: &self.font_mgr,
+            default_fonts: &self.default_fonts,
+            scale_factor: self.scale_factor as f32,
+        }
+        .render_layers(&layers, None);
+    }
+
+    /// Render the app and encode it in the given image format.
+    /// The quality goes from 0 to 100 and is ignored by lossless formats.
+    pub fn render_image(
+        &mut self,
+        format: EncodedImageFormat,
+        quality: impl Into<Option<u32>>,
+    ) -> Option<Vec<u8>> {
+        let (width, height) = self.physical_size().to_i32().to_tuple();
+        let mut surface = raster_n32_premul((width, height))?;
+
+        self.render_to_canvas(surface.canvas());
+
+        let data = surface.image_snapshot().encode(None, format, quality)?;
+        Some(data.as_bytes().to_vec())
+    }
+
+    /// Render the app as a PNG image.
+    pub fn render_png(&mut self) -> Option<Vec<u8>> {
+        self.render_image(EncodedImageFormat::PNG, None)
+    }
+
+    /// Render the app as a JPEG image, with a quality from 0 to 100.
+    pub fn render_jpeg(&mut self, quality: u32) -> Option<Vec<u8>> {
+        self.render_image(EncodedImageFormat::JPEG, quality)
+    }
+
+    /// Render the app as a WebP image, with a quality from 0 to 100.
+    pub fn render_webp(&mut self, quality: u32) -> Option<Vec<u8>> {
+        self.render_image(EncodedImageFormat::WEBP, quality)
+    }
+
+    /// Render the app as a single page PDF document, texts and shapes are kept as vectors.
+    pub fn render_pdf(&mut self) -> Vec<u8> {
+        let (width, height) = self.physical_size().to_tuple();
+        let mut bytes = Vec::new();
+
+        let mut document = pdf::new_document(&mut bytes, None).begin_page((width, height), None);
+        self.render_to_canvas(document.canvas());
+        document.end_page().close();
+
+        bytes
+    }
+
+    /// Render the app as an SVG document.
+    pub fn render_svg(&mut self) -> Vec<u8> {
+        let (width, height) = self.physical_size().to_tuple();
+
+        let canvas = svg::Canvas::new(Rect::new(0.0, 0.0, width, height), None);
+        self.render_to_canvas(&canvas);
+
+        canvas.end().as_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{
+        Duration,
+        Instant,
+    };
+
+    use super::{
+        HeadlessConfig,
+        HeadlessRenderer,
+    };
+    use crate::prelude::*;
+
+    fn app() -> Element {
+        rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                background: "rgb(0, 119, 182)",
+                label { "Hello, World!" }
+            }
+        )
+    }
+
+    #[tokio::test]
+    pub async fn headless_png() {
+        let mut renderer = HeadlessRenderer::new(
+            app,
+            HeadlessConfig::new()
+                .with_size(100.0, 50.0)
+                .with_scale_factor(2.0),
+        );
+        renderer.wait_for_work().await;
+
+        let png = renderer.render_png().unwrap();
+
+        assert!(png.starts_with(b"\x89PNG"));
+        // The width and height are in the IHDR chunk, scaled by the scale factor
+        assert_eq!(&png[16..20], &200u32.to_be_bytes());
+        assert_eq!(&png[20..24], &100u32.to_be_bytes());
+    }
+
+    #[tokio::test]
+    pub async fn headless_pdf() {
+        let mut renderer = HeadlessRenderer::new(app, HeadlessConfig::new());
+        renderer.wait_for_work().await;
+
+        let pdf = renderer.render_pdf();
+
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+
+    #[tokio::test]
+    pub async fn headless_endless_animation() {
+        fn endless_animation_app() -> Element {
+            let animation = use_animation(|ctx| {
+                ctx.auto_start(true);
+                ctx.on_finish(OnFinish::Restart);
+                ctx.with(AnimNum::new(0., 100.).time(50))
+            });
+
+            let width = animation.get().read().as_f32();
+
+            rsx!(rect {
+                width: "{width}",
+                height: "fill",
+                background: "red",
+            })
+        }
+
+        let mut renderer = HeadlessRenderer::new(
+            endless_animation_app,
+            HeadlessConfig::new().with_max_wait(Duration::from_millis(300)),
+        );
+
+        // The animation never stops so only the maximum wait time is waited
+        let start = Instant::now();
+        tokio::time::timeout(Duration::from_secs(5), renderer.wait_for_work())
+            .await
+            .expect("The maximum wait time was not respected");
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -1,4 +1,7 @@
-use dioxus_core::Element;
+use dioxus_core::{
+    Element,
+    VirtualDom,
+};
 use freya_renderer::{
     DesktopRenderer,
     LaunchConfig,
@@ -236,17 +239,28 @@ pub fn launch_cfg<T: 'static + Clone>(app: AppComponent, config: LaunchConfig<T>
             .unwrap();
         let _guard = rt.enter();
 
-        DesktopRenderer::launch(vdom, sdom, config, devtools, hovered_node);
+        DesktopRenderer::launch(
+            vdom,
+            sdom,
+            config,
+            devtools,
+            hovered_node,
+            with_accessibility,
+        );
     }
 
     #[cfg(feature = "custom-tokio-rt")]
-    DesktopRenderer::launch(vdom, sdom, config, devtools, hovered_node);
+    DesktopRenderer::launch(
+        vdom,
+        sdom,
+        config,
+        devtools,
+        hovered_node,
+        with_accessibility,
+    );
 }
 
-#[cfg(any(not(feature = "devtools"), not(debug_assertions)))]
-use dioxus_core::VirtualDom;
-#[cfg(any(not(feature = "devtools"), not(debug_assertions)))]
-fn with_accessibility(app: AppComponent) -> VirtualDom {
+pub(crate) fn with_accessibility(app: AppComponent) -> VirtualDom {
     use dioxus::prelude::Props;
     use dioxus_core::fc_to_builder;
     use dioxus_core_macro::rsx;
@@ -18,6 +18,7 @@
 //! - [Testing](freya_testing)
 //! - [Animating](freya_hooks::use_animation)
 //! - [Devtools](self::_docs::devtools)
+//! - [Headless rendering](self::headless)
 //!
 //! ```rust,no_run
 //! use freya::prelude::*;
@@ -47,6 +48,8 @@
 //!
 //! - `devtools`: enables a side panel to inspect your App tree, styles and computed layout.
 //! - `use_camera`: enables the `use_camera` hook.
+//! - `gamepad`: enables the gamepad events, the D-pad moves the focus spatially and the south button presses the focused element.
+//! - `tray`: enables the system tray icon and the native menu bar, see `LaunchConfig::with_tray_icon`, `LaunchConfig::with_tray_menu` and `LaunchConfig::with_menu_bar`.
 //! - `log`: enables internal logs.
 
 /// Freya docs.
@@ -62,6 +65,9 @@ pub use freya_elements::_docs as elements_docs;
 /// Launch your app.
 pub mod launch;
 
+/// Render components without a Window.
+pub mod headless;
+
 /// Collection of basic components.
 pub mod components {
     pub use freya_components::*;
@@ -92,6 +98,9 @@ pub mod hotreload {
     pub use freya_elements::elements::FreyaCtx;
 }
 
+/// Tray icons and native menus.
+#[cfg(feature = "tray")]
+pub use freya_renderer::tray_icon;
 pub use torin;
 
 pub mod plugins;
@@ -2,9 +2,62 @@ use ropey::Rope;
 
 #[derive(Clone)]
 pub enum HistoryChange {
-    InsertChar { idx: usize, char: char },
-    InsertText { idx: usize, text: String },
-    Remove { idx: usize, text: String },
+    InsertChar {
+        idx: usize,
+        char: char,
+    },
+    InsertText {
+        idx: usize,
+        text: String,
+    },
+    Remove {
+        idx: usize,
+        text: String,
+    },
+    /// Multiple changes that are undone and redone together, e.g a replace.
+    Group(Vec<HistoryChange>),
+}
+
+impl HistoryChange {
+    /// Revert this change, returning the char index where it ends.
+    fn undo(&self, rope: &mut Rope) -> usize {
+        match self {
+            HistoryChange::Remove { idx, text } => {
+                rope.insert(*idx, text);
+                idx + text.chars().count()
+            }
+            HistoryChange::InsertChar { idx, char: ch } => {
+                rope.remove(*idx..*idx + ch.len_utf8());
+                *idx
+            }
+            HistoryChange::InsertText { idx, text } => {
+                rope.remove(*idx..idx + text.chars().count());
+                *idx
+            }
+            HistoryChange::Group(changes) => {
+                changes.iter().rev().fold(0, |_, change| change.undo(rope))
+            }
+        }
+    }
+
+    /// Apply this change again, returning the char index where it ends.
+    fn redo(&self, rope: &mut Rope) -> usize {
+        match self {
+            HistoryChange::Remove { idx, text } => {
+                rope.remove(*idx..idx + text.chars().count());
+                *idx
+            }
+            HistoryChange::InsertChar { idx, char: ch } => {
+                rope.insert_char(*idx, *ch);
+                idx + 1
+            }
+            HistoryChange::InsertText { idx, text, .. } => {
+                rope.insert(*idx, text);
+                idx + text.chars().count()
+            }
+            HistoryChange::Group(changes) => changes.iter().fold(0, |_, change| change.redo(rope)),
+        }
+    }
 }
 
 #[derive(Default, Clone)]
@@ -54,20 +107,7 @@ impl EditorHistory {
 
         let last_change = self.changes.get(self.current_change - 1);
         if let Some(last_change) = last_change {
-            let idx_end = match last_change {
-                HistoryChange::Remove { idx, text } => {
-                    rope.insert(*idx, text);
-                    idx + text.chars().count()
-                }
-                HistoryChange::InsertChar { idx, char: ch } => {
-                    rope.remove(*idx..*idx + ch.len_utf8());
-                    *idx
-                }
-                HistoryChange::InsertText { idx, text } => {
-                    rope.remove(*idx..idx + text.len());
-                    *idx
-                }
-            };
+            let idx_end = last_change.undo(rope);
             self.current_change -= 1;
             self.version += 1;
             Some(idx_end)
@@ -83,20 +123,7 @@ impl EditorHistory {
 
         let next_change = self.changes.get(self.current_change);
         if let Some(next_change) = next_change {
-            let idx_end = match next_change {
-                HistoryChange::Remove { idx, text } => {
-                    rope.remove(*idx..idx + text.chars().count());
-                    *idx
-                }
-                HistoryChange::InsertChar { idx, char: ch } => {
-                    rope.insert_char(*idx, *ch);
-                    idx + 1
-                }
-                HistoryChange::InsertText { idx, text, .. } => {
-                    rope.insert(*idx, text);
-                    idx + text.chars().count()
-                }
-            };
+            let idx_end = next_change.redo(rope);
             self.current_change += 1;
             self.version += 1;
             Some(idx_end)
@@ -11,13 +11,20 @@ mod use_activable_route;
 mod use_animation;
 mod use_asset_cacher;
 mod use_canvas;
+mod use_clipboard;
 mod use_editable;
+mod use_editable_search;
 mod use_focus;
+mod use_fonts;
+mod use_gamepad;
 mod use_init_native_platform;
+mod use_menu_event;
 mod use_node;
+mod use_on_close_requested;
 mod use_platform;
 mod use_platform_information;
 mod use_preferred_theme;
+mod use_shortcut;
 mod use_theme;
 
 #[cfg(feature = "use_camera")]
@@ -34,11 +41,18 @@ pub use use_asset_cacher::*;
 #[cfg(feature = "use_camera")]
 pub use use_camera::*;
 pub use use_canvas::*;
+pub use use_clipboard::*;
 pub use use_editable::*;
+pub use use_editable_search::*;
 pub use use_focus::*;
+pub use use_fonts::*;
+pub use use_gamepad::*;
 pub use use_init_native_platform::*;
+pub use use_menu_event::*;
 pub use use_node::*;
+pub use use_on_close_requested::*;
 pub use use_platform::*;
 pub use use_platform_information::*;
 pub use use_preferred_theme::*;
+pub use use_shortcut::*;
 pub use use_theme::*;
@@ -4,7 +4,6 @@ use std::{
     ops::Range,
 };
 
-use dioxus_sdk::clipboard::UseClipboard;
 use ropey::iter::Lines;
 pub use ropey::Rope;
 
@@ -13,6 +12,8 @@ use crate::{
     EditableMode,
     EditorHistory,
     HistoryChange,
+    SearchQuery,
+    UseClipboard,
 };
 
 /// TextEditor implementing a Rope
@@ -56,6 +57,68 @@ impl RopeEditor {
     pub fn rope(&self) -> &Rope {
         &self.rope
     }
+
+    pub fn mode(&self) -> EditableMode {
+        self.mode
+    }
+
+    /// Find all the matches of the given query, as char ranges.
+    pub fn find_all(&self, query: &SearchQuery) -> Vec<Range<usize>> {
+        let Some(regex) = query.to_regex() else {
+            return Vec::new();
+        };
+        let text = self.rope.to_string();
+
+        regex
+            .find_iter(&text)
+            .filter(|found| !found.is_empty())
+            .map(|found| self.rope.byte_to_char(found.start())..self.rope.byte_to_char(found.end()))
+            .collect()
+    }
+
+    /// Replace the given char range with a text, recorded as a single history change.
+    pub fn replace(&mut self, range: Range<usize>, text: &str) {
+        self.replace_all(&[range], text);
+    }
+
+    /// Replace all the given char ranges with a text, recorded as a single history change.
+    ///
+    /// Ranges must be sorted and not overlap, like the ones returned by [`Self::find_all`].
+    pub fn replace_all(&mut self, ranges: &[Range<usize>], text: &str) {
+        let mut changes = Vec::new();
+        let text_len = text.chars().count();
+
+        // Replace from the end to the start so the next ranges stay valid
+        for range in ranges.iter().rev() {
+            let removed = self.rope.slice(range.clone()).to_string();
+            self.rope.remove(range.clone());
+            changes.push(HistoryChange::Remove {
+                idx: range.start,
+                text: removed,
+            });
+
+            if !text.is_empty() {
+                self.rope.insert(range.start, text);
+                changes.push(HistoryChange::InsertText {
+                    idx: range.start,
+                    text: text.to_owned(),
+                });
+            }
+        }
+
+        if changes.is_empty() {
+            return;
+        }
+
+        self.history.push_change(HistoryChange::Group(changes));
+        self.clear_selection();
+
+        if let Some(last) = ranges.last() {
+            let removed_len: usize = ranges.iter().map(|range| range.len()).sum();
+            let end = last.end + ranges.len() * text_len - removed_len;
+            self.set_cursor_pos(end.min(self.len_chars()));
+        }
+    }
 }
 
 impl TextEditor for RopeEditor {
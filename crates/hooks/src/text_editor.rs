@@ -5,13 +5,18 @@ use std::{
     ops::Range,
 };
 
-use dioxus_sdk::clipboard::UseClipboard;
 use freya_elements::events::keyboard::{
     Code,
     Key,
     Modifiers,
 };
 
+use crate::{
+    ClipboardContent,
+    ClipboardFormat,
+    UseClipboard,
+};
+
 /// Holds the position of a cursor in a text
 #[derive(Clone, Default, PartialEq, Debug)]
 pub struct TextCursor(usize);
@@ -412,7 +417,9 @@ pub trait TextEditor {
                     Code::KeyC if meta_or_ctrl => {
                         let selected = self.get_selected_text();
                         if let Some(selected) = selected {
-                            self.get_clipboard().set(selected).ok();
+                            self.get_clipboard()
+                                .set_blocking(ClipboardContent::Text(selected))
+                                .ok();
                         }
                         event.remove(TextEvent::SELECTION_CHANGED);
                     }
@@ -423,7 +430,9 @@ pub trait TextEditor {
                         if let Some((start, end)) = selection {
                             let text = self.get_selected_text().unwrap();
                             self.remove(start..end);
-                            self.get_clipboard().set(text).ok();
+                            self.get_clipboard()
+                                .set_blocking(ClipboardContent::Text(text))
+                                .ok();
                             self.set_cursor_pos(start);
                             event.insert(TextEvent::TEXT_CHANGED);
                         }
@@ -431,8 +440,8 @@ pub trait TextEditor {
 
                     // Paste copied text
                     Code::KeyV if meta_or_ctrl => {
-                        let copied_text = self.get_clipboard().get();
-                        if let Ok(copied_text) = copied_text {
+                        let copied_text = self.get_clipboard().get_blocking(ClipboardFormat::Text);
+                        if let Ok(ClipboardContent::Text(copied_text)) = copied_text {
                             let char_idx = self.line_to_char(self.cursor_row()) + self.cursor_col();
                             self.insert(&copied_text, char_idx);
                             let last_idx = copied_text.len() + char_idx;
@@ -40,12 +40,39 @@ pub const DARK_THEME: Theme = Theme {
         placeholder_font_theme: FontTheme {
             color: cow_borrowed!("rgb(210, 210, 210)"),
         },
+        error_font_theme: FontTheme {
+            color: cow_borrowed!("rgb(255, 120, 120)"),
+        },
         border_fill: cow_borrowed!("rgb(80, 80, 80)"),
+        error_border_fill: cow_borrowed!("rgb(200, 70, 70)"),
         width: LIGHT_THEME.input.width,
         margin: LIGHT_THEME.input.margin,
         corner_radius: LIGHT_THEME.input.corner_radius,
         shadow: LIGHT_THEME.input.shadow,
     },
+    text_area: TextAreaTheme {
+        background: cow_borrowed!("rgb(35, 35, 35)"),
+        hover_background: cow_borrowed!("rgb(45, 45, 45)"),
+        font_theme: FontTheme {
+            color: cow_borrowed!("white"),
+        },
+        placeholder_font_theme: FontTheme {
+            color: cow_borrowed!("rgb(210, 210, 210)"),
+        },
+        line_numbers_font_theme: FontTheme {
+            color: cow_borrowed!("rgb(150, 150, 150)"),
+        },
+        line_numbers_background: cow_borrowed!("rgb(45, 45, 45)"),
+        border_fill: cow_borrowed!("rgb(80, 80, 80)"),
+        focus_border_fill: cow_borrowed!("rgb(110, 110, 110)"),
+        width: LIGHT_THEME.text_area.width,
+        min_height: LIGHT_THEME.text_area.min_height,
+        max_height: LIGHT_THEME.text_area.max_height,
+        margin: LIGHT_THEME.text_area.margin,
+        padding: LIGHT_THEME.text_area.padding,
+        corner_radius: LIGHT_THEME.text_area.corner_radius,
+        shadow: LIGHT_THEME.text_area.shadow,
+    },
     switch: SwitchTheme {
         margin: LIGHT_THEME.input.margin,
         background: cow_borrowed!("rgb(60, 60, 60)"),
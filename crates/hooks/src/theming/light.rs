@@ -40,12 +40,39 @@ pub const LIGHT_THEME: Theme = Theme {
         placeholder_font_theme: FontTheme {
             color: cow_borrowed!("rgb(100, 100, 100)"),
         },
+        error_font_theme: FontTheme {
+            color: cow_borrowed!("rgb(180, 30, 30)"),
+        },
         border_fill: cow_borrowed!("rgb(210, 210, 210)"),
+        error_border_fill: cow_borrowed!("rgb(220, 60, 60)"),
         width: cow_borrowed!("150"),
         margin: cow_borrowed!("0"),
         corner_radius: cow_borrowed!("10"),
         shadow: cow_borrowed!("0 4 5 0 rgb(0, 0, 0, 0.1)"),
     },
+    text_area: TextAreaTheme {
+        background: cow_borrowed!("rgb(245, 245, 245)"),
+        hover_background: cow_borrowed!("rgb(235, 235, 235)"),
+        font_theme: FontTheme {
+            color: cow_borrowed!("rgb(10, 10, 10)"),
+        },
+        placeholder_font_theme: FontTheme {
+            color: cow_borrowed!("rgb(100, 100, 100)"),
+        },
+        line_numbers_font_theme: FontTheme {
+            color: cow_borrowed!("rgb(130, 130, 130)"),
+        },
+        line_numbers_background: cow_borrowed!("rgb(235, 235, 235)"),
+        border_fill: cow_borrowed!("rgb(210, 210, 210)"),
+        focus_border_fill: cow_borrowed!("rgb(180, 180, 180)"),
+        width: cow_borrowed!("300"),
+        min_height: cow_borrowed!("60"),
+        max_height: cow_borrowed!("200"),
+        margin: cow_borrowed!("0"),
+        padding: cow_borrowed!("8 12"),
+        corner_radius: cow_borrowed!("10"),
+        shadow: cow_borrowed!("0 4 5 0 rgb(0, 0, 0, 0.1)"),
+    },
     switch: SwitchTheme {
         margin: cow_borrowed!("0"),
         background: cow_borrowed!("rgb(225, 225, 225)"),
@@ -274,6 +274,7 @@ define_theme! {
         background: str,
         hover_background: str,
         border_fill: str,
+        error_border_fill: str,
         shadow: str,
         width: str,
         margin: str,
@@ -281,6 +282,30 @@ define_theme! {
         %[subthemes]
         font_theme: FontTheme,
         placeholder_font_theme: FontTheme,
+        error_font_theme: FontTheme,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub TextArea {
+        %[cows]
+        background: str,
+        hover_background: str,
+        border_fill: str,
+        focus_border_fill: str,
+        shadow: str,
+        width: str,
+        min_height: str,
+        max_height: str,
+        margin: str,
+        padding: str,
+        corner_radius: str,
+        line_numbers_background: str,
+        %[subthemes]
+        font_theme: FontTheme,
+        placeholder_font_theme: FontTheme,
+        line_numbers_font_theme: FontTheme,
     }
 }
 
@@ -582,6 +607,7 @@ pub struct Theme {
     pub progress_bar: ProgressBarTheme,
     pub table: TableTheme,
     pub input: InputTheme,
+    pub text_area: TextAreaTheme,
     pub canvas: CanvasTheme,
     pub graph: GraphTheme,
     pub network_image: NetworkImageTheme,
@@ -0,0 +1,292 @@
+use std::{
+    borrow::Cow,
+    fmt,
+    io::Cursor,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use bytes::Bytes;
+use dioxus_core::prelude::{
+    try_consume_context,
+    use_hook,
+};
+
+/// Format of the content stored in the clipboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClipboardFormat {
+    Text,
+    Html,
+    Image,
+}
+
+/// Content stored in the clipboard.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClipboardContent {
+    /// Plain text.
+    Text(String),
+    /// HTML with an optional plain text alternative for apps that don't support HTML.
+    Html {
+        html: String,
+        alt_text: Option<String>,
+    },
+    /// Encoded image (PNG, JPEG, etc), the same bytes you would pass to `image_data`.
+    Image(Bytes),
+}
+
+impl ClipboardContent {
+    pub fn format(&self) -> ClipboardFormat {
+        match self {
+            Self::Text(_) => ClipboardFormat::Text,
+            Self::Html { .. } => ClipboardFormat::Html,
+            Self::Image(_) => ClipboardFormat::Image,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClipboardError {
+    /// The clipboard could not be accessed.
+    Unavailable,
+    /// The clipboard has no content in the requested format.
+    ContentNotAvailable,
+    /// The content could not be converted from or to the requested format.
+    ConversionFailure,
+    Other(String),
+}
+
+impl fmt::Display for ClipboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable => f.write_str("The clipboard is not available"),
+            Self::ContentNotAvailable => {
+                f.write_str("The clipboard has no content in the requested format")
+            }
+            Self::ConversionFailure => f.write_str("The clipboard content could not be converted"),
+            Self::Other(err) => f.write_str(err),
+        }
+    }
+}
+
+impl std::error::Error for ClipboardError {}
+
+/// Backend of a [`UseClipboard`], e.g the system clipboard or a mocked one for testing.
+pub trait ClipboardProvider: Send + 'static {
+    /// Read the content of the clipboard in the given format.
+    fn get(&mut self, format: ClipboardFormat) -> Result<ClipboardContent, ClipboardError>;
+
+    /// Replace the content of the clipboard.
+    fn set(&mut self, content: ClipboardContent) -> Result<(), ClipboardError>;
+}
+
+/// System clipboard.
+#[derive(Default)]
+pub struct NativeClipboard {
+    // Created lazily and kept alive as some platforms drop the content with the clipboard
+    clipboard: Option<arboard::Clipboard>,
+}
+
+impl NativeClipboard {
+    fn clipboard(&mut self) -> Result<&mut arboard::Clipboard, ClipboardError> {
+        if self.clipboard.is_none() {
+            self.clipboard = Some(arboard::Clipboard::new().map_err(ClipboardError::from)?);
+        }
+        Ok(self.clipboard.as_mut().unwrap())
+    }
+}
+
+impl ClipboardProvider for NativeClipboard {
+    fn get(&mut self, format: ClipboardFormat) -> Result<ClipboardContent, ClipboardError> {
+        let clipboard = self.clipboard()?;
+        match format {
+            ClipboardFormat::Text => Ok(ClipboardContent::Text(clipboard.get_text()?)),
+            ClipboardFormat::Html => Ok(ClipboardContent::Html {
+                html: clipboard.get().html()?,
+                alt_text: clipboard.get_text().ok(),
+            }),
+            ClipboardFormat::Image => {
+                let image = clipboard.get_image()?;
+                let buffer = image::RgbaImage::from_raw(
+                    image.width as u32,
+                    image.height as u32,
+                    image.bytes.into_owned(),
+                )
+                .ok_or(ClipboardError::ConversionFailure)?;
+                let mut encoded = Vec::new();
+                buffer
+                    .write_to(&mut Cursor::new(&mut encoded), image::ImageFormat::Png)
+                    .map_err(|_| ClipboardError::ConversionFailure)?;
+                Ok(ClipboardContent::Image(Bytes::from(encoded)))
+            }
+        }
+    }
+
+    fn set(&mut self, content: ClipboardContent) -> Result<(), ClipboardError> {
+        let clipboard = self.clipboard()?;
+        match content {
+            ClipboardContent::Text(text) => clipboard.set_text(text)?,
+            ClipboardContent::Html { html, alt_text } => clipboard.set_html(html, alt_text)?,
+            ClipboardContent::Image(bytes) => {
+                let image = image::load_from_memory(&bytes)
+                    .map_err(|_| ClipboardError::ConversionFailure)?
+                    .into_rgba8();
+                clipboard.set_image(arboard::ImageData {
+                    width: image.width() as usize,
+                    height: image.height() as usize,
+                    bytes: Cow::Owned(image.into_raw()),
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<arboard::Error> for ClipboardError {
+    fn from(err: arboard::Error) -> Self {
+        match err {
+            arboard::Error::ContentNotAvailable => Self::ContentNotAvailable,
+            arboard::Error::ClipboardNotSupported | arboard::Error::ClipboardOccupied => {
+                Self::Unavailable
+            }
+            arboard::Error::ConversionFailure => Self::ConversionFailure,
+            err => Self::Other(err.to_string()),
+        }
+    }
+}
+
+/// Access the clipboard. Created with [`use_clipboard`].
+#[derive(Clone)]
+pub struct UseClipboard {
+    provider: Arc<Mutex<dyn ClipboardProvider>>,
+}
+
+impl PartialEq for UseClipboard {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.provider, &other.provider)
+    }
+}
+
+impl UseClipboard {
+    /// Create a [`UseClipboard`] with a custom [`ClipboardProvider`].
+    pub fn new(provider: impl ClipboardProvider) -> Self {
+        Self {
+            provider: Arc::new(Mutex::new(provider)),
+        }
+    }
+
+    /// Create a [`UseClipboard`] for the system clipboard.
+    pub fn native() -> Self {
+        Self::new(NativeClipboard::default())
+    }
+
+    /// Read the content of the clipboard in the given format, blocking the current thread.
+    pub fn get_blocking(
+        &self,
+        format: ClipboardFormat,
+    ) -> Result<ClipboardContent, ClipboardError> {
+        self.provider
+            .lock()
+            .map_err(|_| ClipboardError::Unavailable)?
+            .get(format)
+    }
+
+    /// Replace the content of the clipboard, blocking the current thread.
+    pub fn set_blocking(&self, content: ClipboardContent) -> Result<(), ClipboardError> {
+        self.provider
+            .lock()
+            .map_err(|_| ClipboardError::Unavailable)?
+            .set(content)
+    }
+
+    /// Read the content of the clipboard in the given format.
+    pub async fn get(&self, format: ClipboardFormat) -> Result<ClipboardContent, ClipboardError> {
+        let clipboard = self.clone();
+        tokio::task::spawn_blocking(move || clipboard.get_blocking(format))
+            .await
+            .map_err(|_| ClipboardError::Unavailable)?
+    }
+
+    /// Replace the content of the clipboard.
+    pub async fn set(&self, content: ClipboardContent) -> Result<(), ClipboardError> {
+        let clipboard = self.clone();
+        tokio::task::spawn_blocking(move || clipboard.set_blocking(content))
+            .await
+            .map_err(|_| ClipboardError::Unavailable)?
+    }
+
+    /// Read the plain text of the clipboard.
+    pub async fn get_text(&self) -> Result<String, ClipboardError> {
+        match self.get(ClipboardFormat::Text).await? {
+            ClipboardContent::Text(text) => Ok(text),
+            _ => Err(ClipboardError::ContentNotAvailable),
+        }
+    }
+
+    /// Replace the content of the clipboard with plain text.
+    pub async fn set_text(&self, text: impl Into<String>) -> Result<(), ClipboardError> {
+        self.set(ClipboardContent::Text(text.into())).await
+    }
+
+    /// Read the HTML of the clipboard.
+    pub async fn get_html(&self) -> Result<String, ClipboardError> {
+        match self.get(ClipboardFormat::Html).await? {
+            ClipboardContent::Html { html, .. } => Ok(html),
+            _ => Err(ClipboardError::ContentNotAvailable),
+        }
+    }
+
+    /// Replace the content of the clipboard with HTML and an optional plain text alternative.
+    pub async fn set_html(
+        &self,
+        html: impl Into<String>,
+        alt_text: Option<String>,
+    ) -> Result<(), ClipboardError> {
+        self.set(ClipboardContent::Html {
+            html: html.into(),
+            alt_text,
+        })
+        .await
+    }
+
+    /// Read the image of the clipboard, encoded as PNG by the system clipboard.
+    pub async fn get_image(&self) -> Result<Bytes, ClipboardError> {
+        match self.get(ClipboardFormat::Image).await? {
+            ClipboardContent::Image(bytes) => Ok(bytes),
+            _ => Err(ClipboardError::ContentNotAvailable),
+        }
+    }
+
+    /// Replace the content of the clipboard with an encoded image.
+    pub async fn set_image(&self, bytes: impl Into<Bytes>) -> Result<(), ClipboardError> {
+        self.set(ClipboardContent::Image(bytes.into())).await
+    }
+}
+
+/// Get access to the clipboard.
+///
+/// Uses the system clipboard unless another [`ClipboardProvider`] was provided as context,
+/// e.g `freya-testing` uses a mocked clipboard.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let clipboard = use_clipboard();
+///
+///     rsx!(
+///         Button {
+///             onclick: move |_| {
+///                 let clipboard = clipboard.clone();
+///                 spawn(async move {
+///                     clipboard.set_text("Hello, World!").await.ok();
+///                 });
+///             },
+///             label { "Copy" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_clipboard() -> UseClipboard {
+    use_hook(|| try_consume_context::<UseClipboard>().unwrap_or_else(UseClipboard::native))
+}
@@ -5,7 +5,6 @@ use dioxus_core::{
     use_hook,
     AttributeValue,
 };
-use dioxus_sdk::clipboard::use_clipboard;
 use dioxus_signals::{
     Readable,
     Signal,
@@ -30,6 +29,7 @@ use torin::geometry::CursorPoint;
 use uuid::Uuid;
 
 use crate::{
+    use_clipboard,
     use_platform,
     EditorHistory,
     RopeEditor,
@@ -0,0 +1,273 @@
+use std::ops::Range;
+
+use dioxus_core::{
+    use_hook,
+    AttributeValue,
+};
+use dioxus_hooks::use_memo;
+use dioxus_signals::{
+    Memo,
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_node_state::CustomAttributeValues;
+use regex::{
+    Regex,
+    RegexBuilder,
+};
+
+use crate::{
+    EditableMode,
+    RopeEditor,
+    TextEditor,
+    UseEditable,
+};
+
+/// How a [`SearchQuery`] matches the text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Match the exact text.
+    #[default]
+    Plain,
+    /// Match the text ignoring the case.
+    CaseInsensitive,
+    /// Match a regular expression.
+    Regex,
+}
+
+/// Text to search in a [`RopeEditor`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub text: String,
+    pub mode: SearchMode,
+}
+
+impl SearchQuery {
+    /// Create a new [`SearchQuery`].
+    pub fn new(text: impl Into<String>, mode: SearchMode) -> Self {
+        Self {
+            text: text.into(),
+            mode,
+        }
+    }
+
+    /// Build the regex for this query. Empty queries and invalid regular expressions return `None`.
+    pub fn to_regex(&self) -> Option<Regex> {
+        if self.text.is_empty() {
+            return None;
+        }
+
+        let mut builder = match self.mode {
+            SearchMode::Plain | SearchMode::CaseInsensitive => {
+                RegexBuilder::new(&regex::escape(&self.text))
+            }
+            SearchMode::Regex => RegexBuilder::new(&self.text),
+        };
+
+        builder
+            .case_insensitive(self.mode == SearchMode::CaseInsensitive)
+            .multi_line(true)
+            .build()
+            .ok()
+    }
+}
+
+/// Search and replace text in a [`UseEditable`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseEditableSearch {
+    editable: UseEditable,
+    query: Signal<SearchQuery>,
+    matches: Memo<Vec<Range<usize>>>,
+}
+
+impl UseEditableSearch {
+    /// Search the given query.
+    pub fn search(&mut self, query: SearchQuery) {
+        if *self.query.peek() != query {
+            self.query.set(query);
+        }
+    }
+
+    /// Stop searching.
+    pub fn clear(&mut self) {
+        self.search(SearchQuery::default());
+    }
+
+    /// Get the current query.
+    pub fn query(&self) -> SearchQuery {
+        self.query.read().clone()
+    }
+
+    /// Get all the matches, as char ranges.
+    pub fn matches(&self) -> Vec<Range<usize>> {
+        self.matches.read().clone()
+    }
+
+    /// Get the index of the match that is currently selected, if any.
+    pub fn current_match(&self) -> Option<usize> {
+        let selection = self.editable.editor().read().get_selection_range()?;
+        self.matches
+            .read()
+            .iter()
+            .position(|range| (range.start, range.end) == selection)
+    }
+
+    /// Find the matches in the current content, without subscribing to it.
+    fn find_matches(&self) -> Vec<Range<usize>> {
+        self.editable.editor().peek().find_all(&self.query.peek())
+    }
+
+    /// Select the next match after the cursor, wrapping around to the first one.
+    pub fn next_match(&mut self) {
+        let matches = self.find_matches();
+        let cursor = self.editable.editor().peek().cursor_pos();
+        let next = matches
+            .iter()
+            .position(|range| range.start >= cursor)
+            .unwrap_or_default();
+        self.select_range(matches.get(next));
+    }
+
+    /// Select the previous match before the cursor, wrapping around to the last one.
+    pub fn prev_match(&mut self) {
+        let editor = self.editable.editor().peek();
+        let from = editor
+            .get_selection_range()
+            .map(|(start, _)| start)
+            .unwrap_or_else(|| editor.cursor_pos());
+        drop(editor);
+
+        let matches = self.find_matches();
+        let prev = matches
+            .iter()
+            .rposition(|range| range.start < from)
+            .unwrap_or(matches.len().saturating_sub(1));
+        self.select_range(matches.get(prev));
+    }
+
+    /// Select the match with the given index, moving the cursor to its end.
+    pub fn select_match(&mut self, index: usize) {
+        let matches = self.find_matches();
+        self.select_range(matches.get(index));
+    }
+
+    fn select_range(&mut self, range: Option<&Range<usize>>) {
+        let Some(range) = range else {
+            return;
+        };
+        let mut editor = self.editable.editor_mut().write();
+        editor.set_selection((range.start, range.end));
+        editor.set_cursor_pos(range.end);
+    }
+
+    /// Replace the currently selected match and select the next one.
+    pub fn replace(&mut self, text: &str) {
+        let selection = self.editable.editor().peek().get_selection_range();
+        let current = self
+            .find_matches()
+            .into_iter()
+            .find(|range| Some((range.start, range.end)) == selection);
+
+        if let Some(range) = current {
+            self.editable.editor_mut().write().replace(range, text);
+        }
+        self.next_match();
+    }
+
+    /// Replace all the matches, as a single history change.
+    pub fn replace_all(&mut self, text: &str) {
+        let matches = self.find_matches();
+        self.editable
+            .editor_mut()
+            .write()
+            .replace_all(&matches, text);
+    }
+
+    /// Create a highlights attribute with the selection and the matches of the given editor.
+    pub fn highlights_attr(&self, editor_id: usize) -> AttributeValue {
+        let editor = self.editable.editor().read();
+        let mut highlights = editor
+            .get_visible_selection(editor_id)
+            .map(|selection| vec![selection])
+            .unwrap_or_default();
+
+        highlights.extend(
+            self.matches
+                .read()
+                .iter()
+                .filter_map(|range| visible_range(&editor, range, editor_id)),
+        );
+
+        AttributeValue::any_value(CustomAttributeValues::TextHighlights(highlights))
+    }
+}
+
+/// Get the part of a char range that is visible in the given editor, in UTF-16 code units.
+fn visible_range(
+    editor: &RopeEditor,
+    range: &Range<usize>,
+    editor_id: usize,
+) -> Option<(usize, usize)> {
+    if editor.mode() == EditableMode::MultipleLinesSingleEditor {
+        return Some((
+            editor.char_to_utf16_cu(range.start),
+            editor.char_to_utf16_cu(range.end),
+        ));
+    }
+
+    let line_start = editor.line_to_char(editor_id);
+    let line_end = line_start + editor.line(editor_id)?.len_chars();
+    let start = range.start.max(line_start);
+    let end = range.end.min(line_end);
+
+    if start >= end {
+        return None;
+    }
+
+    let offset = editor.char_to_utf16_cu(line_start);
+    Some((
+        editor.char_to_utf16_cu(start) - offset,
+        editor.char_to_utf16_cu(end) - offset,
+    ))
+}
+
+/// Search and replace text in the given [`UseEditable`].
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut editable = use_editable(
+///         || EditableConfig::new("Hello, World!".to_string()),
+///         EditableMode::MultipleLinesSingleEditor,
+///     );
+///     let mut search = use_editable_search(editable);
+///
+///     rsx!(
+///         paragraph {
+///             highlights: search.highlights_attr(0),
+///             text { "{editable.editor()}" }
+///         }
+///         Button {
+///             onclick: move |_| {
+///                 search.search(SearchQuery::new("world", SearchMode::CaseInsensitive));
+///                 search.next_match();
+///             },
+///             label { "Search" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_editable_search(editable: UseEditable) -> UseEditableSearch {
+    let query = use_hook(|| Signal::new(SearchQuery::default()));
+    let matches = use_memo(move || {
+        let query = query.read();
+        editable.editor().read().find_all(&query)
+    });
+
+    UseEditableSearch {
+        editable,
+        query,
+        matches,
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    io,
+    path::PathBuf,
+};
+
+use dioxus_core::prelude::use_hook;
+use freya_core::prelude::{
+    EventMessage,
+    FontOperation,
+};
+use freya_engine::prelude::FontMgr;
+
+use crate::UsePlatform;
+
+/// Manage the fonts of the app at runtime. Created with [`use_fonts`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseFonts {
+    platform: UsePlatform,
+}
+
+impl UseFonts {
+    fn update(&self, operation: FontOperation) {
+        self.platform
+            .send(EventMessage::UpdateFonts(operation))
+            .ok();
+    }
+
+    /// Register a font from its bytes under the given family name,
+    /// the texts using it are measured again.
+    pub fn register_font(&self, family: impl Into<String>, data: impl Into<Vec<u8>>) {
+        self.update(FontOperation::Register {
+            family: family.into(),
+            data: data.into(),
+        });
+    }
+
+    /// Register a font from a file under the given family name.
+    pub async fn register_font_file(
+        &self,
+        family: impl Into<String>,
+        path: impl Into<PathBuf>,
+    ) -> io::Result<()> {
+        let path = path.into();
+        let data = tokio::task::spawn_blocking(move || std::fs::read(path))
+            .await
+            .map_err(io::Error::other)??;
+        self.register_font(family, data);
+        Ok(())
+    }
+
+    /// Unregister the fonts with the given family name, e.g after uninstalling a language pack.
+    pub fn unregister_font(&self, family: impl Into<String>) {
+        self.update(FontOperation::Unregister(family.into()));
+    }
+
+    /// Set the fallback font families, in order of preference.
+    /// They are tried after the default fonts for the characters those don't support.
+    pub fn set_fallback_fonts(&self, families: impl IntoIterator<Item = impl Into<String>>) {
+        self.update(FontOperation::SetFallbacks(
+            families.into_iter().map(Into::into).collect(),
+        ));
+    }
+
+    /// Get the font families installed in the system, sorted alphabetically.
+    pub fn system_font_families(&self) -> Vec<String> {
+        let mut families = FontMgr::default().family_names().collect::<Vec<_>>();
+        families.sort();
+        families.dedup();
+        families
+    }
+}
+
+/// Register, unregister and configure the fonts of the app at runtime.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let fonts = use_fonts();
+///
+///     use_hook(move || {
+///         fonts.set_fallback_fonts(["Noto Color Emoji", "Noto Sans CJK JP"]);
+///     });
+///
+///     rsx!(
+///         Button {
+///             onclick: move |_| {
+///                 spawn(async move {
+///                     fonts.register_font_file("Handwriting", "./fonts/handwriting.ttf").await.ok();
+///                 });
+///             },
+///             label { "Load font" }
+///         }
+///         label {
+///             font_family: "Handwriting",
+///             "Hello, World! こんにちは 👋"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_fonts() -> UseFonts {
+    use_hook(|| UseFonts {
+        platform: UsePlatform::new(),
+    })
+}
@@ -0,0 +1,130 @@
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use dioxus_hooks::use_context;
+use dioxus_signals::{
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_elements::events::{
+    GamepadAxis,
+    GamepadButton,
+    GamepadData,
+};
+
+/// State of all the connected gamepads.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct GamepadState {
+    axes: HashMap<(usize, GamepadAxis), f32>,
+    pressed_buttons: HashSet<(usize, GamepadButton)>,
+}
+
+/// Access the state of the gamepads. Created with [`use_gamepad`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseGamepad {
+    state: Signal<GamepadState>,
+}
+
+impl UseGamepad {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            state: Signal::new(GamepadState::default()),
+        }
+    }
+
+    /// Get the value of the given axis in the gamepad with the given id, from `-1.0` to `1.0`.
+    pub fn gamepad_axis(&self, gamepad_id: usize, axis: GamepadAxis) -> f32 {
+        self.state
+            .read()
+            .axes
+            .get(&(gamepad_id, axis))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Get the value of the given axis from `-1.0` to `1.0`,
+    /// the most pronounced one if multiple gamepads are connected.
+    pub fn axis(&self, axis: GamepadAxis) -> f32 {
+        self.state
+            .read()
+            .axes
+            .iter()
+            .filter(|((_, gamepad_axis), _)| *gamepad_axis == axis)
+            .map(|(_, value)| *value)
+            .fold(
+                0.0,
+                |max, value| {
+                    if value.abs() > max.abs() {
+                        value
+                    } else {
+                        max
+                    }
+                },
+            )
+    }
+
+    /// Check if the given button is pressed in any gamepad.
+    pub fn is_pressed(&self, button: GamepadButton) -> bool {
+        self.state
+            .read()
+            .pressed_buttons
+            .iter()
+            .any(|(_, pressed_button)| *pressed_button == button)
+    }
+
+    /// Process a `globalgamepadbuttondown` event.
+    pub fn process_button_down(&mut self, data: &GamepadData) {
+        if let Some(button) = data.get_button() {
+            self.state
+                .write()
+                .pressed_buttons
+                .insert((data.gamepad_id, button));
+        }
+    }
+
+    /// Process a `globalgamepadbuttonup` event.
+    pub fn process_button_up(&mut self, data: &GamepadData) {
+        if let Some(button) = data.get_button() {
+            self.state
+                .write()
+                .pressed_buttons
+                .remove(&(data.gamepad_id, button));
+        }
+    }
+
+    /// Process a `globalgamepadaxis` event.
+    pub fn process_axis(&mut self, data: &GamepadData) {
+        if let Some((axis, value)) = data.get_axis() {
+            self.state
+                .write()
+                .axes
+                .insert((data.gamepad_id, axis), value);
+        }
+    }
+}
+
+/// Get access to the state of the gamepads, like the raw values of the axes.
+///
+/// Gamepads are only supported with the `gamepad` feature enabled.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let gamepad = use_gamepad();
+///     let x = gamepad.axis(GamepadAxis::LeftStickX);
+///     let y = gamepad.axis(GamepadAxis::LeftStickY);
+///
+///     rsx!(
+///         label {
+///             "{x}, {y}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_gamepad() -> UseGamepad {
+    use_context()
+}
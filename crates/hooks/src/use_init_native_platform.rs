@@ -3,6 +3,7 @@ use dioxus_core::{
         consume_context,
         provide_context,
         spawn,
+        try_consume_context,
     },
     use_hook,
 };
@@ -14,7 +15,12 @@ use dioxus_signals::{
 };
 use freya_core::prelude::NativePlatformReceiver;
 
-use crate::use_init_asset_cacher;
+use crate::{
+    use_init_asset_cacher,
+    UseClipboard,
+    UseGamepad,
+    UseShortcuts,
+};
 
 #[derive(Clone)]
 pub struct NavigationMark(bool);
@@ -32,6 +38,8 @@ impl NavigationMark {
 #[derive(Clone, Copy)]
 pub struct UsePlatformEvents {
     pub navigation_mark: Signal<NavigationMark>,
+    pub shortcuts: UseShortcuts,
+    pub gamepad: UseGamepad,
 }
 
 /// Keep some native features (focused element, preferred theme, etc) on sync between the platform and the components
@@ -42,6 +50,19 @@ pub fn use_init_native_platform() -> UsePlatformEvents {
     // Init the NavigationMark signal
     let navigation_mark = use_context_provider(|| Signal::new(NavigationMark(true)));
 
+    // Init the shortcuts registry
+    let shortcuts = use_context_provider(UseShortcuts::new);
+
+    // Init the gamepads state
+    let gamepad = use_context_provider(UseGamepad::new);
+
+    // Init the system clipboard, unless a custom one was already provided
+    use_hook(|| {
+        if try_consume_context::<UseClipboard>().is_none() {
+            provide_context(UseClipboard::native());
+        }
+    });
+
     // Init the signals with platform values
     use_hook(|| {
         let mut platform_receiver = consume_context::<NativePlatformReceiver>();
@@ -82,7 +103,11 @@ pub fn use_init_native_platform() -> UsePlatformEvents {
         provide_context(focused_id);
     });
 
-    UsePlatformEvents { navigation_mark }
+    UsePlatformEvents {
+        navigation_mark,
+        shortcuts,
+        gamepad,
+    }
 }
 
 #[cfg(test)]
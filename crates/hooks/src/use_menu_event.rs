@@ -0,0 +1,52 @@
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_hook,
+};
+use freya_core::prelude::{
+    MenuEvent,
+    MenuEventsSender,
+};
+use tokio::sync::broadcast::error::RecvError;
+
+/// Run the given handler every time a native menu item is activated,
+/// e.g from the tray icon menu or the menu bar of the app.
+///
+/// Native menus are only supported with the `tray` feature enabled.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut synced = use_signal(|| 0);
+///     let platform = use_platform();
+///
+///     use_menu_event(move |event| match event.id.as_str() {
+///         "sync" => synced += 1,
+///         "quit" => platform.exit(),
+///         _ => {}
+///     });
+///
+///     rsx!(
+///         label {
+///             "Synced {synced} times"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_menu_event(mut handler: impl FnMut(MenuEvent) + 'static) {
+    use_hook(move || {
+        let Some(menu_sender) = try_consume_context::<MenuEventsSender>() else {
+            return;
+        };
+        let mut menu_receiver = menu_sender.subscribe();
+        spawn(async move {
+            loop {
+                match menu_receiver.recv().await {
+                    Ok(event) => handler(event),
+                    Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    });
+}
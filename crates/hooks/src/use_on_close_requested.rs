@@ -0,0 +1,69 @@
+use std::future::Future;
+
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_hook,
+};
+use freya_core::prelude::CloseRequests;
+
+use crate::UsePlatform;
+
+/// Intercept the requests to close the Window, e.g when the user clicks the close button.
+///
+/// The handler resolves to `true` to close the Window or `false` to keep it open,
+/// it can also await some cleanup before closing. The Window only closes once every
+/// handler allows it, and closing it with [`UsePlatform::exit`] is never intercepted.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut unsaved_changes = use_signal(|| true);
+///     let mut show_warning = use_signal(|| false);
+///     let platform = use_platform();
+///
+///     use_on_close_requested(move || async move {
+///         if unsaved_changes() {
+///             show_warning.set(true);
+///             false
+///         } else {
+///             true
+///         }
+///     });
+///
+///     rsx!(
+///         if show_warning() {
+///             label { "You have unsaved changes" }
+///             Button {
+///                 onclick: move |_| platform.exit(),
+///                 label { "Discard and close" }
+///             }
+///         }
+///         Button {
+///             onclick: move |_| unsaved_changes.set(false),
+///             label { "Save" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_on_close_requested<F>(mut handler: impl FnMut() -> F + 'static)
+where
+    F: Future<Output = bool> + 'static,
+{
+    use_hook(move || {
+        let Some(close_requests) = try_consume_context::<CloseRequests>() else {
+            return;
+        };
+        let platform = UsePlatform::new();
+        // Listen from now on, not only once the task is polled
+        let mut listener = close_requests.listen();
+        spawn(async move {
+            while let Some(request) = listener.recv().await {
+                let allow = handler().await;
+                if request.resolve(allow) {
+                    platform.exit();
+                }
+            }
+        });
+    });
+}
@@ -1,26 +1,44 @@
-use std::sync::Arc;
+use std::{
+    any::Any,
+    sync::Arc,
+};
 
-use dioxus_core::prelude::{
-    consume_context,
-    try_consume_context,
-    use_hook,
+use dioxus_core::{
+    prelude::{
+        consume_context,
+        try_consume_context,
+        use_hook,
+    },
+    Element,
 };
 use dioxus_signals::{
     Readable,
     Signal,
 };
-use freya_core::prelude::EventMessage;
+use freya_core::prelude::{
+    EventMessage,
+    MonitorInfo,
+    NewWindow,
+    WindowConfig,
+};
 use tokio::sync::{
     broadcast,
     mpsc::UnboundedSender,
+    oneshot,
 };
 use torin::prelude::Area;
 use winit::{
+    dpi::{
+        LogicalPosition,
+        PhysicalPosition,
+    },
     event_loop::EventLoopProxy,
     window::{
         CursorIcon,
         Fullscreen,
         Window,
+        WindowId,
+        WindowLevel,
     },
 };
 
@@ -29,6 +47,7 @@ pub struct UsePlatform {
     ticker: Signal<Arc<broadcast::Receiver<()>>>,
     event_loop_proxy: Signal<Option<EventLoopProxy<EventMessage>>>,
     platform_emitter: Signal<Option<UnboundedSender<EventMessage>>>,
+    window_id: Option<WindowId>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -44,11 +63,17 @@ impl UsePlatform {
             event_loop_proxy: Signal::new(try_consume_context::<EventLoopProxy<EventMessage>>()),
             platform_emitter: Signal::new(try_consume_context::<UnboundedSender<EventMessage>>()),
             ticker: Signal::new(consume_context::<Arc<broadcast::Receiver<()>>>()),
+            window_id: try_consume_context::<WindowId>(),
         }
     }
 
     pub fn send(&self, event: EventMessage) -> Result<(), UsePlatformError> {
         if let Some(event_loop_proxy) = &*self.event_loop_proxy.peek() {
+            // Messages are handled by the Window of this component
+            let event = match self.window_id {
+                Some(window_id) => EventMessage::ToWindow(window_id, Box::new(event)),
+                None => event,
+            };
             event_loop_proxy
                 .send_event(event)
                 .map_err(|_| UsePlatformError::EventLoopProxyFailed)?;
@@ -122,6 +147,88 @@ impl UsePlatform {
         });
     }
 
+    /// Show or hide the Window, e.g to keep the app running in the tray.
+    pub fn set_visible_window(&self, visible: bool) {
+        self.with_window(move |window| {
+            window.set_visible(visible);
+            if visible {
+                window.focus_window();
+            }
+        });
+    }
+
+    /// Move the Window to the given position in the desktop, in logical pixels.
+    pub fn set_window_position(&self, x: f64, y: f64) {
+        self.with_window(move |window| {
+            window.set_outer_position(LogicalPosition::new(x, y));
+        });
+    }
+
+    /// Allow or not resizing the Window by the user.
+    pub fn set_resizable_window(&self, resizable: bool) {
+        self.with_window(move |window| {
+            window.set_resizable(resizable);
+        });
+    }
+
+    /// Keep the Window above the other Windows or not.
+    pub fn set_always_on_top_window(&self, always_on_top: bool) {
+        self.with_window(move |window| {
+            window.set_window_level(if always_on_top {
+                WindowLevel::AlwaysOnTop
+            } else {
+                WindowLevel::Normal
+            });
+        });
+    }
+
+    /// Bring the Window to the front and focus it.
+    /// Some platforms only highlight the Window instead.
+    pub fn focus_window(&self) {
+        self.with_window(|window| {
+            window.focus_window();
+        });
+    }
+
+    /// Get the monitors connected to the desktop.
+    /// Resolves to an empty list if there is no Window, e.g in tests.
+    pub async fn monitors(&self) -> Vec<MonitorInfo> {
+        let (tx, rx) = oneshot::channel();
+        self.with_window(move |window| {
+            let primary_monitor = window.primary_monitor();
+            let monitors = window
+                .available_monitors()
+                .map(|monitor| MonitorInfo::from_winit(&monitor, primary_monitor.as_ref()))
+                .collect();
+            tx.send(monitors).ok();
+        });
+        rx.await.unwrap_or_default()
+    }
+
+    /// Get the monitor where the Window currently is.
+    pub async fn current_monitor(&self) -> Option<MonitorInfo> {
+        let (tx, rx) = oneshot::channel();
+        self.with_window(move |window| {
+            let primary_monitor = window.primary_monitor();
+            let monitor = window
+                .current_monitor()
+                .map(|monitor| MonitorInfo::from_winit(&monitor, primary_monitor.as_ref()));
+            tx.send(monitor).ok();
+        });
+        rx.await.ok().flatten()
+    }
+
+    /// Move the Window to the center of the given monitor.
+    pub fn move_window_to_monitor(&self, monitor: &MonitorInfo) {
+        let (position, size) = (monitor.position, monitor.size);
+        self.with_window(move |window| {
+            let window_size = window.outer_size();
+            let x = position.x + (size.width - window_size.width as f32).max(0.0) / 2.0;
+            let y = position.y + (size.height - window_size.height as f32).max(0.0) / 2.0;
+            window.set_outer_position(PhysicalPosition::new(x as i32, y as i32));
+        });
+    }
+
     pub fn invalidate_drawing_area(&self, area: Area) {
         self.send(EventMessage::InvalidateArea(area)).ok();
     }
@@ -136,13 +243,41 @@ impl UsePlatform {
         }
     }
 
-    /// Closes the whole app.
+    /// Closes the whole app, or only the Window if called from a secondary Window.
     pub fn exit(&self) {
         self.send(EventMessage::ExitApp).ok();
     }
+
+    /// Open a secondary Window with its own VirtualDOM.
+    pub fn new_window(&self, app: fn() -> Element, window_config: WindowConfig) {
+        self.send(EventMessage::NewWindow(NewWindow {
+            app,
+            window_config,
+            contexts: Vec::new(),
+        }))
+        .ok();
+    }
+
+    /// Open a secondary Window providing it the given value as a root context,
+    /// e.g a `SyncSignal` to share state between the Windows.
+    pub fn new_window_with_context(
+        &self,
+        app: fn() -> Element,
+        window_config: WindowConfig,
+        context: impl Any + Send + Sync,
+    ) {
+        self.send(EventMessage::NewWindow(NewWindow {
+            app,
+            window_config,
+            contexts: vec![Box::new(context)],
+        }))
+        .ok();
+    }
 }
 
 /// Get access to information and features of the platform.
+///
+/// Starting a native drag and drop operation to drag data out of the app is not supported.
 pub fn use_platform() -> UsePlatform {
     use_hook(UsePlatform::new)
 }
@@ -0,0 +1,576 @@
+use std::{
+    cell::RefCell,
+    fmt,
+    rc::Rc,
+    str::FromStr,
+};
+
+use dioxus_core::prelude::{
+    use_drop,
+    use_hook,
+};
+use dioxus_hooks::use_context;
+use dioxus_signals::{
+    CopyValue,
+    Readable,
+    Writable,
+};
+use freya_elements::events::{
+    CaptureEvent,
+    Key,
+    KeyboardData,
+    KeyboardEvent,
+    Modifiers,
+    PreventDefault,
+};
+use tracing::error;
+
+/// Modifiers that are taken into account when matching a [`KeyChord`].
+const CHORD_MODIFIERS: Modifiers = Modifiers::CONTROL
+    .union(Modifiers::SHIFT)
+    .union(Modifiers::ALT)
+    .union(Modifiers::META);
+
+/// Combination of modifiers and a key, e.g `Ctrl+Shift+P`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyChord {
+    modifiers: Modifiers,
+    key: Key,
+}
+
+impl KeyChord {
+    pub fn new(key: Key, modifiers: Modifiers) -> Self {
+        // Characters are case insensitive, `Shift` is what matters
+        let key = match key {
+            Key::Character(character) => Key::Character(character.to_lowercase()),
+            key => key,
+        };
+        Self {
+            modifiers: modifiers & CHORD_MODIFIERS,
+            key,
+        }
+    }
+
+    /// Get the modifiers of this chord.
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Get the key of this chord.
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = ShortcutParseError;
+
+    fn from_str(chord: &str) -> Result<Self, Self::Err> {
+        let error = || ShortcutParseError(chord.to_string());
+
+        let mut tokens = chord.split('+').collect::<Vec<_>>();
+        let key = tokens
+            .pop()
+            .filter(|key| !key.is_empty())
+            .ok_or_else(error)?;
+
+        let mut modifiers = Modifiers::empty();
+        for token in tokens {
+            modifiers |= match token {
+                "Ctrl" | "Control" => Modifiers::CONTROL,
+                "Shift" => Modifiers::SHIFT,
+                "Alt" | "Option" => Modifiers::ALT,
+                "Meta" | "Cmd" | "Super" => Modifiers::META,
+                // `Cmd` on macOS, `Ctrl` elsewhere
+                "Mod" if cfg!(target_os = "macos") => Modifiers::META,
+                "Mod" => Modifiers::CONTROL,
+                _ => return Err(error()),
+            };
+        }
+
+        let key = match key {
+            "Space" => Key::Character(" ".to_string()),
+            "Plus" => Key::Character("+".to_string()),
+            "Esc" => Key::Escape,
+            key => Key::from_str(key).map_err(|_| error())?,
+        };
+
+        Ok(Self::new(key, modifiers))
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.ctrl() {
+            f.write_str("Ctrl+")?;
+        }
+        if self.modifiers.alt() {
+            f.write_str(if cfg!(target_os = "macos") {
+                "Option+"
+            } else {
+                "Alt+"
+            })?;
+        }
+        if self.modifiers.shift() {
+            f.write_str("Shift+")?;
+        }
+        if self.modifiers.meta() {
+            f.write_str(if cfg!(target_os = "macos") {
+                "Cmd+"
+            } else {
+                "Super+"
+            })?;
+        }
+        match &self.key {
+            Key::Character(character) if character == " " => f.write_str("Space"),
+            Key::Character(character) if character == "+" => f.write_str("Plus"),
+            Key::Character(character) => f.write_str(&character.to_uppercase()),
+            key => write!(f, "{key}"),
+        }
+    }
+}
+
+/// Keyboard shortcut made of one or more chords pressed in sequence.
+///
+/// Chords are separated by spaces and their modifiers and key by `+`, e.g `Ctrl+Shift+P` or `Ctrl+K Ctrl+S`.
+/// The `Mod` modifier means `Cmd` on macOS and `Ctrl` elsewhere.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shortcut {
+    chords: Vec<KeyChord>,
+}
+
+impl Shortcut {
+    /// Get the chords of this shortcut.
+    pub fn chords(&self) -> &[KeyChord] {
+        &self.chords
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ShortcutParseError;
+
+    fn from_str(shortcut: &str) -> Result<Self, Self::Err> {
+        let chords = shortcut
+            .split_whitespace()
+            .map(KeyChord::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if chords.is_empty() {
+            return Err(ShortcutParseError(shortcut.to_string()));
+        }
+
+        Ok(Self { chords })
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, chord) in self.chords.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{chord}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when a shortcut can't be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShortcutParseError(String);
+
+impl fmt::Display for ShortcutParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid shortcut `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ShortcutParseError {}
+
+/// Identifier of a registered shortcut.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ShortcutId(usize);
+
+/// Identifier of a shortcuts scope, see [`use_shortcut_scope`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ShortcutScopeId(usize);
+
+/// Where a shortcut is listened.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ShortcutScope {
+    /// Listened anywhere in the app.
+    #[default]
+    Global,
+    /// Only listened while the focus is inside the subtree of a scope.
+    Focused(ShortcutScopeId),
+}
+
+/// Configuration of a shortcut registered with [`use_shortcut`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShortcutConfig {
+    shortcut: Shortcut,
+    description: Option<String>,
+    scope: ShortcutScope,
+}
+
+impl ShortcutConfig {
+    /// Create the configuration for the given shortcut.
+    ///
+    /// Returns an error if the shortcut is not valid, see [`Shortcut`].
+    pub fn new(shortcut: &str) -> Result<Self, ShortcutParseError> {
+        Ok(Self {
+            shortcut: Shortcut::from_str(shortcut)?,
+            description: None,
+            scope: ShortcutScope::Global,
+        })
+    }
+
+    /// Describe what the shortcut does, e.g to show it in a command palette.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Only listen to the shortcut while the focus is inside the given scope.
+    pub fn with_scope(mut self, scope: UseShortcutScope) -> Self {
+        self.scope = ShortcutScope::Focused(scope.id);
+        self
+    }
+}
+
+impl TryFrom<&str> for ShortcutConfig {
+    type Error = ShortcutParseError;
+
+    fn try_from(shortcut: &str) -> Result<Self, Self::Error> {
+        Self::new(shortcut)
+    }
+}
+
+/// Information of a registered shortcut.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShortcutInfo {
+    pub id: ShortcutId,
+    pub shortcut: Shortcut,
+    pub description: Option<String>,
+    pub scope: ShortcutScope,
+}
+
+type ShortcutHandler = Rc<RefCell<Box<dyn FnMut()>>>;
+
+struct ShortcutBinding {
+    info: ShortcutInfo,
+    handler: ShortcutHandler,
+}
+
+/// How the chords pressed so far match the registered shortcuts.
+enum ShortcutMatch {
+    /// A shortcut was completed.
+    Complete(ShortcutHandler),
+    /// The chords are the start of at least one shortcut.
+    Partial,
+    None,
+}
+
+/// Registry of the shortcuts of the app.
+#[derive(Default)]
+pub struct Shortcuts {
+    bindings: Vec<ShortcutBinding>,
+    pressed_chords: Vec<KeyChord>,
+    /// Scopes containing the focus for the key press being handled, from the innermost one.
+    focused_scopes: Vec<ShortcutScopeId>,
+    next_id: usize,
+}
+
+impl Shortcuts {
+    fn next_id(&mut self) -> usize {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    /// Match the pressed chords against the shortcuts listened in the given focused scopes.
+    ///
+    /// Shortcuts of the innermost scopes have priority over the outer ones and the global ones,
+    /// and between shortcuts of the same scope the first registered one wins.
+    fn find_match(&self, focused_scopes: &[ShortcutScopeId]) -> ShortcutMatch {
+        let priority = |scope: &ShortcutScope| match scope {
+            ShortcutScope::Global => Some(focused_scopes.len()),
+            ShortcutScope::Focused(scope_id) => focused_scopes
+                .iter()
+                .position(|focused| focused == scope_id),
+        };
+
+        let mut is_partial = false;
+        let mut complete: Option<(usize, &ShortcutBinding)> = None;
+
+        for binding in &self.bindings {
+            let Some(priority) = priority(&binding.info.scope) else {
+                continue;
+            };
+            let chords = binding.info.shortcut.chords();
+            if !chords.starts_with(&self.pressed_chords) {
+                continue;
+            }
+            if chords.len() > self.pressed_chords.len() {
+                is_partial = true;
+            } else if complete.map_or(true, |(best, _)| priority < best) {
+                complete = Some((priority, binding));
+            }
+        }
+
+        match complete {
+            Some((_, binding)) => ShortcutMatch::Complete(binding.handler.clone()),
+            None if is_partial => ShortcutMatch::Partial,
+            None => ShortcutMatch::None,
+        }
+    }
+
+    /// Register a key press and get the shortcut handler to run, if any, and whether the key press was used.
+    fn press(&mut self, chord: KeyChord) -> (Option<ShortcutHandler>, bool) {
+        let focused_scopes = std::mem::take(&mut self.focused_scopes);
+
+        self.pressed_chords.push(chord.clone());
+        let mut shortcut_match = self.find_match(&focused_scopes);
+
+        // Start a new sequence if the previous one did not lead anywhere
+        if matches!(shortcut_match, ShortcutMatch::None) && self.pressed_chords.len() > 1 {
+            self.pressed_chords = vec![chord];
+            shortcut_match = self.find_match(&focused_scopes);
+        }
+
+        match shortcut_match {
+            ShortcutMatch::Complete(handler) => {
+                self.pressed_chords.clear();
+                (Some(handler), true)
+            }
+            ShortcutMatch::Partial => (None, true),
+            ShortcutMatch::None => {
+                self.pressed_chords.clear();
+                (None, false)
+            }
+        }
+    }
+}
+
+/// Access the shortcuts registered in the app.
+#[derive(Clone, Copy)]
+pub struct UseShortcuts {
+    shortcuts: CopyValue<Shortcuts>,
+}
+
+impl UseShortcuts {
+    pub(crate) fn new() -> Self {
+        Self {
+            shortcuts: CopyValue::new(Shortcuts::default()),
+        }
+    }
+
+    /// Get all the registered shortcuts, e.g to list them in a command palette or a help overlay.
+    pub fn list(&self) -> Vec<ShortcutInfo> {
+        self.shortcuts
+            .read()
+            .bindings
+            .iter()
+            .map(|binding| binding.info.clone())
+            .collect()
+    }
+
+    /// Run the handler of a registered shortcut, e.g when it's selected in a command palette.
+    pub fn run(&self, id: ShortcutId) {
+        let handler = self
+            .shortcuts
+            .read()
+            .bindings
+            .iter()
+            .find(|binding| binding.info.id == id)
+            .map(|binding| binding.handler.clone());
+
+        if let Some(handler) = handler {
+            (*handler.borrow_mut())();
+        }
+    }
+
+    /// Run the shortcut completed by this key press, if any.
+    ///
+    /// Returns whether the key press was used by a shortcut, either completing it or continuing a sequence.
+    pub fn process_keydown(&mut self, e: &KeyboardEvent) -> bool {
+        if e.is_default_prevented() || is_modifier(&e.key) {
+            self.shortcuts.write().focused_scopes.clear();
+            return false;
+        }
+
+        let chord = KeyChord::new(e.key.clone(), e.modifiers);
+        let (handler, used) = self.shortcuts.write().press(chord);
+
+        // The registry is not borrowed anymore so the handler can use it
+        if let Some(handler) = handler {
+            (*handler.borrow_mut())();
+        }
+
+        used
+    }
+}
+
+fn is_modifier(key: &Key) -> bool {
+    matches!(
+        key,
+        Key::Control | Key::Shift | Key::Alt | Key::AltGraph | Key::Meta | Key::Super | Key::Hyper
+    )
+}
+
+/// Get access to the shortcuts registered in the app.
+pub fn use_shortcuts() -> UseShortcuts {
+    use_context()
+}
+
+/// Register a keyboard shortcut that runs the given handler.
+///
+/// The shortcut is listened anywhere in the app unless it's restricted to a scope with [`ShortcutConfig::with_scope`].
+/// Invalid shortcuts are logged and not registered.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut palette_open = use_signal(|| false);
+///
+///     use_shortcut(
+///         ShortcutConfig::new("Mod+Shift+P")
+///             .map(|config| config.with_description("Open the command palette")),
+///         move || palette_open.set(true),
+///     );
+///
+///     rsx!(label { "Palette open: {palette_open}" })
+/// }
+/// ```
+pub fn use_shortcut<C>(config: C, handler: impl FnMut() + 'static)
+where
+    C: TryInto<ShortcutConfig>,
+    C::Error: fmt::Display,
+{
+    let mut shortcuts = use_shortcuts();
+
+    let (id, binding_handler) = use_hook(|| {
+        let id = ShortcutId(shortcuts.shortcuts.write().next_id());
+        let handler: ShortcutHandler = Rc::new(RefCell::new(Box::new(|| {})));
+        (id, handler)
+    });
+
+    // Keep the registered shortcut and handler up to date
+    *binding_handler.borrow_mut() = Box::new(handler);
+
+    let config = match config.try_into() {
+        Ok(config) => Some(config),
+        Err(err) => {
+            error!("{err}, the shortcut will not be registered");
+            None
+        }
+    };
+
+    let mut registry = shortcuts.shortcuts.write();
+    let position = registry
+        .bindings
+        .iter()
+        .position(|binding| binding.info.id == id);
+
+    match (config, position) {
+        (
+            Some(ShortcutConfig {
+                shortcut,
+                description,
+                scope,
+            }),
+            Some(position),
+        ) => {
+            let info = &mut registry.bindings[position].info;
+            info.shortcut = shortcut;
+            info.description = description;
+            info.scope = scope;
+        }
+        (
+            Some(ShortcutConfig {
+                shortcut,
+                description,
+                scope,
+            }),
+            None,
+        ) => {
+            registry.bindings.push(ShortcutBinding {
+                info: ShortcutInfo {
+                    id,
+                    shortcut,
+                    description,
+                    scope,
+                },
+                handler: binding_handler,
+            });
+        }
+        (None, Some(position)) => {
+            registry.bindings.remove(position);
+        }
+        (None, None) => {}
+    }
+    drop(registry);
+
+    use_drop(move || {
+        shortcuts
+            .shortcuts
+            .write()
+            .bindings
+            .retain(|binding| binding.info.id != id);
+    });
+}
+
+/// Scope for shortcuts that are only listened while the focus is inside of it.
+///
+/// The `capturekeydown` events of the element containing the scope must be passed to [`UseShortcutScope::oncapturekeydown`],
+/// capture listeners run before the focused element handles the key, so the scope still works
+/// when it stops the propagation of the keydown events, e.g an `Input`.
+#[derive(Clone, Copy)]
+pub struct UseShortcutScope {
+    id: ShortcutScopeId,
+    shortcuts: UseShortcuts,
+}
+
+impl UseShortcutScope {
+    /// Get the ID of this scope.
+    pub fn id(&self) -> ShortcutScopeId {
+        self.id
+    }
+
+    /// Mark this scope as focused for the key press being handled.
+    pub fn oncapturekeydown(&mut self, _e: CaptureEvent<KeyboardData>) {
+        // Capture listeners run from the outer scopes to the inner ones
+        self.shortcuts
+            .shortcuts
+            .write()
+            .focused_scopes
+            .insert(0, self.id);
+    }
+}
+
+/// Create a scope for shortcuts that are only listened while the focus is inside of it.
+///
+/// Shortcuts of a scope have priority over the ones of its outer scopes and the global ones.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut scope = use_shortcut_scope();
+///
+///     use_shortcut(
+///         ShortcutConfig::new("Ctrl+F").map(|config| config.with_scope(scope)),
+///         || println!("Find in the editor"),
+///     );
+///
+///     rsx!(rect {
+///         oncapturekeydown: move |e| scope.oncapturekeydown(e),
+///         // Focusable elements of the editor
+///     })
+/// }
+/// ```
+pub fn use_shortcut_scope() -> UseShortcutScope {
+    let mut shortcuts = use_shortcuts();
+    use_hook(|| UseShortcutScope {
+        id: ShortcutScopeId(shortcuts.shortcuts.write().next_id()),
+        shortcuts,
+    })
+}
@@ -0,0 +1,113 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn editable_copy_paste() {
+    fn use_editable_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("Hello".to_string()),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let editor = editable.editor().read();
+
+        let onglobalkeydown = move |e: Event<KeyboardData>| {
+            editable.process_event(&EditableEvent::KeyDown(e.data));
+        };
+
+        rsx!(
+            paragraph {
+                onglobalkeydown,
+                text {
+                    "{editor}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_editable_app);
+
+    let content = utils.root().get(0).get(0).get(0);
+    assert_eq!(content.text(), Some("Hello"));
+
+    // Copy all the text
+    for (character, code) in [("a", Code::KeyA), ("c", Code::KeyC)] {
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character(character.to_string()),
+            code,
+            modifiers: Modifiers::CONTROL,
+        });
+        utils.wait_for_update().await;
+    }
+
+    assert_eq!(
+        utils.clipboard().content(),
+        Some(ClipboardContent::Text("Hello".to_string()))
+    );
+
+    // Paste the HTML alternative text
+    utils.clipboard().set_content(ClipboardContent::Html {
+        html: "<b>World</b>".to_string(),
+        alt_text: Some("World".to_string()),
+    });
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("v".to_string()),
+        code: Code::KeyV,
+        modifiers: Modifiers::CONTROL,
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(content.text(), Some("HelloWorld"));
+}
+
+#[tokio::test]
+pub async fn async_image_clipboard() {
+    fn use_clipboard_app() -> Element {
+        let clipboard = use_clipboard();
+        let mut image_len = use_signal(|| None);
+
+        let onclick = move |_| {
+            let clipboard = clipboard.clone();
+            spawn(async move {
+                let image = clipboard.get_image().await.ok();
+                image_len.set(image.map(|image| image.len()));
+            });
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onclick,
+                label {
+                    "{image_len:?}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_clipboard_app);
+
+    let label = utils.root().get(0).get(0);
+    assert_eq!(label.get(0).text(), Some("None"));
+
+    utils
+        .clipboard()
+        .set_content(ClipboardContent::Image(vec![0; 8].into()));
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("Some(8)"));
+
+    // Plain text can't be read as an image
+    utils
+        .clipboard()
+        .set_content(ClipboardContent::Text("Hello".to_string()));
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("None"));
+}
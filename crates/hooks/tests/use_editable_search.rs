@@ -0,0 +1,105 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+use crate::{
+    use_editable,
+    use_editable_search,
+    EditableMode,
+    TextEditor,
+};
+
+#[tokio::test]
+pub async fn search_and_replace() {
+    fn use_editable_search_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("Hello World\nhello world".to_string()),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let mut search = use_editable_search(editable);
+        let editor = editable.editor().read();
+        let matches = search.matches().len();
+        let current_match = search
+            .current_match()
+            .map(|current| current.to_string())
+            .unwrap_or_default();
+
+        let onglobalkeydown = move |e: Event<KeyboardData>| match e.data.key {
+            Key::Character(ref c) if c == "p" => {
+                search.search(SearchQuery::new("hello", SearchMode::Plain))
+            }
+            Key::Character(ref c) if c == "i" => {
+                search.search(SearchQuery::new("hello", SearchMode::CaseInsensitive))
+            }
+            Key::Character(ref c) if c == "x" => {
+                search.search(SearchQuery::new(r"w\w+", SearchMode::Regex))
+            }
+            Key::ArrowDown => search.next_match(),
+            Key::ArrowUp => search.prev_match(),
+            Key::Enter => search.replace_all("Bye"),
+            Key::Backspace => {
+                editable.editor_mut().write().undo();
+            }
+            _ => {}
+        };
+
+        rsx!(
+            rect {
+                onglobalkeydown,
+                label {
+                    "{editor}"
+                }
+                label {
+                    "{matches}:{current_match}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_editable_search_app);
+    let root = utils.root().get(0);
+    let content = root.get(0).get(0);
+    let matches = root.get(1).get(0);
+
+    async fn press(utils: &mut TestingHandler, key: Key) {
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key,
+            code: Code::Unidentified,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+    }
+
+    // Plain search is case sensitive
+    press(&mut utils, Key::Character("p".to_string())).await;
+    assert_eq!(matches.text(), Some("1:"));
+
+    // Case insensitive search
+    press(&mut utils, Key::Character("i".to_string())).await;
+    assert_eq!(matches.text(), Some("2:"));
+
+    // Navigate the matches, wrapping around
+    press(&mut utils, Key::ArrowDown).await;
+    assert_eq!(matches.text(), Some("2:0"));
+    press(&mut utils, Key::ArrowDown).await;
+    assert_eq!(matches.text(), Some("2:1"));
+    press(&mut utils, Key::ArrowDown).await;
+    assert_eq!(matches.text(), Some("2:0"));
+    press(&mut utils, Key::ArrowUp).await;
+    assert_eq!(matches.text(), Some("2:1"));
+
+    // Regex search
+    press(&mut utils, Key::Character("x".to_string())).await;
+    assert_eq!(matches.text(), Some("1:"));
+
+    // Replace all the matches
+    press(&mut utils, Key::Character("i".to_string())).await;
+    press(&mut utils, Key::Enter).await;
+    assert_eq!(content.text(), Some("Bye World\nBye world"));
+    assert_eq!(matches.text(), Some("0:"));
+
+    // A replace all is undone in a single step
+    press(&mut utils, Key::Backspace).await;
+    assert_eq!(content.text(), Some("Hello World\nhello world"));
+    assert_eq!(matches.text(), Some("2:"));
+}
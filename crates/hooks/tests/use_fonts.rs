@@ -0,0 +1,51 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn register_and_unregister_font() {
+    fn fonts_app() -> Element {
+        let fonts = use_fonts();
+
+        rsx!(
+            label {
+                font_family: "Sansita",
+                font_size: "30",
+                "Hello, World!"
+            }
+            Button {
+                onclick: move |_| {
+                    fonts.register_font(
+                        "Sansita",
+                        include_bytes!("../../../examples/SansitaSwashed-Regular.ttf"),
+                    )
+                },
+                label { "Register" }
+            }
+            Button {
+                onclick: move |_| fonts.unregister_font("Sansita"),
+                label { "Unregister" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(fonts_app);
+    utils.wait_for_update().await;
+    let default_width = utils.root().get(0).area().unwrap().width();
+
+    // Register the font
+    let register = utils.root().get(1).area().unwrap().center();
+    utils
+        .click_cursor((register.x as f64, register.y as f64))
+        .await;
+    utils.wait_for_update().await;
+    let sansita_width = utils.root().get(0).area().unwrap().width();
+    assert_ne!(default_width, sansita_width);
+
+    // Unregister the font
+    let unregister = utils.root().get(2).area().unwrap().center();
+    utils
+        .click_cursor((unregister.x as f64, unregister.y as f64))
+        .await;
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), default_width);
+}
@@ -0,0 +1,115 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn gamepad_event(name: EventName, input: GamepadInput) -> PlatformEvent {
+    PlatformEvent::Gamepad {
+        name,
+        gamepad_id: 0,
+        input,
+    }
+}
+
+#[tokio::test]
+pub async fn gamepad_state() {
+    fn use_gamepad_app() -> Element {
+        let gamepad = use_gamepad();
+        let x = gamepad.axis(GamepadAxis::LeftStickX);
+        let pressed = gamepad.is_pressed(GamepadButton::North);
+
+        rsx!(
+            label {
+                "{x} {pressed}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_gamepad_app);
+
+    let label = utils.root().get(0);
+    assert_eq!(label.get(0).text(), Some("0 false"));
+
+    utils.push_event(gamepad_event(
+        EventName::GamepadAxis,
+        GamepadInput::Axis(GamepadAxis::LeftStickX, -0.5),
+    ));
+    utils.push_event(gamepad_event(
+        EventName::GamepadButtonDown,
+        GamepadInput::Button(GamepadButton::North),
+    ));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("-0.5 true"));
+
+    utils.push_event(gamepad_event(
+        EventName::GamepadButtonUp,
+        GamepadInput::Button(GamepadButton::North),
+    ));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("-0.5 false"));
+}
+
+#[tokio::test]
+pub async fn gamepad_focus_navigation() {
+    fn use_gamepad_app() -> Element {
+        let mut count = use_signal(|| 0);
+
+        rsx!(
+            Button {
+                onpress: move |_| count += 1,
+                label {
+                    "Increase"
+                }
+            }
+            Button {
+                onpress: move |_| count -= 1,
+                label {
+                    "Decrease"
+                }
+            }
+            label {
+                "{count}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_gamepad_app);
+
+    let label = utils.root().get(2);
+    utils.wait_for_update().await;
+    assert_eq!(utils.focus_id(), ACCESSIBILITY_ROOT_ID);
+
+    // Focus the first button with the D-pad
+    utils.push_event(gamepad_event(
+        EventName::GamepadButtonDown,
+        GamepadInput::Button(GamepadButton::DPadDown),
+    ));
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    let first_focus_id = utils.focus_id();
+    assert_ne!(first_focus_id, ACCESSIBILITY_ROOT_ID);
+
+    // Press it with the south button
+    utils.push_event(gamepad_event(
+        EventName::GamepadButtonDown,
+        GamepadInput::Button(GamepadButton::South),
+    ));
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1"));
+
+    // Focus the second button, which is below, and press it
+    utils.push_event(gamepad_event(
+        EventName::GamepadButtonDown,
+        GamepadInput::Button(GamepadButton::DPadDown),
+    ));
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_ne!(utils.focus_id(), first_focus_id);
+
+    utils.push_event(gamepad_event(
+        EventName::GamepadButtonDown,
+        GamepadInput::Button(GamepadButton::South),
+    ));
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("0"));
+}
@@ -0,0 +1,32 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn menu_event() {
+    fn menu_event_app() -> Element {
+        let mut activations = use_signal(Vec::<String>::new);
+
+        use_menu_event(move |event| activations.write().push(event.id));
+
+        let activations = activations.read().join(", ");
+
+        rsx!(
+            label {
+                "Activated: {activations}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(menu_event_app);
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).get(0).text(), Some("Activated: "));
+
+    utils.activate_menu_item("sync").await;
+    assert_eq!(utils.root().get(0).get(0).text(), Some("Activated: sync"));
+
+    utils.activate_menu_item("quit").await;
+    assert_eq!(
+        utils.root().get(0).get(0).text(),
+        Some("Activated: sync, quit")
+    );
+}
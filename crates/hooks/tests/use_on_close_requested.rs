@@ -0,0 +1,95 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn close_without_listeners() {
+    fn close_app() -> Element {
+        rsx!(
+            label {
+                "Hello"
+            }
+        )
+    }
+
+    let mut utils = launch_test(close_app);
+    utils.wait_for_update().await;
+    assert!(!utils.is_closed());
+
+    utils.request_close().await;
+    assert!(utils.is_closed());
+}
+
+#[tokio::test]
+pub async fn prevent_close() {
+    fn close_app() -> Element {
+        let mut unsaved_changes = use_signal(|| true);
+        let mut show_warning = use_signal(|| false);
+
+        use_on_close_requested(move || async move {
+            if unsaved_changes() {
+                show_warning.set(true);
+                false
+            } else {
+                true
+            }
+        });
+
+        rsx!(
+            Button {
+                onclick: move |_| unsaved_changes.set(false),
+                label {
+                    "Save"
+                }
+            }
+            label {
+                "{show_warning}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(close_app);
+    utils.wait_for_update().await;
+
+    utils.request_close().await;
+    utils.wait_for_update().await;
+    assert!(!utils.is_closed());
+    assert_eq!(utils.root().get(1).get(0).text(), Some("true"));
+
+    // Save the changes
+    utils.click_cursor((15., 15.)).await;
+
+    utils.request_close().await;
+    utils.wait_for_update().await;
+    assert!(utils.is_closed());
+}
+
+#[tokio::test]
+pub async fn async_cleanup_before_close() {
+    fn close_app() -> Element {
+        let mut saved = use_signal(|| false);
+
+        use_on_close_requested(move || async move {
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            saved.set(true);
+            true
+        });
+
+        rsx!(
+            label {
+                "{saved}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(close_app);
+    utils.wait_for_update().await;
+
+    utils.request_close().await;
+    assert!(!utils.is_closed());
+
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert!(utils.is_closed());
+    assert_eq!(utils.root().get(0).get(0).text(), Some("true"));
+}
@@ -0,0 +1,79 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn new_window() {
+    fn preferences_app() -> Element {
+        let count = use_context::<SyncSignal<i32>>();
+
+        rsx!(
+            label {
+                "{count}"
+            }
+        )
+    }
+
+    fn new_window_app() -> Element {
+        let platform = use_platform();
+        let count = use_hook(|| Signal::new_maybe_sync(0));
+
+        rsx!(
+            Button {
+                onclick: move |_| {
+                    platform.new_window_with_context(
+                        preferences_app,
+                        WindowConfig {
+                            title: "Preferences",
+                            ..Default::default()
+                        },
+                        count,
+                    );
+                },
+                label {
+                    "Preferences"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(new_window_app);
+    utils.wait_for_update().await;
+    assert!(utils.opened_windows().is_empty());
+
+    utils.click_cursor((15., 15.)).await;
+    utils.wait_for_update().await;
+
+    let opened_windows = utils.opened_windows();
+    assert_eq!(opened_windows.len(), 1);
+    assert_eq!(opened_windows[0].window_config.title, "Preferences");
+    assert_eq!(opened_windows[0].contexts.len(), 1);
+    assert!(opened_windows[0].contexts[0].is::<SyncSignal<i32>>());
+}
+
+#[tokio::test]
+pub async fn monitors_without_window() {
+    fn monitors_app() -> Element {
+        let platform = use_platform();
+        let mut monitors = use_signal(|| None);
+
+        use_hook(move || {
+            spawn(async move {
+                monitors.set(Some(platform.monitors().await.len()));
+            })
+        });
+
+        let monitors = monitors.read();
+
+        rsx!(
+            label {
+                "{monitors:?}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(monitors_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).text(), Some("Some(0)"));
+}
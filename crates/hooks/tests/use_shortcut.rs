@@ -0,0 +1,262 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn key_down(character: &str, code: Code, modifiers: Modifiers) -> PlatformEvent {
+    PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character(character.to_string()),
+        code,
+        modifiers,
+    }
+}
+
+#[tokio::test]
+pub async fn shortcut_sequence() {
+    fn use_shortcut_app() -> Element {
+        let mut saves = use_signal(|| 0);
+        let shortcuts = use_shortcuts();
+
+        use_shortcut(
+            ShortcutConfig::new("Ctrl+K Ctrl+S").map(|config| config.with_description("Save")),
+            move || saves += 1,
+        );
+
+        let list = shortcuts
+            .list()
+            .into_iter()
+            .map(|info| {
+                format!(
+                    "{}: {}",
+                    info.shortcut,
+                    info.description.unwrap_or_default()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        rsx!(
+            label {
+                "{saves}"
+            }
+            label {
+                "{list}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_shortcut_app);
+
+    let root = utils.root();
+    let label = root.get(0);
+
+    assert_eq!(root.get(1).get(0).text(), Some("Ctrl+K Ctrl+S: Save"));
+
+    // The sequence is not complete yet
+    utils.push_event(key_down("k", Code::KeyK, Modifiers::CONTROL));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("0"));
+
+    utils.push_event(key_down("s", Code::KeyS, Modifiers::CONTROL));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1"));
+
+    // The sequence must start from the beginning
+    utils.push_event(key_down("s", Code::KeyS, Modifiers::CONTROL));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1"));
+
+    // Other keys break the sequence
+    utils.push_event(key_down("k", Code::KeyK, Modifiers::CONTROL));
+    utils.push_event(key_down("x", Code::KeyX, Modifiers::empty()));
+    utils.push_event(key_down("s", Code::KeyS, Modifiers::CONTROL));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1"));
+}
+
+#[tokio::test]
+pub async fn scoped_shortcut() {
+    #[allow(non_snake_case)]
+    fn Editor() -> Element {
+        let mut scope = use_shortcut_scope();
+        let mut focus = use_focus();
+        let mut finds = use_signal(|| 0);
+
+        use_shortcut(
+            ShortcutConfig::new("Ctrl+F").map(|config| config.with_scope(scope)),
+            move || finds += 1,
+        );
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "50%",
+                oncapturekeydown: move |e| scope.oncapturekeydown(e),
+                rect {
+                    a11y_id: focus.attribute(),
+                    width: "100%",
+                    height: "100%",
+                    onclick: move |_| focus.focus(),
+                    label {
+                        "{finds}"
+                    }
+                }
+            }
+        )
+    }
+
+    fn use_shortcut_app() -> Element {
+        let mut finds = use_signal(|| 0);
+
+        use_shortcut("Ctrl+F", move || finds += 1);
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                Editor {}
+                label {
+                    "{finds}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        use_shortcut_app,
+        TestingConfig {
+            size: (100.0, 100.0).into(),
+            ..TestingConfig::default()
+        },
+    );
+
+    let root = utils.root().get(0);
+    let editor_label = root.get(0).get(0).get(0);
+    let global_label = root.get(1);
+
+    // The editor is not focused so the global shortcut runs
+    utils.push_event(key_down("f", Code::KeyF, Modifiers::CONTROL));
+    utils.wait_for_update().await;
+    assert_eq!(editor_label.get(0).text(), Some("0"));
+    assert_eq!(global_label.get(0).text(), Some("1"));
+
+    // Focus the editor
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+
+    // The scoped shortcut has priority over the global one
+    utils.push_event(key_down("F", Code::KeyF, Modifiers::CONTROL));
+    utils.wait_for_update().await;
+    assert_eq!(editor_label.get(0).text(), Some("1"));
+    assert_eq!(global_label.get(0).text(), Some("1"));
+}
+
+#[tokio::test]
+pub async fn scoped_shortcut_focused_input() {
+    fn use_shortcut_app() -> Element {
+        let mut scope = use_shortcut_scope();
+        let mut value = use_signal(String::new);
+        let mut scoped_finds = use_signal(|| 0);
+        let mut global_finds = use_signal(|| 0);
+
+        use_shortcut(
+            ShortcutConfig::new("Ctrl+F").map(|config| config.with_scope(scope)),
+            move || scoped_finds += 1,
+        );
+        use_shortcut("Ctrl+F", move || global_finds += 1);
+
+        rsx!(
+            rect {
+                oncapturekeydown: move |e| scope.oncapturekeydown(e),
+                Input {
+                    value: value.read().clone(),
+                    onchange: move |e| value.set(e),
+                }
+            }
+            label {
+                "{scoped_finds} {global_finds}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_shortcut_app);
+
+    let label = utils.root().get(1);
+
+    // Focus the input
+    utils.click_cursor((10., 10.)).await;
+    utils.wait_for_update().await;
+
+    // The input stops the propagation of the keydown events but the scope still sees them
+    utils.push_event(key_down("f", Code::KeyF, Modifiers::CONTROL));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1 0"));
+}
+
+#[tokio::test]
+pub async fn prevented_shortcut() {
+    fn use_shortcut_app() -> Element {
+        let mut focus = use_focus();
+        let mut finds = use_signal(|| 0);
+
+        use_shortcut("Ctrl+F", move || finds += 1);
+
+        rsx!(
+            rect {
+                a11y_id: focus.attribute(),
+                width: "100%",
+                height: "100%",
+                onclick: move |_| focus.focus(),
+                onkeydown: move |e| e.prevent_default(),
+                label {
+                    "{finds}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        use_shortcut_app,
+        TestingConfig {
+            size: (100.0, 100.0).into(),
+            ..TestingConfig::default()
+        },
+    );
+
+    let label = utils.root().get(0).get(0);
+
+    // The focused element is not listening yet
+    utils.push_event(key_down("f", Code::KeyF, Modifiers::CONTROL));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1"));
+
+    // Focus the element
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+
+    // The focused element prevents the shortcut
+    utils.push_event(key_down("f", Code::KeyF, Modifiers::CONTROL));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1"));
+}
+
+#[tokio::test]
+pub async fn invalid_shortcut() {
+    fn use_shortcut_app() -> Element {
+        let shortcuts = use_shortcuts();
+
+        use_shortcut("Ctrl+", || {});
+        use_shortcut("Ctrl+S", || {});
+
+        let count = shortcuts.list().len();
+
+        rsx!(label {
+            "{count}"
+        })
+    }
+
+    let mut utils = launch_test(use_shortcut_app);
+    utils.wait_for_update().await;
+
+    // The invalid shortcut is ignored
+    assert_eq!(utils.root().get(0).get(0).text(), Some("1"));
+}
@@ -34,8 +34,11 @@ pub enum AttributeName {
     DecorationColor,
     DecorationStyle,
     TextOverflow,
+    BaselineShift,
+    FontFeatures,
     Rotate,
     Overflow,
+    PointerEvents,
     Margin,
     Position,
     PositionTop,
@@ -47,9 +50,22 @@ pub enum AttributeName {
     A11YAutoFocus,
     A11YName,
     A11YFocusable,
+    A11YFocusTrap,
     A11YRole,
     A11YId,
     A11YAlt,
+    A11YLive,
+    A11YChecked,
+    A11YExpanded,
+    A11YSelected,
+    A11YDisabled,
+    A11YNumericValue,
+    A11YMinNumericValue,
+    A11YMaxNumericValue,
+    A11YNumericValueStep,
+    A11YLabelledBy,
+    A11YDescribedBy,
+    A11YKeyboardShortcut,
     CanvasReference,
     Layer,
     OffsetY,
@@ -107,8 +123,11 @@ impl FromStr for AttributeName {
             "decoration_color" => Ok(AttributeName::DecorationColor),
             "decoration_style" => Ok(AttributeName::DecorationStyle),
             "text_overflow" => Ok(AttributeName::TextOverflow),
+            "baseline_shift" => Ok(AttributeName::BaselineShift),
+            "font_features" => Ok(AttributeName::FontFeatures),
             "rotate" => Ok(AttributeName::Rotate),
             "overflow" => Ok(AttributeName::Overflow),
+            "pointer_events" => Ok(AttributeName::PointerEvents),
             "margin" => Ok(AttributeName::Margin),
             "position" => Ok(AttributeName::Position),
             "position_top" => Ok(AttributeName::PositionTop),
@@ -123,6 +142,19 @@ impl FromStr for AttributeName {
             "a11y_id" => Ok(AttributeName::A11YId),
             "a11y_alt" => Ok(AttributeName::A11YAlt),
             "a11y_focusable" => Ok(AttributeName::A11YFocusable),
+            "a11y_focus_trap" => Ok(AttributeName::A11YFocusTrap),
+            "a11y_live" => Ok(AttributeName::A11YLive),
+            "a11y_checked" => Ok(AttributeName::A11YChecked),
+            "a11y_expanded" => Ok(AttributeName::A11YExpanded),
+            "a11y_selected" => Ok(AttributeName::A11YSelected),
+            "a11y_disabled" => Ok(AttributeName::A11YDisabled),
+            "a11y_numeric_value" => Ok(AttributeName::A11YNumericValue),
+            "a11y_min_numeric_value" => Ok(AttributeName::A11YMinNumericValue),
+            "a11y_max_numeric_value" => Ok(AttributeName::A11YMaxNumericValue),
+            "a11y_numeric_value_step" => Ok(AttributeName::A11YNumericValueStep),
+            "a11y_labelled_by" => Ok(AttributeName::A11YLabelledBy),
+            "a11y_described_by" => Ok(AttributeName::A11YDescribedBy),
+            "a11y_keyboard_shortcut" => Ok(AttributeName::A11YKeyboardShortcut),
             "canvas_reference" => Ok(AttributeName::CanvasReference),
             "layer" => Ok(AttributeName::Layer),
             "offset_y" => Ok(AttributeName::OffsetY),
@@ -7,6 +7,8 @@ pub enum EventName {
     Click,
     MiddleClick,
     RightClick,
+    DoubleClick,
+    LongPress,
 
     MouseUp,
     MouseDown,
@@ -16,17 +18,30 @@ pub enum EventName {
 
     Wheel,
 
+    Pinch,
+    Rotation,
+
+    PointerMove,
     PointerOver,
+    PointerOut,
     PointerDown,
     PointerEnter,
     PointerLeave,
     PointerUp,
+    LostPointerCapture,
 
     KeyDown,
     KeyUp,
     GlobalKeyDown,
     GlobalKeyUp,
 
+    GamepadButtonDown,
+    GamepadButtonUp,
+    GamepadAxis,
+    GlobalGamepadButtonDown,
+    GlobalGamepadButtonUp,
+    GlobalGamepadAxis,
+
     TouchCancel,
     TouchStart,
     TouchMove,
@@ -40,6 +55,18 @@ pub enum EventName {
     GlobalFileHoverCancelled,
 
     FileDrop,
+    FileHover,
+
+    A11yAction,
+
+    CaptureClick,
+    CaptureMouseDown,
+    CaptureMouseUp,
+    CaptureWheel,
+    CapturePointerDown,
+    CapturePointerUp,
+    CaptureKeyDown,
+    CaptureKeyUp,
 }
 
 impl FromStr for EventName {
@@ -50,21 +77,34 @@ impl FromStr for EventName {
             "click" => Ok(EventName::Click),
             "rightclick" => Ok(EventName::RightClick),
             "middleclick" => Ok(EventName::MiddleClick),
+            "doubleclick" => Ok(EventName::DoubleClick),
+            "longpress" => Ok(EventName::LongPress),
             "mouseup" => Ok(EventName::MouseUp),
             "mousedown" => Ok(EventName::MouseDown),
             "mousemove" => Ok(EventName::MouseMove),
             "mouseenter" => Ok(EventName::MouseEnter),
             "mouseleave" => Ok(EventName::MouseLeave),
             "wheel" => Ok(EventName::Wheel),
-            "pointermove" => Ok(EventName::PointerOver),
+            "pinch" => Ok(EventName::Pinch),
+            "rotation" => Ok(EventName::Rotation),
+            "pointermove" => Ok(EventName::PointerMove),
+            "pointerover" => Ok(EventName::PointerOver),
+            "pointerout" => Ok(EventName::PointerOut),
             "pointerdown" => Ok(EventName::PointerDown),
             "pointerenter" => Ok(EventName::PointerEnter),
             "pointerleave" => Ok(EventName::PointerLeave),
             "pointerup" => Ok(EventName::PointerUp),
+            "lostpointercapture" => Ok(EventName::LostPointerCapture),
             "keydown" => Ok(EventName::KeyDown),
             "keyup" => Ok(EventName::KeyUp),
             "globalkeydown" => Ok(EventName::GlobalKeyDown),
             "globalkeyup" => Ok(EventName::GlobalKeyUp),
+            "gamepadbuttondown" => Ok(EventName::GamepadButtonDown),
+            "gamepadbuttonup" => Ok(EventName::GamepadButtonUp),
+            "gamepadaxis" => Ok(EventName::GamepadAxis),
+            "globalgamepadbuttondown" => Ok(EventName::GlobalGamepadButtonDown),
+            "globalgamepadbuttonup" => Ok(EventName::GlobalGamepadButtonUp),
+            "globalgamepadaxis" => Ok(EventName::GlobalGamepadAxis),
             "touchcancel" => Ok(EventName::TouchCancel),
             "touchstart" => Ok(EventName::TouchStart),
             "touchmove" => Ok(EventName::TouchMove),
@@ -74,8 +114,18 @@ impl FromStr for EventName {
             "globalmousedown" => Ok(EventName::GlobalMouseDown),
             "globalmousemove" => Ok(EventName::GlobalMouseMove),
             "filedrop" => Ok(EventName::FileDrop),
+            "filehover" => Ok(EventName::FileHover),
+            "a11yaction" => Ok(EventName::A11yAction),
             "globalfilehover" => Ok(EventName::GlobalFileHover),
             "globalfilehovercancelled" => Ok(EventName::GlobalFileHoverCancelled),
+            "captureclick" => Ok(EventName::CaptureClick),
+            "capturemousedown" => Ok(EventName::CaptureMouseDown),
+            "capturemouseup" => Ok(EventName::CaptureMouseUp),
+            "capturewheel" => Ok(EventName::CaptureWheel),
+            "capturepointerdown" => Ok(EventName::CapturePointerDown),
+            "capturepointerup" => Ok(EventName::CapturePointerUp),
+            "capturekeydown" => Ok(EventName::CaptureKeyDown),
+            "capturekeyup" => Ok(EventName::CaptureKeyUp),
             _ => Err(()),
         }
     }
@@ -87,21 +137,34 @@ impl From<EventName> for &str {
             EventName::Click => "click",
             EventName::MiddleClick => "middleclick",
             EventName::RightClick => "rightclick",
+            EventName::DoubleClick => "doubleclick",
+            EventName::LongPress => "longpress",
             EventName::MouseUp => "mouseup",
             EventName::MouseDown => "mousedown",
             EventName::MouseMove => "mousemove",
             EventName::MouseEnter => "mouseenter",
             EventName::MouseLeave => "mouseleave",
             EventName::Wheel => "wheel",
-            EventName::PointerOver => "pointermove",
+            EventName::Pinch => "pinch",
+            EventName::Rotation => "rotation",
+            EventName::PointerMove => "pointermove",
+            EventName::PointerOver => "pointerover",
+            EventName::PointerOut => "pointerout",
             EventName::PointerDown => "pointerdown",
             EventName::PointerEnter => "pointerenter",
             EventName::PointerLeave => "pointerleave",
             EventName::PointerUp => "pointerup",
+            EventName::LostPointerCapture => "lostpointercapture",
             EventName::KeyUp => "keyup",
             EventName::KeyDown => "keydown",
             EventName::GlobalKeyDown => "globalkeydown",
             EventName::GlobalKeyUp => "globalkeyup",
+            EventName::GamepadButtonDown => "gamepadbuttondown",
+            EventName::GamepadButtonUp => "gamepadbuttonup",
+            EventName::GamepadAxis => "gamepadaxis",
+            EventName::GlobalGamepadButtonDown => "globalgamepadbuttondown",
+            EventName::GlobalGamepadButtonUp => "globalgamepadbuttonup",
+            EventName::GlobalGamepadAxis => "globalgamepadaxis",
             EventName::TouchCancel => "touchcancel",
             EventName::TouchStart => "touchstart",
             EventName::TouchMove => "touchmove",
@@ -111,8 +174,18 @@ impl From<EventName> for &str {
             EventName::GlobalMouseDown => "globalmousedown",
             EventName::GlobalMouseMove => "globalmousemove",
             EventName::FileDrop => "filedrop",
+            EventName::FileHover => "filehover",
+            EventName::A11yAction => "a11yaction",
             EventName::GlobalFileHover => "globalfilehover",
             EventName::GlobalFileHoverCancelled => "globalfilehovercancelled",
+            EventName::CaptureClick => "captureclick",
+            EventName::CaptureMouseDown => "capturemousedown",
+            EventName::CaptureMouseUp => "capturemouseup",
+            EventName::CaptureWheel => "capturewheel",
+            EventName::CapturePointerDown => "capturepointerdown",
+            EventName::CapturePointerUp => "capturepointerup",
+            EventName::CaptureKeyDown => "capturekeydown",
+            EventName::CaptureKeyUp => "capturekeyup",
         }
     }
 }
@@ -153,12 +226,64 @@ impl EventName {
             Self::GlobalFileHoverCancelled => Some(Self::GlobalFileHoverCancelled),
             Self::KeyDown => Some(EventName::GlobalKeyDown),
             Self::KeyUp => Some(EventName::GlobalKeyUp),
+            Self::GamepadButtonDown => Some(EventName::GlobalGamepadButtonDown),
+            Self::GamepadButtonUp => Some(EventName::GlobalGamepadButtonUp),
+            Self::GamepadAxis => Some(EventName::GlobalGamepadAxis),
             _ => None,
         }
     }
 
+    /// Get the equivalent to a capture event
+    pub fn get_capture_event(&self) -> Option<Self> {
+        match self {
+            Self::Click => Some(Self::CaptureClick),
+            Self::MouseDown => Some(Self::CaptureMouseDown),
+            Self::MouseUp => Some(Self::CaptureMouseUp),
+            Self::Wheel => Some(Self::CaptureWheel),
+            Self::PointerDown => Some(Self::CapturePointerDown),
+            Self::PointerUp => Some(Self::CapturePointerUp),
+            Self::KeyDown => Some(Self::CaptureKeyDown),
+            Self::KeyUp => Some(Self::CaptureKeyUp),
+            _ => None,
+        }
+    }
+
+    /// Check if it's one of the capture variants, these are emitted from the root to the target
+    /// before any other listener
+    pub fn is_capture(&self) -> bool {
+        matches!(
+            self,
+            Self::CaptureClick
+                | Self::CaptureMouseDown
+                | Self::CaptureMouseUp
+                | Self::CaptureWheel
+                | Self::CapturePointerDown
+                | Self::CapturePointerUp
+                | Self::CaptureKeyDown
+                | Self::CaptureKeyUp
+        )
+    }
+
+    /// Check if it's one of the global variants
+    pub fn is_global(&self) -> bool {
+        matches!(
+            self,
+            Self::GlobalKeyDown
+                | Self::GlobalKeyUp
+                | Self::GlobalGamepadButtonDown
+                | Self::GlobalGamepadButtonUp
+                | Self::GlobalGamepadAxis
+                | Self::GlobalClick
+                | Self::GlobalPointerUp
+                | Self::GlobalMouseDown
+                | Self::GlobalMouseMove
+                | Self::GlobalFileHover
+                | Self::GlobalFileHoverCancelled
+        )
+    }
+
     /// Some events might cause other events, like for example:
-    /// A `mousemove` might also trigger a `mouseenter`
+    /// A `mousemove` might also trigger a `pointermove`
     /// A `mousedown` or a `touchdown` might also trigger a `pointerdown`
     pub fn get_collateral_events(&self) -> SmallVec<[Self; 4]> {
         let mut events = SmallVec::new();
@@ -166,16 +291,18 @@ impl EventName {
         events.push(*self);
 
         match self {
-            Self::MouseMove => {
-                events.extend([Self::MouseEnter, Self::PointerEnter, Self::PointerOver])
-            }
-            Self::TouchMove => events.extend([Self::PointerEnter, Self::PointerOver]),
+            Self::MouseMove | Self::TouchMove => events.push(Self::PointerMove),
             Self::MouseDown | Self::TouchStart => events.push(Self::PointerDown),
-            Self::MouseUp | Self::MiddleClick | Self::RightClick | Self::TouchEnd => {
+            Self::MouseUp => events.extend([Self::Click, Self::PointerUp, Self::DoubleClick]),
+            Self::MiddleClick | Self::RightClick | Self::TouchEnd => {
                 events.extend([Self::Click, Self::PointerUp])
             }
-            Self::MouseLeave => events.push(Self::PointerLeave),
-            Self::GlobalFileHover | Self::GlobalFileHoverCancelled => events.clear(),
+            // Files hovered over the window are also hovered over the elements under the cursor
+            Self::GlobalFileHover => {
+                events.clear();
+                events.push(Self::FileHover)
+            }
+            Self::GlobalFileHoverCancelled => events.clear(),
             _ => {}
         }
 
@@ -193,10 +320,15 @@ impl EventName {
             &self,
             Self::PointerEnter
                 | Self::PointerLeave
+                | Self::PointerMove
                 | Self::PointerOver
+                | Self::PointerOut
                 | Self::PointerDown
                 | Self::PointerUp
+                | Self::LostPointerCapture
                 | Self::GlobalPointerUp
+                | Self::CapturePointerDown
+                | Self::CapturePointerUp
         )
     }
 
@@ -204,40 +336,83 @@ impl EventName {
     pub fn was_cursor_moved(&self) -> bool {
         matches!(
             &self,
-            Self::MouseMove | Self::MouseEnter | Self::PointerEnter | Self::PointerOver
+            Self::MouseMove | Self::MouseEnter | Self::PointerEnter | Self::PointerMove
         )
     }
 
     // Bubble all events except:
-    // - Global Keyboard events
+    // - Global Keyboard and Gamepad events
     // - Mouse movements events
+    // - Capture events
+    // - Lost pointer capture events
     pub fn does_bubble(&self) -> bool {
-        !matches!(
+        !self.is_capture()
+            && !matches!(
+                self,
+                Self::GlobalKeyDown
+                    | Self::GlobalKeyUp
+                    | Self::GlobalGamepadButtonDown
+                    | Self::GlobalGamepadButtonUp
+                    | Self::GlobalGamepadAxis
+                    | Self::LostPointerCapture
+                    | Self::MouseLeave
+                    | Self::PointerLeave
+                    | Self::MouseEnter
+                    | Self::PointerEnter
+                    | Self::MouseMove
+                    | Self::PointerMove
+            )
+    }
+
+    /// Check if this event is sent to the Node capturing the pointer, if any, instead of the Nodes under the cursor
+    pub fn can_be_pointer_captured(&self) -> bool {
+        matches!(
             self,
-            Self::GlobalKeyDown
-                | Self::GlobalKeyUp
-                | Self::MouseLeave
-                | Self::PointerLeave
-                | Self::MouseEnter
-                | Self::PointerEnter
-                | Self::MouseMove
-                | Self::PointerOver
+            Self::PointerMove | Self::PointerUp | Self::MouseMove | Self::MouseUp
+        )
+    }
+
+    /// Check if this event releases the pointer capture
+    pub fn releases_pointer_capture(&self) -> bool {
+        matches!(
+            self,
+            Self::MouseUp
+                | Self::MiddleClick
+                | Self::RightClick
+                | Self::TouchEnd
+                | Self::TouchCancel
         )
     }
 
     /// Only let events that do not move the mouse, go through solid nodes
     pub fn does_go_through_solid(&self) -> bool {
-        matches!(self, Self::GlobalKeyDown | Self::GlobalKeyUp)
+        matches!(
+            self,
+            Self::GlobalKeyDown
+                | Self::GlobalKeyUp
+                | Self::GlobalGamepadButtonDown
+                | Self::GlobalGamepadButtonUp
+                | Self::GlobalGamepadAxis
+        )
     }
 
     /// Check if this event can change the hover state of a Node.
     pub fn can_change_hover_state(&self) -> bool {
         matches!(
             self,
-            Self::MouseMove | Self::MouseEnter | Self::PointerOver | Self::PointerEnter
+            Self::MouseMove
+                | Self::TouchStart
+                | Self::TouchMove
+                | Self::TouchEnd
+                | Self::TouchCancel
         )
     }
 
+    /// Check if this event means that the pointer no longer hovers any Node, e.g a finger being lifted.
+    pub fn clears_hover_state(&self) -> bool {
+        matches!(self, Self::TouchEnd | Self::TouchCancel)
+    }
+
     /// Check if this event can change the press state of a Node.
     pub fn can_change_press_state(&self) -> bool {
         matches!(self, Self::MouseDown | Self::TouchStart | Self::PointerDown)
@@ -257,6 +432,30 @@ impl EventName {
         )
     }
 
+    /// Check if the data of this event carries the click count of the current clicks sequence
+    pub fn has_click_count(&self) -> bool {
+        matches!(
+            &self,
+            Self::MouseDown
+                | Self::MouseUp
+                | Self::Click
+                | Self::MiddleClick
+                | Self::RightClick
+                | Self::DoubleClick
+                | Self::LongPress
+                | Self::PointerDown
+                | Self::PointerUp
+                | Self::GlobalClick
+                | Self::GlobalMouseDown
+                | Self::GlobalPointerUp
+                | Self::CaptureClick
+                | Self::CaptureMouseDown
+                | Self::CaptureMouseUp
+                | Self::CapturePointerDown
+                | Self::CapturePointerUp
+        )
+    }
+
     /// Check if the event was pressed
     pub fn is_pressed(&self) -> bool {
         matches!(&self, Self::Click)
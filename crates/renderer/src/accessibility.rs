@@ -119,7 +119,7 @@ impl AccessKitManager {
             .accessibility_tree
             .lock()
             .unwrap()
-            .set_focus_on_next_node(direction, rdom);
+            .set_focus_on_next_node(direction, rdom, layout);
 
         // Notify the components
         platform_sender.send_modify(|state| {
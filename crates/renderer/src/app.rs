@@ -6,7 +6,6 @@ use dioxus_core::{
 };
 use freya_core::prelude::*;
 use freya_engine::prelude::*;
-use freya_native_core::prelude::NodeImmutableDioxusExt;
 use futures_task::Waker;
 use futures_util::Future;
 use pin_utils::pin_mut;
@@ -22,7 +21,10 @@ use torin::geometry::Area;
 use winit::{
     dpi::PhysicalSize,
     event_loop::EventLoopProxy,
-    window::Window,
+    window::{
+        Window,
+        WindowId,
+    },
 };
 
 use crate::{
@@ -30,7 +32,6 @@ use crate::{
     devtools::Devtools,
     size::WinitSize,
     winit_waker::winit_waker,
-    EmbeddedFonts,
     HoveredNode,
 };
 
@@ -42,6 +43,7 @@ pub struct Application {
     pub(crate) events: EventsQueue,
     pub(crate) vdom_waker: Waker,
     pub(crate) proxy: EventLoopProxy<EventMessage>,
+    pub(crate) window_id: WindowId,
     pub(crate) devtools: Option<Devtools>,
     pub(crate) event_emitter: EventEmitter,
     pub(crate) event_receiver: EventReceiver,
@@ -52,10 +54,13 @@ pub struct Application {
     pub(crate) font_collection: FontCollection,
     pub(crate) font_mgr: FontMgr,
     pub(crate) ticker_sender: broadcast::Sender<()>,
+    pub(crate) menu_sender: MenuEventsSender,
+    pub(crate) close_requests: CloseRequests,
     pub(crate) plugins: PluginsManager,
     pub(crate) measure_layout_on_next_render: bool,
     pub(crate) init_accessibility_on_next_render: bool,
     pub(crate) default_fonts: Vec<String>,
+    pub(crate) fonts: FontsRegistry,
 }
 
 impl Application {
@@ -66,25 +71,13 @@ impl Application {
         proxy: &EventLoopProxy<EventMessage>,
         devtools: Option<Devtools>,
         window: &Window,
-        fonts_config: EmbeddedFonts,
         plugins: PluginsManager,
-        default_fonts: Vec<String>,
+        fonts: FontsRegistry,
     ) -> Self {
         let accessibility = AccessKitManager::new(window, proxy.clone());
 
         let mut font_collection = FontCollection::new();
-        let def_mgr = FontMgr::default();
-
-        let mut provider = TypefaceFontProvider::new();
-
-        for (font_name, font_data) in fonts_config {
-            let ft_type = def_mgr.new_from_data(font_data, None).unwrap();
-            provider.register_typeface(ft_type, Some(font_name));
-        }
-
-        let font_mgr: FontMgr = provider.into();
-        font_collection.set_default_font_manager(def_mgr, None);
-        font_collection.set_dynamic_font_manager(font_mgr.clone());
+        let font_mgr = fonts.setup_font_collection(&mut font_collection);
 
         let (event_emitter, event_receiver) = mpsc::unbounded_channel();
         let (platform_sender, platform_receiver) = watch::channel(NativePlatformState {
@@ -99,8 +92,9 @@ impl Application {
             sdom,
             vdom,
             events: EventsQueue::new(),
-            vdom_waker: winit_waker(proxy),
+            vdom_waker: winit_waker(proxy, window.id()),
             proxy: proxy.clone(),
+            window_id: window.id(),
             devtools,
             event_emitter,
             event_receiver,
@@ -111,10 +105,13 @@ impl Application {
             font_collection,
             font_mgr,
             ticker_sender: broadcast::channel(5).0,
+            menu_sender: broadcast::channel(5).0,
+            close_requests: CloseRequests::default(),
             plugins,
             measure_layout_on_next_render: false,
             init_accessibility_on_next_render: false,
-            default_fonts,
+            default_fonts: fonts.default_fonts(),
+            fonts,
             compositor: Compositor::default(),
         };
 
@@ -133,10 +130,15 @@ impl Application {
         }
         self.vdom
             .insert_any_root_context(Box::new(self.proxy.clone()));
+        self.vdom.insert_any_root_context(Box::new(self.window_id));
         self.vdom
             .insert_any_root_context(Box::new(self.platform_receiver.clone()));
         self.vdom
             .insert_any_root_context(Box::new(Arc::new(self.ticker_sender.subscribe())));
+        self.vdom
+            .insert_any_root_context(Box::new(self.menu_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.close_requests.clone()));
         self.vdom
             .insert_any_root_context(Box::new(self.sdom.get().accessibility_generator().clone()));
     }
@@ -193,18 +195,15 @@ impl Application {
                     Some(events) = self.event_receiver.recv() => {
                         let fdom = self.sdom.get();
                         let rdom = fdom.rdom();
-                        for event in events {
-                            if let Some(element_id) = rdom
-                                .get(event.node_id)
-                                .and_then(|node| node.mounted_id())
-                            {
-                                let name = event.name.into();
-                                let data = event.data.any();
-                                self.vdom
-                                    .handle_event(name, data, element_id, event.bubbles);
+                        emit_dom_events(
+                            events,
+                            rdom,
+                            &mut self.nodes_state,
+                            |name, data, element_id, bubbles| {
+                                self.vdom.handle_event(name, data, element_id, bubbles);
                                 self.vdom.process_events();
-                            }
-                        }
+                            },
+                        );
                     },
                     _ = self.vdom.wait_for_work() => {},
                 }
@@ -213,7 +212,12 @@ impl Application {
 
             match fut.poll(&mut cx) {
                 std::task::Poll::Ready(_) => {
-                    self.proxy.send_event(EventMessage::PollVDOM).ok();
+                    self.proxy
+                        .send_event(EventMessage::ToWindow(
+                            self.window_id,
+                            Box::new(EventMessage::PollVDOM),
+                        ))
+                        .ok();
                 }
                 std::task::Poll::Pending => return,
             }
@@ -330,7 +334,9 @@ impl Application {
             ));
         self.sdom.get().layout().reset();
         self.platform_sender.send_modify(|state| {
+            let is_occluded = state.information.is_occluded;
             state.information = PlatformInformation::from_winit(window);
+            state.information.is_occluded = is_occluded;
         })
     }
 
@@ -361,6 +367,16 @@ impl Application {
         self.ticker_sender.send(()).ok();
     }
 
+    /// Apply a change to the fonts and measure the texts again if necessary.
+    pub fn update_fonts(&mut self, operation: &FontOperation, window: &Window) {
+        if self.fonts.apply(operation) {
+            self.font_mgr = self.fonts.setup_font_collection(&mut self.font_collection);
+            self.default_fonts = self.fonts.default_fonts();
+            self.resize(window);
+            window.request_redraw();
+        }
+    }
+
     /// Update the [NavigationMode].
     pub fn set_navigation_mode(&mut self, navigation_mode: NavigationMode) {
         self.platform_sender.send_modify(|state| {
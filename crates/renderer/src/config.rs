@@ -1,8 +1,16 @@
 use std::{
     io::Cursor,
+    path::PathBuf,
     sync::Arc,
+    time::Duration,
 };
 
+pub use freya_core::window_config::{
+    GraphicsBackend,
+    WindowBuilderHook,
+    WindowCallback,
+    WindowConfig,
+};
 use freya_core::{
     plugins::{
         FreyaPlugin,
@@ -19,52 +27,19 @@ use winit::window::{
     WindowAttributes,
 };
 
-pub type WindowBuilderHook = Box<dyn Fn(WindowAttributes) -> WindowAttributes>;
 pub type EmbeddedFonts<'a> = Vec<(&'a str, &'a [u8])>;
 
-/// Configuration for a Window.
-pub struct WindowConfig {
-    /// Size of the Window.
-    pub size: (f64, f64),
-    /// Minimum size of the Window.
-    pub min_size: Option<(f64, f64)>,
-    /// Maximum size of the Window.
-    pub max_size: Option<(f64, f64)>,
-    /// Enable Window decorations.
-    pub decorations: bool,
-    /// Title for the Window.
-    pub title: &'static str,
-    /// Make the Window transparent or not.
-    pub transparent: bool,
-    /// Background color of the Window.
-    pub background: Color,
-    /// The Icon of the Window.
-    pub icon: Option<Icon>,
-    /// Setup callback.
-    pub on_setup: Option<WindowCallback>,
-    /// Exit callback.
-    pub on_exit: Option<WindowCallback>,
-    /// Hook function called with the Window Attributes.
-    pub window_attributes_hook: Option<WindowBuilderHook>,
-}
+/// Create the tray icon, it might be called in a separate thread.
+#[cfg(feature = "tray")]
+pub type TrayIconBuilder = Box<dyn FnOnce() -> tray_icon::TrayIconBuilder + Send>;
 
-impl Default for WindowConfig {
-    fn default() -> Self {
-        Self {
-            size: (600.0, 600.0),
-            min_size: None,
-            max_size: None,
-            decorations: true,
-            title: "Freya app",
-            transparent: false,
-            background: Color::WHITE,
-            icon: None,
-            on_setup: None,
-            on_exit: None,
-            window_attributes_hook: None,
-        }
-    }
-}
+/// Create the menu of the tray icon, it might be called in a separate thread.
+#[cfg(feature = "tray")]
+pub type TrayMenuBuilder = Box<dyn FnOnce() -> tray_icon::menu::Menu + Send>;
+
+/// Create the menu bar of the app.
+#[cfg(feature = "tray")]
+pub type MenuBarBuilder = Box<dyn FnOnce() -> tray_icon::menu::Menu>;
 
 /// Launch configuration.
 pub struct LaunchConfig<'a, T: Clone = ()> {
@@ -73,6 +48,13 @@ pub struct LaunchConfig<'a, T: Clone = ()> {
     pub embedded_fonts: EmbeddedFonts<'a>,
     pub plugins: PluginsManager,
     pub default_fonts: Vec<String>,
+    pub fallback_fonts: Vec<String>,
+    #[cfg(feature = "tray")]
+    pub tray_icon: Option<TrayIconBuilder>,
+    #[cfg(feature = "tray")]
+    pub tray_menu: Option<TrayMenuBuilder>,
+    #[cfg(feature = "tray")]
+    pub menu_bar: Option<MenuBarBuilder>,
 }
 
 impl<'a, T: Clone> Default for LaunchConfig<'a, T> {
@@ -83,6 +65,13 @@ impl<'a, T: Clone> Default for LaunchConfig<'a, T> {
             embedded_fonts: Default::default(),
             plugins: Default::default(),
             default_fonts: default_fonts(),
+            fallback_fonts: Vec::new(),
+            #[cfg(feature = "tray")]
+            tray_icon: None,
+            #[cfg(feature = "tray")]
+            tray_menu: None,
+            #[cfg(feature = "tray")]
+            menu_bar: None,
         }
     }
 }
@@ -108,8 +97,6 @@ impl LaunchConfig<'_, ()> {
     }
 }
 
-pub type WindowCallback = Arc<Box<fn(&mut Window)>>;
-
 impl<'a, T: Clone> LaunchConfig<'a, T> {
     /// Specify a Window size.
     pub fn with_size(mut self, width: f64, height: f64) -> Self {
@@ -129,6 +116,45 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
         self
     }
 
+    /// Specify the Window position in the desktop, in logical pixels.
+    pub fn with_position(mut self, x: f64, y: f64) -> Self {
+        self.window_config.position = Some((x, y));
+        self
+    }
+
+    /// Whether the Window can be resized or not.
+    pub fn with_resizable(mut self, resizable: bool) -> Self {
+        self.window_config.resizable = resizable;
+        self
+    }
+
+    /// Keep the Window above the other Windows.
+    pub fn with_always_on_top(mut self, always_on_top: bool) -> Self {
+        self.window_config.always_on_top = always_on_top;
+        self
+    }
+
+    /// Save the position, size and maximized state of the Window into the given file when closed,
+    /// and restore them the next time the app is launched.
+    pub fn with_persisted_geometry(mut self, path: impl Into<PathBuf>) -> Self {
+        self.window_config.persisted_geometry = Some(path.into());
+        self
+    }
+
+    /// Hide the Window instead of closing it when the user requests it,
+    /// e.g to keep the app running in the tray. Use `UsePlatform::exit` to close the app.
+    pub fn with_hide_on_close(mut self, hide_on_close: bool) -> Self {
+        self.window_config.hide_on_close = hide_on_close;
+        self
+    }
+
+    /// Specify the maximum time between two clicks of a double click,
+    /// by default it's the one configured in the platform.
+    pub fn with_double_click_duration(mut self, double_click_duration: Duration) -> Self {
+        self.window_config.double_click_duration = Some(double_click_duration);
+        self
+    }
+
     /// Whether the Window will have decorations or not.
     pub fn with_decorations(mut self, decorations: bool) -> Self {
         self.window_config.decorations = decorations;
@@ -177,6 +203,13 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
         self
     }
 
+    /// Register a fallback font, tried after the default fonts and the previous fallback fonts
+    /// for the characters they don't support, e.g CJK, Arabic or emojis.
+    pub fn with_fallback_font(mut self, font_name: &str) -> Self {
+        self.fallback_fonts.push(font_name.to_string());
+        self
+    }
+
     /// Specify the Window icon.
     pub fn with_icon(mut self, icon: Icon) -> Self {
         self.window_config.icon = Some(icon);
@@ -201,10 +234,51 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
         self
     }
 
+    /// Specify the graphics backend used to render the Window.
+    pub fn with_graphics_backend(mut self, graphics_backend: GraphicsBackend) -> Self {
+        self.window_config.graphics_backend = graphics_backend;
+        self
+    }
+
+    /// Show an icon in the system tray.
+    /// Clicking the icon shows the main Window, except in Linux where the icon can't be clicked,
+    /// so a `Show` item that does it is added to the end of its menu instead.
+    ///
+    /// Set the menu of the icon with [`LaunchConfig::with_tray_menu`], not with `TrayIconBuilder::with_menu`.
+    #[cfg(feature = "tray")]
+    pub fn with_tray_icon(
+        mut self,
+        tray_icon: impl FnOnce() -> tray_icon::TrayIconBuilder + Send + 'static,
+    ) -> Self {
+        self.tray_icon = Some(Box::new(tray_icon));
+        self
+    }
+
+    /// Set the menu of the tray icon, the activations of its items can be listened with `use_menu_event`.
+    #[cfg(feature = "tray")]
+    pub fn with_tray_menu(
+        mut self,
+        tray_menu: impl FnOnce() -> tray_icon::menu::Menu + Send + 'static,
+    ) -> Self {
+        self.tray_menu = Some(Box::new(tray_menu));
+        self
+    }
+
+    /// Set the menu bar of the app, supported in macOS and Windows.
+    /// The activations of its items can be listened with `use_menu_event`.
+    #[cfg(feature = "tray")]
+    pub fn with_menu_bar(
+        mut self,
+        menu_bar: impl FnOnce() -> tray_icon::menu::Menu + 'static,
+    ) -> Self {
+        self.menu_bar = Some(Box::new(menu_bar));
+        self
+    }
+
     /// Register a Window Attributes hook.
     pub fn with_window_attributes(
         mut self,
-        window_attributes_hook: impl Fn(WindowAttributes) -> WindowAttributes + 'static,
+        window_attributes_hook: impl Fn(WindowAttributes) -> WindowAttributes + Send + Sync + 'static,
     ) -> Self {
         self.window_config.window_attributes_hook = Some(Box::new(window_attributes_hook));
         self
@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+/// Get the maximum time between two clicks of a double click configured in the platform, if it's known.
+#[cfg(target_os = "windows")]
+pub fn system_double_click_duration() -> Option<Duration> {
+    // SAFETY: It has no preconditions
+    let millis = unsafe { windows_sys::Win32::UI::Input::KeyboardAndMouse::GetDoubleClickTime() };
+    Some(Duration::from_millis(millis as u64))
+}
+
+/// Get the maximum time between two clicks of a double click configured in the platform, if it's known.
+#[cfg(target_os = "macos")]
+pub fn system_double_click_duration() -> Option<Duration> {
+    // SAFETY: It has no preconditions
+    let secs = unsafe { objc2_app_kit::NSEvent::doubleClickInterval() };
+    Duration::try_from_secs_f64(secs).ok()
+}
+
+/// Get the maximum time between two clicks of a double click configured in the platform, if it's known.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub fn system_double_click_duration() -> Option<Duration> {
+    None
+}
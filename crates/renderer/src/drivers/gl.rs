@@ -1,4 +1,5 @@
 use std::{
+    error::Error,
     ffi::CString,
     num::NonZeroU32,
 };
@@ -32,6 +33,8 @@ use glutin::{
         PossiblyCurrentContext,
     },
     display::{
+        Display,
+        DisplayApiPreference,
         GetGlDisplay,
         GlDisplay,
     },
@@ -46,8 +49,12 @@ use glutin::{
         WindowSurface,
     },
 };
-use glutin_winit::DisplayBuilder;
-use raw_window_handle::HasWindowHandle;
+use raw_window_handle::{
+    HasDisplayHandle,
+    HasWindowHandle,
+};
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+use winit::platform::x11::register_xlib_error_hook;
 use winit::{
     dpi::PhysicalSize,
     event_loop::ActiveEventLoop,
@@ -59,7 +66,7 @@ use winit::{
 
 use crate::{
     size::WinitSize,
-    LaunchConfig,
+    WindowConfig,
 };
 
 /// Graphics driver using OpenGL.
@@ -82,36 +89,48 @@ impl Drop for OpenGLDriver {
 }
 
 impl OpenGLDriver {
-    pub fn new<State: Clone + 'static>(
+    /// Create the Window and its OpenGL context, fails if OpenGL is not available.
+    pub fn new(
         event_loop: &ActiveEventLoop,
         window_attributes: WindowAttributes,
-        config: &LaunchConfig<State>,
-    ) -> (Self, Window, SkiaSurface) {
+        window_config: &WindowConfig,
+    ) -> Result<(Self, Window, SkiaSurface), Box<dyn Error>> {
         let template = ConfigTemplateBuilder::new()
             .with_alpha_size(8)
-            .with_transparency(config.window_config.transparent);
-
-        let display_builder = DisplayBuilder::new().with_window_attributes(Some(window_attributes));
-        let (window, gl_config) = display_builder
-            .build(event_loop, template, |configs| {
-                configs
-                    .reduce(|accum, config| {
-                        let transparency_check = config.supports_transparency().unwrap_or(false)
-                            & !accum.supports_transparency().unwrap_or(false);
-
-                        if transparency_check || config.num_samples() < accum.num_samples() {
-                            config
-                        } else {
-                            accum
-                        }
-                    })
-                    .unwrap()
+            .with_transparency(window_config.transparent);
+
+        // WGL needs the Window to find the configs that support modern OpenGL
+        #[cfg(target_os = "windows")]
+        let window = event_loop.create_window(window_attributes)?;
+        #[cfg(target_os = "windows")]
+        let template = template.compatible_with_native_window(window.window_handle()?.as_raw());
+
+        #[cfg(target_os = "windows")]
+        let preference = DisplayApiPreference::WglThenEgl(Some(window.window_handle()?.as_raw()));
+        #[cfg(target_os = "macos")]
+        let preference = DisplayApiPreference::Cgl;
+        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+        let preference = DisplayApiPreference::GlxThenEgl(Box::new(register_xlib_error_hook));
+
+        let display = unsafe { Display::new(event_loop.display_handle()?.as_raw(), preference)? };
+
+        let gl_config = unsafe { display.find_configs(template.build())? }
+            .reduce(|accum, config| {
+                let transparency_check = config.supports_transparency().unwrap_or(false)
+                    & !accum.supports_transparency().unwrap_or(false);
+
+                if transparency_check || config.num_samples() < accum.num_samples() {
+                    config
+                } else {
+                    accum
+                }
             })
-            .unwrap();
+            .ok_or("Could not find an OpenGL config")?;
 
-        let window = window.expect("Could not create window with OpenGL context");
+        #[cfg(not(target_os = "windows"))]
+        let window = glutin_winit::finalize_window(event_loop, window_attributes, &gl_config)?;
 
-        let window_handle = window.window_handle().unwrap();
+        let window_handle = window.window_handle()?;
 
         let context_attributes = ContextAttributesBuilder::new()
             .with_profile(GlProfile::Core)
@@ -126,12 +145,11 @@ impl OpenGLDriver {
             gl_config
                 .display()
                 .create_context(&gl_config, &context_attributes)
-                .unwrap_or_else(|_| {
+                .or_else(|_| {
                     gl_config
                         .display()
                         .create_context(&gl_config, &fallback_context_attributes)
-                        .expect("failed to create context")
-                })
+                })?
         };
 
         let (width, height): (u32, u32) = window.inner_size().into();
@@ -145,13 +163,10 @@ impl OpenGLDriver {
         let gl_surface = unsafe {
             gl_config
                 .display()
-                .create_window_surface(&gl_config, &attrs)
-                .expect("Could not create gl window surface")
+                .create_window_surface(&gl_config, &attrs)?
         };
 
-        let gl_context = not_current_gl_context
-            .make_current(&gl_surface)
-            .expect("Could not make GL context current when setting up skia renderer");
+        let gl_context = not_current_gl_context.make_current(&gl_surface)?;
 
         // Try setting vsync.
         gl_surface
@@ -171,14 +186,14 @@ impl OpenGLDriver {
                 .display()
                 .get_proc_address(CString::new(name).unwrap().as_c_str())
         })
-        .expect("Could not create interface");
+        .ok_or("Could not create interface")?;
 
         let fb_info = {
             let mut fboid: GLint = 0;
             unsafe { GetIntegerv(FRAMEBUFFER_BINDING, &mut fboid) };
 
             FramebufferInfo {
-                fboid: fboid.try_into().unwrap(),
+                fboid: fboid.try_into()?,
                 format: Format::RGBA8.into(),
                 ..Default::default()
             }
@@ -189,7 +204,7 @@ impl OpenGLDriver {
         let size = window.inner_size();
 
         let mut gr_context =
-            direct_contexts::make_gl(interface, None).expect("Could not create direct context");
+            direct_contexts::make_gl(interface, None).ok_or("Could not create direct context")?;
 
         let render_target =
             backend_render_targets::make_gl(size.to_skia(), num_samples, stencil_size, fb_info);
@@ -201,7 +216,7 @@ impl OpenGLDriver {
             None,
             None,
         )
-        .expect("Could not create skia surface");
+        .ok_or("Could not create skia surface")?;
 
         let driver = OpenGLDriver {
             gl_context,
@@ -212,7 +227,7 @@ impl OpenGLDriver {
             fb_info,
         };
 
-        (driver, window, skia_surface)
+        Ok((driver, window, skia_surface))
     }
 
     pub fn make_current(&mut self) {
@@ -1,8 +1,14 @@
 mod gl;
+mod software;
 
 use freya_engine::prelude::Surface as SkiaSurface;
 pub use gl::*;
 use glutin::surface::GlSurface;
+pub use software::*;
+use tracing::{
+    error,
+    warn,
+};
 use winit::{
     dpi::PhysicalSize,
     event_loop::ActiveEventLoop,
@@ -12,40 +18,77 @@ use winit::{
     },
 };
 
-use crate::LaunchConfig;
+use crate::{
+    GraphicsBackend,
+    WindowConfig,
+};
 
 pub enum GraphicsDriver {
     OpenGl(OpenGLDriver),
+    Software(SoftwareDriver),
 }
 
 impl GraphicsDriver {
-    pub fn new<State: Clone + 'static>(
+    /// Create the Window with the [GraphicsBackend] of the config,
+    /// falling back to the software driver if OpenGL fails.
+    pub fn new(
+        event_loop: &ActiveEventLoop,
+        window_attributes: WindowAttributes,
+        window_config: &WindowConfig,
+    ) -> (Self, Window, SkiaSurface) {
+        match window_config.graphics_backend {
+            GraphicsBackend::Auto => {
+                match OpenGLDriver::new(event_loop, window_attributes.clone(), window_config) {
+                    Ok((driver, window, surface)) => (Self::OpenGl(driver), window, surface),
+                    Err(err) => {
+                        warn!("Could not use OpenGL ({err}), falling back to software rendering.");
+                        Self::new_software(event_loop, window_attributes)
+                    }
+                }
+            }
+            GraphicsBackend::OpenGl => {
+                match OpenGLDriver::new(event_loop, window_attributes.clone(), window_config) {
+                    Ok((driver, window, surface)) => (Self::OpenGl(driver), window, surface),
+                    Err(err) => {
+                        error!("Could not use the required OpenGL backend ({err}), falling back to software rendering.");
+                        Self::new_software(event_loop, window_attributes)
+                    }
+                }
+            }
+            GraphicsBackend::Software => Self::new_software(event_loop, window_attributes),
+        }
+    }
+
+    fn new_software(
         event_loop: &ActiveEventLoop,
         window_attributes: WindowAttributes,
-        config: &LaunchConfig<State>,
     ) -> (Self, Window, SkiaSurface) {
-        let (driver, window, surface) = OpenGLDriver::new(event_loop, window_attributes, config);
-        (Self::OpenGl(driver), window, surface)
+        let (driver, window, surface) = SoftwareDriver::new(event_loop, window_attributes)
+            .expect("Could not create the software driver");
+        (Self::Software(driver), window, surface)
     }
 
     pub fn make_current(&mut self) {
         match self {
             Self::OpenGl(gl) => gl.make_current(),
+            Self::Software(_) => {}
         }
     }
 
-    pub fn flush_and_submit(&mut self) {
+    pub fn flush_and_submit(&mut self, surface: &mut SkiaSurface) {
         match self {
             Self::OpenGl(gl) => {
                 gl.gr_context.flush_and_submit();
                 gl.gl_surface.swap_buffers(&gl.gl_context).unwrap();
             }
+            Self::Software(software) => software.present(surface),
         }
     }
 
     pub fn resize(&mut self, size: PhysicalSize<u32>) -> (SkiaSurface, SkiaSurface) {
         match self {
             Self::OpenGl(gl) => gl.resize(size),
+            Self::Software(software) => software.resize(size),
         }
     }
 }
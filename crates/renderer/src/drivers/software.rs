@@ -0,0 +1,129 @@
+use std::error::Error;
+
+use freya_engine::prelude::{
+    raster_n32_premul,
+    AlphaType,
+    ColorType,
+    ImageInfo,
+    Surface as SkiaSurface,
+};
+use raw_window_handle::{
+    DisplayHandle,
+    HandleError,
+    HasDisplayHandle,
+    HasWindowHandle,
+    RawDisplayHandle,
+    RawWindowHandle,
+    WindowHandle,
+};
+use softbuffer::{
+    Context,
+    Surface as SoftbufferSurface,
+};
+use winit::{
+    dpi::PhysicalSize,
+    event_loop::ActiveEventLoop,
+    window::{
+        Window,
+        WindowAttributes,
+    },
+};
+
+use crate::size::WinitSize;
+
+/// Raw handles of the Window.
+///
+/// The driver is always dropped before its Window, so they stay valid while being used.
+pub(crate) struct WindowHandles {
+    window: RawWindowHandle,
+    display: RawDisplayHandle,
+}
+
+impl WindowHandles {
+    fn new(window: &Window) -> Result<Self, HandleError> {
+        Ok(Self {
+            window: window.window_handle()?.as_raw(),
+            display: window.display_handle()?.as_raw(),
+        })
+    }
+}
+
+impl HasWindowHandle for WindowHandles {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        Ok(unsafe { WindowHandle::borrow_raw(self.window) })
+    }
+}
+
+impl HasDisplayHandle for WindowHandles {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        Ok(unsafe { DisplayHandle::borrow_raw(self.display) })
+    }
+}
+
+/// Graphics driver rendering with the CPU into a Skia raster surface,
+/// for machines without a working OpenGL, e.g VMs, remote desktops or CI.
+pub struct SoftwareDriver {
+    pub(crate) softbuffer_surface: SoftbufferSurface<WindowHandles, WindowHandles>,
+    pub(crate) pixels: Vec<u8>,
+}
+
+impl SoftwareDriver {
+    pub fn new(
+        event_loop: &ActiveEventLoop,
+        window_attributes: WindowAttributes,
+    ) -> Result<(Self, Window, SkiaSurface), Box<dyn Error>> {
+        let window = event_loop.create_window(window_attributes)?;
+
+        let context = Context::new(WindowHandles::new(&window)?)?;
+        let softbuffer_surface = SoftbufferSurface::new(&context, WindowHandles::new(&window)?)?;
+
+        let mut driver = SoftwareDriver {
+            softbuffer_surface,
+            pixels: Vec::new(),
+        };
+
+        let (skia_surface, _) = driver.resize(window.inner_size());
+
+        Ok((driver, window, skia_surface))
+    }
+
+    /// Copy the rendered surface into the Window framebuffer.
+    pub fn present(&mut self, surface: &mut SkiaSurface) {
+        let (width, height) = (surface.width(), surface.height());
+        let row_bytes = width as usize * 4;
+        self.pixels.resize(row_bytes * height as usize, 0);
+
+        let image_info = ImageInfo::new(
+            (width, height),
+            ColorType::RGBA8888,
+            AlphaType::Premul,
+            None,
+        );
+        if !surface.read_pixels(&image_info, &mut self.pixels, row_bytes, (0, 0)) {
+            return;
+        }
+
+        let Ok(mut buffer) = self.softbuffer_surface.buffer_mut() else {
+            return;
+        };
+
+        // Softbuffer expects the pixels as `0RGB` u32 values
+        for (pixel, rgba) in buffer.iter_mut().zip(self.pixels.chunks_exact(4)) {
+            *pixel = u32::from_be_bytes([0, rgba[0], rgba[1], rgba[2]]);
+        }
+
+        buffer.present().ok();
+    }
+
+    pub fn resize(&mut self, size: PhysicalSize<u32>) -> (SkiaSurface, SkiaSurface) {
+        self.softbuffer_surface
+            .resize(size.as_gl_width(), size.as_gl_height())
+            .expect("Could not resize the software framebuffer");
+
+        let mut surface = raster_n32_premul(size.to_skia()).expect("Could not create skia surface");
+
+        let dirty_surface = surface.new_surface_with_dimensions(size.to_skia()).unwrap();
+
+        (surface, dirty_surface)
+    }
+}
@@ -0,0 +1,103 @@
+use std::thread;
+
+use freya_core::prelude::{
+    EventMessage,
+    EventName,
+    PlatformEvent,
+};
+use freya_elements::events::{
+    GamepadAxis,
+    GamepadButton,
+    GamepadInput,
+};
+use gilrs::{
+    Axis,
+    Button,
+    EventType,
+    Gilrs,
+};
+use winit::event_loop::EventLoopProxy;
+
+/// Listen for gamepad events in a separate thread and forward them to the event loop,
+/// which sends them to the focused Window.
+pub(crate) fn listen_gamepads(proxy: EventLoopProxy<EventMessage>) {
+    thread::spawn(move || {
+        let mut gilrs = match Gilrs::new() {
+            Ok(gilrs) => gilrs,
+            Err(err) => {
+                tracing::error!("Failed to listen for gamepads: {err}");
+                return;
+            }
+        };
+
+        loop {
+            let Some(event) = gilrs.next_event_blocking(None) else {
+                continue;
+            };
+
+            let (name, input) = match event.event {
+                EventType::ButtonPressed(button, _) => (
+                    EventName::GamepadButtonDown,
+                    GamepadInput::Button(map_gilrs_button(button)),
+                ),
+                EventType::ButtonReleased(button, _) => (
+                    EventName::GamepadButtonUp,
+                    GamepadInput::Button(map_gilrs_button(button)),
+                ),
+                EventType::AxisChanged(axis, value, _) => (
+                    EventName::GamepadAxis,
+                    GamepadInput::Axis(map_gilrs_axis(axis), value),
+                ),
+                _ => continue,
+            };
+
+            let sent = proxy.send_event(EventMessage::PlatformEvent(PlatformEvent::Gamepad {
+                name,
+                gamepad_id: event.id.into(),
+                input,
+            }));
+
+            // The event loop was closed
+            if sent.is_err() {
+                break;
+            }
+        }
+    });
+}
+
+fn map_gilrs_button(button: Button) -> GamepadButton {
+    match button {
+        Button::South => GamepadButton::South,
+        Button::East => GamepadButton::East,
+        Button::North => GamepadButton::North,
+        Button::West => GamepadButton::West,
+        Button::LeftTrigger => GamepadButton::LeftTrigger,
+        Button::LeftTrigger2 => GamepadButton::LeftTrigger2,
+        Button::RightTrigger => GamepadButton::RightTrigger,
+        Button::RightTrigger2 => GamepadButton::RightTrigger2,
+        Button::Select => GamepadButton::Select,
+        Button::Start => GamepadButton::Start,
+        Button::Mode => GamepadButton::Mode,
+        Button::LeftThumb => GamepadButton::LeftThumb,
+        Button::RightThumb => GamepadButton::RightThumb,
+        Button::DPadUp => GamepadButton::DPadUp,
+        Button::DPadDown => GamepadButton::DPadDown,
+        Button::DPadLeft => GamepadButton::DPadLeft,
+        Button::DPadRight => GamepadButton::DPadRight,
+        _ => GamepadButton::Unknown,
+    }
+}
+
+fn map_gilrs_axis(axis: Axis) -> GamepadAxis {
+    match axis {
+        Axis::LeftStickX => GamepadAxis::LeftStickX,
+        Axis::LeftStickY => GamepadAxis::LeftStickY,
+        Axis::LeftZ => GamepadAxis::LeftZ,
+        Axis::RightStickX => GamepadAxis::RightStickX,
+        Axis::RightStickY => GamepadAxis::RightStickY,
+        Axis::RightZ => GamepadAxis::RightZ,
+        Axis::DPadX => GamepadAxis::DPadX,
+        Axis::DPadY => GamepadAxis::DPadY,
+        _ => GamepadAxis::Unknown,
+    }
+}
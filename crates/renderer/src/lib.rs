@@ -8,15 +8,25 @@ pub use config::{
     *,
 };
 use freya_native_core::NodeId;
-pub use renderer::DesktopRenderer;
+pub use renderer::{
+    DesktopRenderer,
+    VirtualDomBuilder,
+};
+#[cfg(feature = "tray")]
+pub use tray_icon;
 
 mod accessibility;
 mod app;
 mod config;
 pub mod devtools;
+mod double_click;
 mod drivers;
+#[cfg(feature = "gamepad")]
+mod gamepad;
 mod renderer;
 mod size;
+#[cfg(feature = "tray")]
+mod tray;
 mod window_state;
 mod winit_waker;
 
@@ -1,16 +1,28 @@
-use std::path::PathBuf;
+use std::collections::HashMap;
 
-use dioxus_core::VirtualDom;
+use dioxus_core::{
+    Element,
+    VirtualDom,
+};
 use freya_core::{
-    accessibility::AccessibilityFocusStrategy,
-    dom::SafeDOM,
+    accessibility::{
+        accessibility_action_from_request,
+        AccessibilityFocusStrategy,
+    },
+    dom::{
+        FreyaDOM,
+        SafeDOM,
+    },
     events::{
         EventName,
         PlatformEvent,
     },
+    fonts::FontsRegistry,
+    plugins::PluginsManager,
     prelude::{
         EventMessage,
         NavigationMode,
+        NewWindow,
     },
 };
 use freya_elements::events::{
@@ -20,7 +32,10 @@ use freya_elements::events::{
     Code,
     Key,
 };
-use torin::geometry::CursorPoint;
+use torin::geometry::{
+    CursorPoint,
+    Point2D,
+};
 use winit::{
     application::ApplicationHandler,
     event::{
@@ -35,10 +50,11 @@ use winit::{
         WindowEvent,
     },
     event_loop::{
+        ActiveEventLoop,
         EventLoop,
         EventLoopProxy,
     },
-    keyboard::ModifiersState,
+    window::WindowId,
 };
 
 use crate::{
@@ -54,15 +70,20 @@ use crate::{
 
 const WHEEL_SPEED_MODIFIER: f32 = 53.0;
 
+/// Create the VirtualDOM of a secondary Window given its root component.
+pub type VirtualDomBuilder = fn(fn() -> Element) -> VirtualDom;
+
 /// Desktop renderer using Skia, Glutin and Winit
 pub struct DesktopRenderer<'a, State: Clone + 'static> {
     pub(crate) event_loop_proxy: EventLoopProxy<EventMessage>,
     pub(crate) state: WindowState<'a, State>,
+    pub(crate) secondary_windows: HashMap<WindowId, CreatedState>,
     pub(crate) hovered_node: HoveredNode,
-    pub(crate) cursor_pos: CursorPoint,
-    pub(crate) mouse_state: ElementState,
-    pub(crate) modifiers_state: ModifiersState,
-    pub(crate) dropped_file_path: Option<PathBuf>,
+    pub(crate) vdom_builder: VirtualDomBuilder,
+    pub(crate) app_state: Option<State>,
+    pub(crate) fonts: FontsRegistry,
+    #[cfg(feature = "tray")]
+    pub(crate) tray: crate::tray::Tray,
 }
 
 impl<'a, State: Clone + 'static> DesktopRenderer<'a, State> {
@@ -73,6 +94,7 @@ impl<'a, State: Clone + 'static> DesktopRenderer<'a, State> {
         config: LaunchConfig<State>,
         devtools: Option<Devtools>,
         hovered_node: HoveredNode,
+        vdom_builder: VirtualDomBuilder,
     ) {
         let event_loop = EventLoop::<EventMessage>::with_user_event()
             .build()
@@ -93,8 +115,21 @@ impl<'a, State: Clone + 'static> DesktopRenderer<'a, State> {
             });
         }
 
-        let mut desktop_renderer =
-            DesktopRenderer::new(vdom, sdom, config, devtools, hovered_node, proxy);
+        #[cfg(feature = "gamepad")]
+        crate::gamepad::listen_gamepads(proxy.clone());
+
+        #[cfg(feature = "tray")]
+        crate::tray::listen_tray_events(proxy.clone());
+
+        let mut desktop_renderer = DesktopRenderer::new(
+            vdom,
+            sdom,
+            config,
+            devtools,
+            hovered_node,
+            proxy,
+            vdom_builder,
+        );
 
         event_loop.run_app(&mut desktop_renderer).unwrap();
     }
@@ -106,62 +141,147 @@ impl<'a, State: Clone + 'static> DesktopRenderer<'a, State> {
         devtools: Option<Devtools>,
         hovered_node: HoveredNode,
         proxy: EventLoopProxy<EventMessage>,
+        vdom_builder: VirtualDomBuilder,
     ) -> Self {
+        #[cfg(feature = "tray")]
+        let (config, tray) = {
+            let mut config = config;
+            let tray = crate::tray::Tray::new(
+                config.tray_icon.take(),
+                config.tray_menu.take(),
+                config.menu_bar.take(),
+            );
+            (config, tray)
+        };
+
         DesktopRenderer {
+            #[cfg(feature = "tray")]
+            tray,
+            app_state: config.state.clone(),
+            fonts: FontsRegistry::new(
+                config.embedded_fonts.iter().copied(),
+                config.default_fonts.clone(),
+                config.fallback_fonts.clone(),
+            ),
             state: WindowState::NotCreated(NotCreatedState {
                 sdom,
                 devtools,
                 vdom,
                 config,
             }),
+            secondary_windows: HashMap::new(),
             hovered_node,
             event_loop_proxy: proxy,
-            cursor_pos: CursorPoint::default(),
-            mouse_state: ElementState::Released,
-            modifiers_state: ModifiersState::default(),
-            dropped_file_path: None,
+            vdom_builder,
         }
     }
 
-    // Send and process an event
-    fn send_event(&mut self, event: PlatformEvent) {
-        let scale_factor = self.scale_factor();
-        self.state
-            .created_state()
-            .app
-            .send_event(event, scale_factor);
+    /// Check if the given Window is the main one
+    fn is_main_window(&self, window_id: WindowId) -> bool {
+        matches!(&self.state, WindowState::Created(CreatedState { window, .. }) if window.id() == window_id)
     }
 
-    /// Get the current scale factor of the Window
-    fn scale_factor(&self) -> f64 {
-        match &self.state {
-            WindowState::Created(CreatedState { window, .. }) => window.scale_factor(),
-            _ => 0.0,
+    /// Get the ID of the focused Window, if any
+    fn focused_window_id(&self) -> Option<WindowId> {
+        let main_window = match &self.state {
+            WindowState::Created(created_state) => Some(created_state),
+            _ => None,
+        };
+        main_window
+            .into_iter()
+            .chain(self.secondary_windows.values())
+            .find(|created_state| created_state.is_window_focused)
+            .map(|created_state| created_state.window.id())
+    }
+
+    /// Open a secondary Window with its own VirtualDOM
+    fn open_window(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        NewWindow {
+            app,
+            window_config,
+            contexts,
+        }: NewWindow,
+    ) {
+        let mut vdom = (self.vdom_builder)(app);
+        for context in contexts {
+            vdom.insert_any_root_context(context);
+        }
+
+        let mut created_state = CreatedState::new(
+            event_loop,
+            &self.event_loop_proxy,
+            SafeDOM::new(FreyaDOM::default()),
+            vdom,
+            None,
+            window_config,
+            PluginsManager::default(),
+            self.fonts.clone(),
+            self.app_state.clone(),
+        );
+        created_state.run_on_setup();
+
+        let window_id = created_state.window.id();
+        self.secondary_windows.insert(window_id, created_state);
+
+        self.event_loop_proxy
+            .send_event(EventMessage::ToWindow(
+                window_id,
+                Box::new(EventMessage::PollVDOM),
+            ))
+            .ok();
+    }
+
+    /// Close a secondary Window, or the whole app if it's the main Window
+    fn close_window(&mut self, event_loop: &ActiveEventLoop, window_id: Option<WindowId>) {
+        match window_id {
+            Some(window_id) if !self.is_main_window(window_id) => {
+                if let Some(mut created_state) = self.secondary_windows.remove(&window_id) {
+                    created_state.run_on_exit();
+                }
+            }
+            _ => event_loop.exit(),
         }
     }
 
     /// Run the `on_setup` callback that was passed to the launch function
     pub fn run_on_setup(&mut self) {
-        let state = self.state.created_state();
-        if let Some(on_setup) = &state.window_config.on_setup {
-            (on_setup)(&mut state.window)
-        }
+        self.state.created_state().run_on_setup();
     }
 
     /// Run the `on_exit` callback that was passed to the launch function
     pub fn run_on_exit(&mut self) {
-        let state = self.state.created_state();
-        if let Some(on_exit) = &state.window_config.on_exit {
-            (on_exit)(&mut state.window)
+        self.state.created_state().run_on_exit();
+    }
+}
+
+/// Get a Window given its ID, or the main Window if there is no ID.
+fn get_window<'b, State: Clone + 'static>(
+    state: &'b mut WindowState<'_, State>,
+    secondary_windows: &'b mut HashMap<WindowId, CreatedState>,
+    window_id: Option<WindowId>,
+) -> Option<&'b mut CreatedState> {
+    let WindowState::Created(main_window) = state else {
+        return None;
+    };
+    match window_id {
+        Some(window_id) if window_id != main_window.window.id() => {
+            secondary_windows.get_mut(&window_id)
         }
+        _ => Some(main_window),
     }
 }
 
 impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a, State> {
     fn resumed(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
         if !self.state.has_been_created() {
-            self.state.create(event_loop, &self.event_loop_proxy);
+            self.state
+                .create(event_loop, &self.event_loop_proxy, self.fonts.clone());
             self.run_on_setup();
+
+            #[cfg(feature = "tray")]
+            self.tray.create(&self.state.created_state().window);
         }
     }
 
@@ -178,8 +298,69 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
     }
 
     fn user_event(&mut self, event_loop: &winit::event_loop::ActiveEventLoop, event: EventMessage) {
-        let scale_factor = self.scale_factor();
-        let CreatedState { window, app, .. } = self.state.created_state();
+        // Messages without a Window go to the main one
+        let (window_id, event) = match event {
+            EventMessage::ToWindow(window_id, event) => (Some(window_id), *event),
+            event => (None, event),
+        };
+
+        // Gamepads are not tied to a Window, so their events go to the focused one
+        let window_id = match &event {
+            EventMessage::PlatformEvent(PlatformEvent::Gamepad { .. }) if window_id.is_none() => {
+                self.focused_window_id()
+            }
+            _ => window_id,
+        };
+
+        let event = match event {
+            EventMessage::NewWindow(new_window) => {
+                self.open_window(event_loop, new_window);
+                return;
+            }
+            EventMessage::ExitApp => {
+                self.close_window(event_loop, window_id);
+                return;
+            }
+            EventMessage::MenuEvent(menu_event) => {
+                let main_window = match &self.state {
+                    WindowState::Created(created_state) => Some(created_state),
+                    _ => None,
+                };
+                for CreatedState { app, .. } in main_window
+                    .into_iter()
+                    .chain(self.secondary_windows.values())
+                {
+                    app.menu_sender.send(menu_event.clone()).ok();
+                }
+                return;
+            }
+            EventMessage::UpdateFonts(operation) => {
+                self.fonts.apply(&operation);
+                if let WindowState::Created(CreatedState { window, app, .. }) = &mut self.state {
+                    app.update_fonts(&operation, window);
+                }
+                for CreatedState { window, app, .. } in self.secondary_windows.values_mut() {
+                    app.update_fonts(&operation, window);
+                }
+                return;
+            }
+            EventMessage::TrayIconClicked => {
+                if let WindowState::Created(CreatedState { window, .. }) = &self.state {
+                    window.set_visible(true);
+                    window.set_minimized(false);
+                    window.focus_window();
+                }
+                return;
+            }
+            event => event,
+        };
+
+        let Some(CreatedState { window, app, .. }) =
+            get_window(&mut self.state, &mut self.secondary_windows, window_id)
+        else {
+            return;
+        };
+        let scale_factor = window.scale_factor();
         match event {
             EventMessage::FocusAccessibilityNode(id) => {
                 app.focus_node(id, window);
@@ -202,6 +383,15 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
             EventMessage::Accessibility(accesskit_winit::WindowEvent::ActionRequested(request)) => {
                 if accesskit::Action::Focus == request.action {
                     app.focus_node(request.target, window);
+                } else if let Some(action) = accessibility_action_from_request(&request) {
+                    app.send_event(
+                        PlatformEvent::Accessibility {
+                            name: EventName::A11yAction,
+                            target: request.target,
+                            action,
+                        },
+                        scale_factor,
+                    );
                 }
             }
             EventMessage::Accessibility(accesskit_winit::WindowEvent::InitialTreeRequested) => {
@@ -216,9 +406,14 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                 app.set_navigation_mode(NavigationMode::Keyboard);
                 app.focus_next_node(AccessibilityFocusStrategy::Forward, window);
             }
+            EventMessage::FocusAccessibilityNodeWithStrategy(strategy) => {
+                app.set_navigation_mode(NavigationMode::Keyboard);
+                app.focus_next_node(strategy, window);
+            }
             EventMessage::WithWindow(use_window) => (use_window)(window),
-            EventMessage::ExitApp => event_loop.exit(),
-            EventMessage::PlatformEvent(platform_event) => self.send_event(platform_event),
+            EventMessage::PlatformEvent(platform_event) => {
+                app.send_event(platform_event, scale_factor)
+            }
             ev => {
                 if let EventMessage::UpdateTemplate(template) = ev {
                     app.vdom_replace_template(template);
@@ -236,10 +431,23 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
     fn window_event(
         &mut self,
         event_loop: &winit::event_loop::ActiveEventLoop,
-        _window_id: winit::window::WindowId,
+        window_id: winit::window::WindowId,
         event: winit::event::WindowEvent,
     ) {
-        let scale_factor = self.scale_factor();
+        // The devtools only inspect the main Window
+        let hovered_node = if self.is_main_window(window_id) {
+            self.hovered_node.clone()
+        } else {
+            None
+        };
+        let Some(created_state) = get_window(
+            &mut self.state,
+            &mut self.secondary_windows,
+            Some(window_id),
+        ) else {
+            return;
+        };
+        let scale_factor = created_state.window.scale_factor();
         let CreatedState {
             surface,
             dirty_surface,
@@ -249,7 +457,7 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
             is_window_focused,
             graphics_driver,
             ..
-        } = self.state.created_state();
+        } = &mut *created_state;
         app.accessibility
             .process_accessibility_event(&event, window);
         match event {
@@ -258,13 +466,22 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                     state.preferred_theme = theme.into();
                 });
             }
-            WindowEvent::CloseRequested => event_loop.exit(),
+            WindowEvent::CloseRequested => {
+                if window_config.hide_on_close {
+                    window.set_visible(false);
+                } else if app.close_requests.is_intercepted() {
+                    // The app decides when to close the Window
+                    app.close_requests.request();
+                } else {
+                    self.close_window(event_loop, Some(window_id));
+                }
+            }
             WindowEvent::Ime(Ime::Commit(text)) => {
-                self.send_event(PlatformEvent::Keyboard {
+                created_state.send_event(PlatformEvent::Keyboard {
                     name: EventName::KeyDown,
                     key: Key::Character(text),
                     code: Code::Unidentified,
-                    modifiers: map_winit_modifiers(self.modifiers_state),
+                    modifiers: map_winit_modifiers(created_state.modifiers_state),
                 });
             }
             WindowEvent::RedrawRequested => {
@@ -289,7 +506,7 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                 graphics_driver.make_current();
 
                 app.render(
-                    &self.hovered_node,
+                    &hovered_node,
                     window_config.background,
                     surface,
                     dirty_surface,
@@ -298,12 +515,12 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
 
                 app.event_loop_tick();
                 window.pre_present_notify();
-                graphics_driver.flush_and_submit();
+                graphics_driver.flush_and_submit(surface);
             }
             WindowEvent::MouseInput { state, button, .. } => {
                 app.set_navigation_mode(NavigationMode::NotKeyboard);
 
-                self.mouse_state = state;
+                created_state.mouse_state = state;
 
                 let name = match state {
                     ElementState::Pressed => EventName::MouseDown,
@@ -315,11 +532,15 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                     },
                 };
 
-                self.send_event(PlatformEvent::Mouse {
+                created_state.send_event(PlatformEvent::Mouse {
                     name,
-                    cursor: self.cursor_pos,
+                    cursor: created_state.cursor_pos,
                     button: Some(button),
                 });
+
+                if state == ElementState::Pressed {
+                    created_state.schedule_long_press(Some(button));
+                }
             }
             WindowEvent::MouseWheel { delta, phase, .. } => {
                 if TouchPhase::Moved == phase {
@@ -333,15 +554,31 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                         }
                     };
 
-                    self.send_event(PlatformEvent::Wheel {
+                    created_state.send_event(PlatformEvent::Wheel {
                         name: EventName::Wheel,
                         scroll: CursorPoint::from(scroll_data),
-                        cursor: self.cursor_pos,
+                        cursor: created_state.cursor_pos,
                     });
                 }
             }
+            WindowEvent::PinchGesture { delta, phase, .. } => {
+                created_state.send_event(PlatformEvent::Pinch {
+                    name: EventName::Pinch,
+                    cursor: created_state.cursor_pos,
+                    delta,
+                    phase,
+                });
+            }
+            WindowEvent::RotationGesture { delta, phase, .. } => {
+                created_state.send_event(PlatformEvent::Rotation {
+                    name: EventName::Rotation,
+                    cursor: created_state.cursor_pos,
+                    delta,
+                    phase,
+                });
+            }
             WindowEvent::ModifiersChanged(modifiers) => {
-                self.modifiers_state = modifiers.state();
+                created_state.modifiers_state = modifiers.state();
             }
             WindowEvent::KeyboardInput {
                 event:
@@ -361,38 +598,47 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                     ElementState::Pressed => EventName::KeyDown,
                     ElementState::Released => EventName::KeyUp,
                 };
-                self.send_event(PlatformEvent::Keyboard {
+                created_state.send_event(PlatformEvent::Keyboard {
                     name,
                     key: map_winit_key(&logical_key),
                     code: map_winit_physical_key(&physical_key),
-                    modifiers: map_winit_modifiers(self.modifiers_state),
+                    modifiers: map_winit_modifiers(created_state.modifiers_state),
                 })
             }
             WindowEvent::CursorLeft { .. } => {
-                if self.mouse_state == ElementState::Released {
-                    self.cursor_pos = CursorPoint::new(-1.0, -1.0);
+                if created_state.mouse_state == ElementState::Released {
+                    created_state.cursor_pos = CursorPoint::new(-1.0, -1.0);
 
-                    self.send_event(PlatformEvent::Mouse {
+                    created_state.send_event(PlatformEvent::Mouse {
                         name: EventName::MouseMove,
-                        cursor: self.cursor_pos,
+                        cursor: created_state.cursor_pos,
                         button: None,
                     });
                 }
             }
             WindowEvent::CursorMoved { position, .. } => {
-                self.cursor_pos = CursorPoint::from((position.x, position.y));
+                created_state.cursor_pos = CursorPoint::from((position.x, position.y));
 
-                self.send_event(PlatformEvent::Mouse {
+                created_state.send_event(PlatformEvent::Mouse {
                     name: EventName::MouseMove,
-                    cursor: self.cursor_pos,
+                    cursor: created_state.cursor_pos,
                     button: None,
                 });
 
-                if let Some(dropped_file_path) = self.dropped_file_path.take() {
-                    self.send_event(PlatformEvent::File {
+                // The files are dropped once the cursor position is known,
+                // all the files dropped at once go in the same event
+                if !created_state.dropped_file_paths.is_empty() {
+                    let file_paths = std::mem::take(&mut created_state.dropped_file_paths);
+                    created_state.send_event(PlatformEvent::File {
                         name: EventName::FileDrop,
-                        file_path: Some(dropped_file_path),
-                        cursor: self.cursor_pos,
+                        file_paths,
+                        cursor: created_state.cursor_pos,
+                    });
+                } else if !created_state.hovered_file_paths.is_empty() {
+                    created_state.send_event(PlatformEvent::File {
+                        name: EventName::GlobalFileHover,
+                        file_paths: created_state.hovered_file_paths.clone(),
+                        cursor: created_state.cursor_pos,
                     });
                 }
             }
@@ -403,7 +649,7 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                 force,
                 ..
             }) => {
-                self.cursor_pos = CursorPoint::from((location.x, location.y));
+                created_state.cursor_pos = CursorPoint::from((location.x, location.y));
 
                 let name = match phase {
                     TouchPhase::Cancelled => EventName::TouchCancel,
@@ -412,15 +658,22 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                     TouchPhase::Started => EventName::TouchStart,
                 };
 
-                self.send_event(PlatformEvent::Touch {
+                created_state.send_event(PlatformEvent::Touch {
                     name,
-                    location: self.cursor_pos,
+                    location: created_state.cursor_pos,
                     finger_id: id,
                     phase,
                     force,
                 });
+
+                if phase == TouchPhase::Started {
+                    created_state.schedule_long_press(None);
+                }
             }
             WindowEvent::Resized(size) => {
+                // Every Window has its own graphics context
+                graphics_driver.make_current();
+
                 let (new_surface, new_dirty_surface) = graphics_driver.resize(size);
 
                 *surface = new_surface;
@@ -429,32 +682,61 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                 window.request_redraw();
 
                 app.resize(window);
+
+                created_state.update_normal_geometry();
             }
             WindowEvent::DroppedFile(file_path) => {
-                self.dropped_file_path = Some(file_path);
+                created_state.hovered_file_paths.clear();
+                created_state.dropped_file_paths.push(file_path);
             }
             WindowEvent::HoveredFile(file_path) => {
-                self.send_event(PlatformEvent::File {
+                // winit emits an event for every file
+                created_state.hovered_file_paths.push(file_path);
+                created_state.send_event(PlatformEvent::File {
                     name: EventName::GlobalFileHover,
-                    file_path: Some(file_path),
-                    cursor: self.cursor_pos,
+                    file_paths: created_state.hovered_file_paths.clone(),
+                    cursor: created_state.cursor_pos,
                 });
             }
             WindowEvent::HoveredFileCancelled => {
-                self.send_event(PlatformEvent::File {
+                created_state.hovered_file_paths.clear();
+                created_state.send_event(PlatformEvent::File {
                     name: EventName::GlobalFileHoverCancelled,
-                    file_path: None,
-                    cursor: self.cursor_pos,
+                    file_paths: Vec::new(),
+                    cursor: created_state.cursor_pos,
                 });
             }
             WindowEvent::Focused(is_focused) => {
                 *is_window_focused = is_focused;
+                app.platform_sender.send_modify(|state| {
+                    state.information.is_focused = is_focused;
+                });
+            }
+            WindowEvent::Moved(position) => {
+                app.platform_sender.send_modify(|state| {
+                    state.information.window_position =
+                        Some(Point2D::new(position.x as f32, position.y as f32));
+                });
+
+                created_state.update_normal_geometry();
+            }
+            WindowEvent::Occluded(is_occluded) => {
+                app.platform_sender.send_modify(|state| {
+                    state.information.is_occluded = is_occluded;
+                });
             }
             _ => {}
         }
     }
 
     fn exiting(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {
+        for window in self.secondary_windows.values_mut() {
+            window.run_on_exit();
+        }
         self.run_on_exit();
+
+        // Drop the VirtualDOMs while the event loop is still alive so the components can clean up
+        self.secondary_windows.clear();
+        self.state = WindowState::Exited;
     }
 }
@@ -0,0 +1,181 @@
+use freya_core::prelude::{
+    EventMessage,
+    MenuEvent,
+};
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+use tray_icon::menu::Menu;
+use tray_icon::{
+    menu::MenuEvent as NativeMenuEvent,
+    MouseButton,
+    MouseButtonState,
+    TrayIconEvent,
+};
+use winit::{
+    event_loop::EventLoopProxy,
+    window::Window,
+};
+
+use crate::config::{
+    MenuBarBuilder,
+    TrayIconBuilder,
+    TrayMenuBuilder,
+};
+
+/// ID of the menu item added to the tray icon menu in Linux to show the main Window.
+const SHOW_MENU_ITEM_ID: &str = "freya-tray-show";
+
+/// Tray icon and menu bar of the app, they are removed once dropped.
+pub(crate) struct Tray {
+    tray_icon_builder: Option<TrayIconBuilder>,
+    tray_menu_builder: Option<TrayMenuBuilder>,
+    menu_bar_builder: Option<MenuBarBuilder>,
+    // In Linux the tray icon lives in its own thread
+    #[cfg(not(target_os = "linux"))]
+    _tray_icon: Option<tray_icon::TrayIcon>,
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    _menu_bar: Option<Menu>,
+}
+
+impl Tray {
+    pub(crate) fn new(
+        tray_icon_builder: Option<TrayIconBuilder>,
+        tray_menu_builder: Option<TrayMenuBuilder>,
+        menu_bar_builder: Option<MenuBarBuilder>,
+    ) -> Self {
+        Self {
+            tray_icon_builder,
+            tray_menu_builder,
+            menu_bar_builder,
+            #[cfg(not(target_os = "linux"))]
+            _tray_icon: None,
+            #[cfg(any(target_os = "macos", target_os = "windows"))]
+            _menu_bar: None,
+        }
+    }
+
+    /// Create the tray icon and the menu bar once the main Window exists.
+    pub(crate) fn create(&mut self, window: &Window) {
+        if let Some(tray_icon_builder) = self.tray_icon_builder.take() {
+            let tray_menu_builder = self.tray_menu_builder.take();
+
+            #[cfg(target_os = "linux")]
+            spawn_tray_icon(tray_icon_builder, tray_menu_builder);
+
+            #[cfg(not(target_os = "linux"))]
+            {
+                self._tray_icon = create_tray_icon(tray_icon_builder, tray_menu_builder);
+            }
+        }
+
+        if let Some(menu_bar_builder) = self.menu_bar_builder.take() {
+            #[cfg(any(target_os = "macos", target_os = "windows"))]
+            {
+                self._menu_bar = Some(create_menu_bar(menu_bar_builder, window));
+            }
+
+            #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+            create_menu_bar(menu_bar_builder, window);
+        }
+    }
+}
+
+/// Forward the activations of the menu items and the clicks on the tray icon to the event loop.
+pub(crate) fn listen_tray_events(proxy: EventLoopProxy<EventMessage>) {
+    let menu_proxy = proxy.clone();
+    NativeMenuEvent::set_event_handler(Some(move |event: NativeMenuEvent| {
+        let event = if event.id.0 == SHOW_MENU_ITEM_ID {
+            EventMessage::TrayIconClicked
+        } else {
+            EventMessage::MenuEvent(MenuEvent { id: event.id.0 })
+        };
+        menu_proxy.send_event(event).ok();
+    }));
+
+    TrayIconEvent::set_event_handler(Some(move |event: TrayIconEvent| {
+        if let TrayIconEvent::Click {
+            button: MouseButton::Left,
+            button_state: MouseButtonState::Up,
+            ..
+        } = event
+        {
+            proxy.send_event(EventMessage::TrayIconClicked).ok();
+        }
+    }));
+}
+
+#[cfg(target_os = "linux")]
+fn spawn_tray_icon(tray_icon_builder: TrayIconBuilder, tray_menu_builder: Option<TrayMenuBuilder>) {
+    use tray_icon::menu::{
+        Menu,
+        MenuItem,
+        PredefinedMenuItem,
+    };
+
+    // The tray icon needs a GTK event loop running in the thread where it was created
+    std::thread::spawn(move || {
+        if let Err(err) = gtk::init() {
+            tracing::error!("Failed to create the tray icon: {err}");
+            return;
+        }
+
+        // The tray icon can't be clicked in Linux, so its menu has an item to show the main Window instead
+        let menu = tray_menu_builder
+            .map(|tray_menu_builder| (tray_menu_builder)())
+            .unwrap_or_else(Menu::new);
+        if !menu.items().is_empty() {
+            menu.append(&PredefinedMenuItem::separator()).ok();
+        }
+        menu.append(&MenuItem::with_id(SHOW_MENU_ITEM_ID, "Show", true, None))
+            .ok();
+
+        match (tray_icon_builder)().with_menu(Box::new(menu)).build() {
+            Ok(_tray_icon) => gtk::main(),
+            Err(err) => tracing::error!("Failed to create the tray icon: {err}"),
+        }
+    });
+}
+
+#[cfg(not(target_os = "linux"))]
+fn create_tray_icon(
+    tray_icon_builder: TrayIconBuilder,
+    tray_menu_builder: Option<TrayMenuBuilder>,
+) -> Option<tray_icon::TrayIcon> {
+    let mut tray_icon_builder = (tray_icon_builder)();
+    if let Some(tray_menu_builder) = tray_menu_builder {
+        tray_icon_builder = tray_icon_builder.with_menu(Box::new((tray_menu_builder)()));
+    }
+    tray_icon_builder
+        .build()
+        .map_err(|err| tracing::error!("Failed to create the tray icon: {err}"))
+        .ok()
+}
+
+#[cfg(target_os = "macos")]
+fn create_menu_bar(menu_bar_builder: MenuBarBuilder, _window: &Window) -> Menu {
+    let menu_bar = (menu_bar_builder)();
+    menu_bar.init_for_nsapp();
+    menu_bar
+}
+
+#[cfg(target_os = "windows")]
+fn create_menu_bar(menu_bar_builder: MenuBarBuilder, window: &Window) -> Menu {
+    use raw_window_handle::{
+        HasWindowHandle,
+        RawWindowHandle,
+    };
+
+    let menu_bar = (menu_bar_builder)();
+    if let Ok(RawWindowHandle::Win32(handle)) = window.window_handle().map(|handle| handle.as_raw())
+    {
+        // The menu bar lives as long as the app
+        if let Err(err) = unsafe { menu_bar.init_for_hwnd(handle.hwnd.get()) } {
+            tracing::error!("Failed to set the menu bar: {err}");
+        }
+    }
+    menu_bar
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn create_menu_bar(_menu_bar_builder: MenuBarBuilder, _window: &Window) {
+    tracing::warn!("The menu bar is not supported in this platform");
+}
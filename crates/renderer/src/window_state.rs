@@ -1,24 +1,55 @@
-use std::mem;
+use std::{
+    mem,
+    path::PathBuf,
+};
 
 use dioxus_core::VirtualDom;
 use freya_core::{
     dom::SafeDOM,
-    prelude::EventMessage,
+    events::{
+        EventName,
+        PlatformEvent,
+        DOUBLE_CLICK_DURATION,
+        LONG_PRESS_DURATION,
+    },
+    fonts::FontsRegistry,
+    plugins::PluginsManager,
+    prelude::{
+        EventMessage,
+        MonitorInfo,
+    },
+    window_config::WindowGeometry,
 };
 use freya_engine::prelude::*;
+use torin::geometry::{
+    CursorPoint,
+    Point2D,
+};
 use winit::{
-    dpi::LogicalSize,
+    dpi::{
+        LogicalPosition,
+        LogicalSize,
+    },
+    event::{
+        ElementState,
+        MouseButton,
+    },
     event_loop::{
         ActiveEventLoop,
         EventLoopProxy,
     },
-    window::Window,
+    keyboard::ModifiersState,
+    window::{
+        Window,
+        WindowLevel,
+    },
 };
 
 use crate::{
     app::Application,
     config::WindowConfig,
     devtools::Devtools,
+    double_click::system_double_click_duration,
     drivers::GraphicsDriver,
     size::WinitSize,
     LaunchConfig,
@@ -39,67 +70,81 @@ pub struct CreatedState {
     pub(crate) window: Window,
     pub(crate) window_config: WindowConfig,
     pub(crate) is_window_focused: bool,
+    pub(crate) cursor_pos: CursorPoint,
+    pub(crate) mouse_state: ElementState,
+    pub(crate) modifiers_state: ModifiersState,
+    pub(crate) dropped_file_paths: Vec<PathBuf>,
+    pub(crate) hovered_file_paths: Vec<PathBuf>,
+    /// Geometry of the Window the last time it was neither minimized nor maximized.
+    pub(crate) normal_geometry: Option<WindowGeometry>,
 }
 
-pub enum WindowState<'a, State: Clone + 'static> {
-    NotCreated(NotCreatedState<'a, State>),
-    Creating,
-    Created(CreatedState),
-}
-
-impl<'a, State: Clone + 'a> WindowState<'a, State> {
-    pub fn created_state(&mut self) -> &mut CreatedState {
-        let Self::Created(created) = self else {
-            panic!("Unexpected.")
-        };
-        created
-    }
-
-    pub fn has_been_created(&self) -> bool {
-        matches!(self, Self::Created(..))
-    }
-
-    pub fn create(
-        &mut self,
+impl CreatedState {
+    /// Create a Window and the Application that renders the given VirtualDOM in it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<State: Clone + 'static>(
         event_loop: &ActiveEventLoop,
         event_loop_proxy: &EventLoopProxy<EventMessage>,
-    ) {
-        let Self::NotCreated(NotCreatedState {
-            sdom,
-            vdom,
-            devtools,
-            mut config,
-        }) = mem::replace(self, WindowState::Creating)
-        else {
-            panic!("Unexpected.")
-        };
-
+        sdom: SafeDOM,
+        vdom: VirtualDom,
+        devtools: Option<Devtools>,
+        mut window_config: WindowConfig,
+        plugins: PluginsManager,
+        fonts: FontsRegistry,
+        app_state: Option<State>,
+    ) -> Self {
         let mut window_attributes = Window::default_attributes()
             .with_visible(false)
-            .with_title(config.window_config.title)
-            .with_decorations(config.window_config.decorations)
-            .with_transparent(config.window_config.transparent)
-            .with_window_icon(config.window_config.icon.take())
-            .with_inner_size(LogicalSize::<f64>::from(config.window_config.size));
+            .with_title(window_config.title)
+            .with_decorations(window_config.decorations)
+            .with_transparent(window_config.transparent)
+            .with_window_icon(window_config.icon.take())
+            .with_inner_size(LogicalSize::<f64>::from(window_config.size))
+            .with_resizable(window_config.resizable);
+
+        if window_config.always_on_top {
+            window_attributes = window_attributes.with_window_level(WindowLevel::AlwaysOnTop);
+        }
+        if let Some(position) = window_config.position {
+            window_attributes =
+                window_attributes.with_position(LogicalPosition::<f64>::from(position));
+        }
+        let persisted_geometry = window_config
+            .persisted_geometry
+            .as_ref()
+            .and_then(WindowGeometry::load);
+        if let Some(geometry) = persisted_geometry {
+            // Only restore the position if the monitor where it was is still connected
+            let position = Point2D::new(geometry.position.x as f32, geometry.position.y as f32);
+            let is_visible = event_loop
+                .available_monitors()
+                .any(|monitor| MonitorInfo::from_winit(&monitor, None).contains(position));
+            if is_visible {
+                window_attributes = window_attributes.with_position(geometry.position);
+            }
+            window_attributes = window_attributes
+                .with_inner_size(geometry.size)
+                .with_maximized(geometry.is_maximized);
+        }
 
         set_resource_cache_total_bytes_limit(1000000); // 1MB
         set_resource_cache_single_allocation_byte_limit(Some(500000)); // 0.5MB
 
-        if let Some(min_size) = config.window_config.min_size {
+        if let Some(min_size) = window_config.min_size {
             window_attributes =
                 window_attributes.with_min_inner_size(LogicalSize::<f64>::from(min_size));
         }
-        if let Some(max_size) = config.window_config.max_size {
+        if let Some(max_size) = window_config.max_size {
             window_attributes =
                 window_attributes.with_max_inner_size(LogicalSize::<f64>::from(max_size));
         }
 
-        if let Some(with_window_attributes) = &config.window_config.window_attributes_hook {
+        if let Some(with_window_attributes) = &window_config.window_attributes_hook {
             window_attributes = (with_window_attributes)(window_attributes);
         }
 
         let (graphics_driver, window, mut surface) =
-            GraphicsDriver::new(event_loop, window_attributes, &config);
+            GraphicsDriver::new(event_loop, window_attributes, &window_config);
 
         // Allow IME
         window.set_ime_allowed(true);
@@ -116,14 +161,12 @@ impl<'a, State: Clone + 'a> WindowState<'a, State> {
         surface
             .canvas()
             .scale((scale_factor as f32, scale_factor as f32));
-        surface.canvas().clear(config.window_config.background);
+        surface.canvas().clear(window_config.background);
 
         dirty_surface
             .canvas()
             .scale((scale_factor as f32, scale_factor as f32));
-        dirty_surface
-            .canvas()
-            .clear(config.window_config.background);
+        dirty_surface.canvas().clear(window_config.background);
 
         let mut app = Application::new(
             sdom,
@@ -131,22 +174,147 @@ impl<'a, State: Clone + 'a> WindowState<'a, State> {
             event_loop_proxy,
             devtools,
             &window,
-            config.embedded_fonts,
-            config.plugins,
-            config.default_fonts,
+            plugins,
+            fonts,
         );
 
-        app.init_doms(scale_factor as f32, config.state.clone());
+        app.nodes_state.set_double_click_duration(
+            window_config
+                .double_click_duration
+                .or_else(system_double_click_duration)
+                .unwrap_or(DOUBLE_CLICK_DURATION),
+        );
+
+        app.init_doms(scale_factor as f32, app_state);
         app.process_layout(window.inner_size(), scale_factor);
 
-        *self = WindowState::Created(CreatedState {
+        CreatedState {
             surface,
             dirty_surface,
             graphics_driver,
             window,
             app,
-            window_config: config.window_config,
+            window_config,
             is_window_focused: false,
+            cursor_pos: CursorPoint::default(),
+            mouse_state: ElementState::Released,
+            modifiers_state: ModifiersState::default(),
+            dropped_file_paths: Vec::new(),
+            hovered_file_paths: Vec::new(),
+            // A Window restored as maximized keeps the normal geometry it had before
+            normal_geometry: WindowGeometry::from_winit(&window).or(persisted_geometry),
+        }
+    }
+
+    // Send and process an event
+    pub fn send_event(&mut self, event: PlatformEvent) {
+        let scale_factor = self.window.scale_factor();
+        self.app.send_event(event, scale_factor);
+    }
+
+    /// Send a `longpress` event after [`LONG_PRESS_DURATION`],
+    /// it will be discarded if the pointer is released or moved before that.
+    pub fn schedule_long_press(&self, button: Option<MouseButton>) {
+        let proxy = self.app.proxy.clone();
+        let window_id = self.window.id();
+        let cursor = self.cursor_pos;
+        tokio::spawn(async move {
+            tokio::time::sleep(LONG_PRESS_DURATION).await;
+            proxy
+                .send_event(EventMessage::ToWindow(
+                    window_id,
+                    Box::new(EventMessage::PlatformEvent(PlatformEvent::Mouse {
+                        name: EventName::LongPress,
+                        cursor,
+                        button,
+                    })),
+                ))
+                .ok();
         });
     }
+
+    /// Run the `on_setup` callback of the Window
+    pub fn run_on_setup(&mut self) {
+        if let Some(on_setup) = &self.window_config.on_setup {
+            (on_setup)(&mut self.window)
+        }
+    }
+
+    /// Remember the geometry of the Window if it is in its normal state, called when it is moved or resized.
+    pub fn update_normal_geometry(&mut self) {
+        if let Some(geometry) = WindowGeometry::from_winit(&self.window) {
+            self.normal_geometry = Some(geometry);
+        }
+    }
+
+    /// Run the `on_exit` callback of the Window and save its geometry if it is persisted.
+    ///
+    /// The position and size saved are the ones of its normal state, so it is restored
+    /// with them even if it is closed while maximized.
+    pub fn run_on_exit(&mut self) {
+        if let Some(path) = &self.window_config.persisted_geometry {
+            if let Some(normal_geometry) = self.normal_geometry {
+                let geometry = WindowGeometry {
+                    is_maximized: self.window.is_maximized(),
+                    ..normal_geometry
+                };
+                if let Err(err) = geometry.save(path) {
+                    tracing::warn!("Failed to save the Window geometry into {path:?}: {err}");
+                }
+            }
+        }
+        if let Some(on_exit) = &self.window_config.on_exit {
+            (on_exit)(&mut self.window)
+        }
+    }
+}
+
+pub enum WindowState<'a, State: Clone + 'static> {
+    NotCreated(NotCreatedState<'a, State>),
+    Creating,
+    Created(CreatedState),
+    /// The app exited and the Window was dropped.
+    Exited,
+}
+
+impl<'a, State: Clone + 'a> WindowState<'a, State> {
+    pub fn created_state(&mut self) -> &mut CreatedState {
+        let Self::Created(created) = self else {
+            panic!("Unexpected.")
+        };
+        created
+    }
+
+    pub fn has_been_created(&self) -> bool {
+        matches!(self, Self::Created(..))
+    }
+
+    pub fn create(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        event_loop_proxy: &EventLoopProxy<EventMessage>,
+        fonts: FontsRegistry,
+    ) {
+        let Self::NotCreated(NotCreatedState {
+            sdom,
+            vdom,
+            devtools,
+            config,
+        }) = mem::replace(self, WindowState::Creating)
+        else {
+            panic!("Unexpected.")
+        };
+
+        *self = WindowState::Created(CreatedState::new(
+            event_loop,
+            event_loop_proxy,
+            sdom,
+            vdom,
+            devtools,
+            config.window_config,
+            config.plugins,
+            fonts,
+            config.state,
+        ));
+    }
 }
@@ -5,17 +5,23 @@ use futures_task::{
     waker,
     ArcWake,
 };
-use winit::event_loop::EventLoopProxy;
+use winit::{
+    event_loop::EventLoopProxy,
+    window::WindowId,
+};
 
-/// Used to enqueue a new polling for the VirtualDOM once the current one has finished
-pub fn winit_waker(proxy: &EventLoopProxy<EventMessage>) -> std::task::Waker {
-    struct DomHandle(EventLoopProxy<EventMessage>);
+/// Used to enqueue a new polling for the VirtualDOM of a Window once the current one has finished
+pub fn winit_waker(proxy: &EventLoopProxy<EventMessage>, window_id: WindowId) -> std::task::Waker {
+    struct DomHandle(EventLoopProxy<EventMessage>, WindowId);
 
     impl ArcWake for DomHandle {
         fn wake_by_ref(arc_self: &Arc<Self>) {
-            _ = arc_self.0.send_event(EventMessage::PollVDOM);
+            _ = arc_self.0.send_event(EventMessage::ToWindow(
+                arc_self.1,
+                Box::new(EventMessage::PollVDOM),
+            ));
         }
     }
 
-    waker(Arc::new(DomHandle(proxy.clone())))
+    waker(Arc::new(DomHandle(proxy.clone(), window_id)))
 }
@@ -4,8 +4,10 @@ use std::sync::{
 };
 
 use accesskit::{
+    Live,
     NodeId as AccessibilityId,
     Role,
+    Toggled,
 };
 use freya_common::{
     AccessibilityDirtyNodes,
@@ -35,7 +37,7 @@ use crate::{
     ParseError,
 };
 
-#[derive(Clone, Debug, PartialEq, Eq, Default, Component)]
+#[derive(Clone, Debug, PartialEq, Default, Component)]
 pub struct AccessibilityNodeState {
     pub closest_accessibility_node_id: Option<NodeId>,
     pub descencent_accessibility_ids: Vec<AccessibilityId>,
@@ -46,6 +48,19 @@ pub struct AccessibilityNodeState {
     pub a11y_name: Option<String>,
     pub a11y_auto_focus: bool,
     pub a11y_focusable: Focusable,
+    pub a11y_focus_trap: bool,
+    pub a11y_live: Option<Live>,
+    pub a11y_checked: Option<Toggled>,
+    pub a11y_expanded: Option<bool>,
+    pub a11y_selected: Option<bool>,
+    pub a11y_disabled: bool,
+    pub a11y_numeric_value: Option<f64>,
+    pub a11y_min_numeric_value: Option<f64>,
+    pub a11y_max_numeric_value: Option<f64>,
+    pub a11y_numeric_value_step: Option<f64>,
+    pub a11y_labelled_by: Option<AccessibilityId>,
+    pub a11y_described_by: Option<AccessibilityId>,
+    pub a11y_keyboard_shortcut: Option<String>,
 }
 
 impl ParseAttribute for AccessibilityNodeState {
@@ -94,6 +109,85 @@ impl ParseAttribute for AccessibilityNodeState {
                     self.a11y_focusable = Focusable::parse(attr)?;
                 }
             }
+            AttributeName::A11YFocusTrap => {
+                if let OwnedAttributeValue::Text(attr) = attr.value {
+                    self.a11y_focus_trap = attr.parse().unwrap_or_default()
+                }
+            }
+            AttributeName::A11YLive => {
+                if let OwnedAttributeValue::Text(attr) = attr.value {
+                    self.a11y_live = Some(match attr.as_str() {
+                        "off" => Live::Off,
+                        "polite" => Live::Polite,
+                        "assertive" => Live::Assertive,
+                        _ => return Err(ParseError),
+                    })
+                }
+            }
+            AttributeName::A11YChecked => {
+                if let OwnedAttributeValue::Text(attr) = attr.value {
+                    self.a11y_checked = Some(match attr.as_str() {
+                        "true" => Toggled::True,
+                        "false" => Toggled::False,
+                        "mixed" => Toggled::Mixed,
+                        _ => return Err(ParseError),
+                    })
+                }
+            }
+            AttributeName::A11YExpanded => {
+                if let OwnedAttributeValue::Text(attr) = attr.value {
+                    self.a11y_expanded = Some(attr.parse().map_err(|_| ParseError)?)
+                }
+            }
+            AttributeName::A11YSelected => {
+                if let OwnedAttributeValue::Text(attr) = attr.value {
+                    self.a11y_selected = Some(attr.parse().map_err(|_| ParseError)?)
+                }
+            }
+            AttributeName::A11YDisabled => {
+                if let OwnedAttributeValue::Text(attr) = attr.value {
+                    self.a11y_disabled = attr.parse().unwrap_or_default()
+                }
+            }
+            AttributeName::A11YNumericValue => {
+                if let OwnedAttributeValue::Text(attr) = attr.value {
+                    self.a11y_numeric_value = Some(attr.parse().map_err(|_| ParseError)?)
+                }
+            }
+            AttributeName::A11YMinNumericValue => {
+                if let OwnedAttributeValue::Text(attr) = attr.value {
+                    self.a11y_min_numeric_value = Some(attr.parse().map_err(|_| ParseError)?)
+                }
+            }
+            AttributeName::A11YMaxNumericValue => {
+                if let OwnedAttributeValue::Text(attr) = attr.value {
+                    self.a11y_max_numeric_value = Some(attr.parse().map_err(|_| ParseError)?)
+                }
+            }
+            AttributeName::A11YNumericValueStep => {
+                if let OwnedAttributeValue::Text(attr) = attr.value {
+                    self.a11y_numeric_value_step = Some(attr.parse().map_err(|_| ParseError)?)
+                }
+            }
+            AttributeName::A11YLabelledBy => {
+                if let OwnedAttributeValue::Custom(CustomAttributeValues::AccessibilityId(id)) =
+                    attr.value
+                {
+                    self.a11y_labelled_by = Some(*id);
+                }
+            }
+            AttributeName::A11YDescribedBy => {
+                if let OwnedAttributeValue::Custom(CustomAttributeValues::AccessibilityId(id)) =
+                    attr.value
+                {
+                    self.a11y_described_by = Some(*id);
+                }
+            }
+            AttributeName::A11YKeyboardShortcut => {
+                if let OwnedAttributeValue::Text(attr) = attr.value {
+                    self.a11y_keyboard_shortcut = Some(attr.to_owned())
+                }
+            }
             _ => {}
         }
 
@@ -116,6 +210,19 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
             AttributeName::A11YAlt,
             AttributeName::A11YName,
             AttributeName::A11YAutoFocus,
+            AttributeName::A11YFocusTrap,
+            AttributeName::A11YLive,
+            AttributeName::A11YChecked,
+            AttributeName::A11YExpanded,
+            AttributeName::A11YSelected,
+            AttributeName::A11YDisabled,
+            AttributeName::A11YNumericValue,
+            AttributeName::A11YMinNumericValue,
+            AttributeName::A11YMaxNumericValue,
+            AttributeName::A11YNumericValueStep,
+            AttributeName::A11YLabelledBy,
+            AttributeName::A11YDescribedBy,
+            AttributeName::A11YKeyboardShortcut,
         ]));
 
     fn update<'a>(
@@ -15,6 +15,7 @@ use freya_native_core::{
         NodeMaskBuilder,
         State,
     },
+    tags::TagName,
     NodeId,
     SendAnyMap,
 };
@@ -22,8 +23,10 @@ use freya_native_core_macro::partial_derive_state;
 use torin::torin::Torin;
 
 use crate::{
+    BaselineShift,
     CustomAttributeValues,
     ExtSplit,
+    OpenTypeFeature,
     Parse,
     ParseAttribute,
     TextOverflow,
@@ -45,6 +48,10 @@ pub struct FontStyleState {
     pub text_align: TextAlign,
     pub max_lines: Option<usize>,
     pub text_overflow: TextOverflow,
+    /// Only used by `text` spans, it's not inherited.
+    pub background: Option<Color>,
+    pub baseline_shift: BaselineShift,
+    pub font_features: Vec<OpenTypeFeature>,
 }
 
 impl FontStyleState {
@@ -81,6 +88,19 @@ impl FontStyleState {
         text_style.set_decoration_type(self.decoration.ty);
         text_style.set_decoration_color(self.decoration.color);
 
+        if let Some(background) = self.background {
+            let mut paint = Paint::default();
+            paint.set_anti_alias(true);
+            paint.set_color(background);
+            text_style.set_background_paint(&paint);
+        }
+
+        text_style.set_baseline_shift(self.baseline_shift.offset(self.font_size) * scale_factor);
+
+        for feature in self.font_features.iter() {
+            text_style.add_font_feature(&feature.tag, feature.value);
+        }
+
         text_style
     }
 }
@@ -105,6 +125,9 @@ impl Default for FontStyleState {
             text_align: TextAlign::default(),
             max_lines: None,
             text_overflow: TextOverflow::default(),
+            background: None,
+            baseline_shift: BaselineShift::default(),
+            font_features: Vec::new(),
         }
     }
 }
@@ -237,6 +260,24 @@ impl ParseAttribute for FontStyleState {
                     }
                 }
             }
+            AttributeName::Background => {
+                if let Some(value) = attr.value.as_text() {
+                    self.background = Some(Color::parse(value)?);
+                }
+            }
+            AttributeName::BaselineShift => {
+                if let Some(value) = attr.value.as_text() {
+                    self.baseline_shift = BaselineShift::parse(value)?;
+                }
+            }
+            AttributeName::FontFeatures => {
+                if let Some(value) = attr.value.as_text() {
+                    self.font_features = value
+                        .split(',')
+                        .filter_map(|feature| OpenTypeFeature::parse(feature.trim()).ok())
+                        .collect();
+                }
+            }
             _ => {}
         }
 
@@ -252,8 +293,8 @@ impl State<CustomAttributeValues> for FontStyleState {
 
     type NodeDependencies = ();
 
-    const NODE_MASK: NodeMaskBuilder<'static> =
-        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
+        .with_attrs(AttributeMaskBuilder::Some(&[
             AttributeName::Color,
             AttributeName::TextAlign,
             AttributeName::TextShadow,
@@ -270,7 +311,11 @@ impl State<CustomAttributeValues> for FontStyleState {
             AttributeName::DecorationColor,
             AttributeName::DecorationStyle,
             AttributeName::TextOverflow,
-        ]));
+            AttributeName::Background,
+            AttributeName::BaselineShift,
+            AttributeName::FontFeatures,
+        ]))
+        .with_tag();
 
     fn update<'a>(
         &mut self,
@@ -284,6 +329,7 @@ impl State<CustomAttributeValues> for FontStyleState {
         let compositor_dirty_nodes = context.get::<Arc<Mutex<CompositorDirtyNodes>>>().unwrap();
 
         let mut font_style = parent.map(|(v,)| v.clone()).unwrap_or_default();
+        font_style.background = None;
 
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
@@ -291,6 +337,11 @@ impl State<CustomAttributeValues> for FontStyleState {
             }
         }
 
+        // The background of other elements is painted by themselves
+        if node_view.tag() != Some(&TagName::Text) {
+            font_style.background = None;
+        }
+
         let changed = &font_style != self;
 
         if changed {
@@ -117,3 +117,80 @@ impl Parse for TextOverflow {
         })
     }
 }
+
+/// Vertical position of a text span relative to the baseline.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub enum BaselineShift {
+    #[default]
+    Baseline,
+    Super,
+    Sub,
+    /// Pixels to shift the text up, negative values shift it down.
+    Custom(f32),
+}
+
+impl BaselineShift {
+    /// Offset to apply to the baseline, positive values shift the text down as Skia expects.
+    pub fn offset(&self, font_size: f32) -> f32 {
+        match self {
+            Self::Baseline => 0.0,
+            Self::Super => -font_size * 0.35,
+            Self::Sub => font_size * 0.2,
+            Self::Custom(shift) => -shift,
+        }
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Baseline => "baseline".to_string(),
+            Self::Super => "super".to_string(),
+            Self::Sub => "sub".to_string(),
+            Self::Custom(shift) => shift.to_string(),
+        }
+    }
+}
+
+impl Parse for BaselineShift {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Ok(match value {
+            "baseline" => BaselineShift::Baseline,
+            "super" => BaselineShift::Super,
+            "sub" => BaselineShift::Sub,
+            value => BaselineShift::Custom(value.parse::<f32>().map_err(|_| ParseError)?),
+        })
+    }
+}
+
+/// OpenType feature of a font, e.g `liga` or `tnum`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OpenTypeFeature {
+    pub tag: String,
+    pub value: i32,
+}
+
+impl Parse for OpenTypeFeature {
+    // Same syntax as CSS's `font-feature-settings`: `<tag> [<value> | on | off]?`.
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let mut values = value.split_ascii_whitespace();
+
+        let tag = values.next().ok_or(ParseError)?.trim_matches(['"', '\'']);
+        if tag.len() != 4 || !tag.chars().all(|c| c.is_ascii_graphic()) {
+            return Err(ParseError);
+        }
+
+        let value = match values.next() {
+            None | Some("on") => 1,
+            Some("off") => 0,
+            Some(value) => value.parse::<i32>().map_err(|_| ParseError)?,
+        };
+
+        if values.next().is_some() {
+            return Err(ParseError);
+        }
+
+        Ok(OpenTypeFeature {
+            tag: tag.to_string(),
+            value,
+        })
+    }
+}
@@ -12,6 +12,7 @@ mod gaps;
 mod gradient;
 mod highlight;
 mod overflow;
+mod pointer_events;
 mod position;
 mod shadow;
 mod size;
@@ -27,5 +28,6 @@ pub use font::*;
 pub use gradient::*;
 pub use highlight::*;
 pub use overflow::*;
+pub use pointer_events::*;
 pub use shadow::*;
 pub use size::*;
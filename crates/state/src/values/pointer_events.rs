@@ -0,0 +1,32 @@
+use std::fmt;
+
+use crate::{
+    Parse,
+    ParseError,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum PointerEvents {
+    #[default]
+    Auto,
+    None,
+}
+
+impl Parse for PointerEvents {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "auto" => Ok(PointerEvents::Auto),
+            "none" => Ok(PointerEvents::None),
+            _ => Err(ParseError),
+        }
+    }
+}
+
+impl fmt::Display for PointerEvents {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            PointerEvents::Auto => "auto",
+            PointerEvents::None => "none",
+        })
+    }
+}
@@ -19,6 +19,7 @@ use crate::{
     Parse,
     ParseAttribute,
     ParseError,
+    PointerEvents,
 };
 
 #[derive(Default, PartialEq, Clone, Debug, Component)]
@@ -26,6 +27,7 @@ pub struct ViewportState {
     pub viewports: Vec<NodeId>,
     pub node_id: NodeId,
     pub overflow: OverflowMode,
+    pub pointer_events: PointerEvents,
 }
 
 impl ParseAttribute for ViewportState {
@@ -33,13 +35,17 @@ impl ParseAttribute for ViewportState {
         &mut self,
         attr: freya_native_core::prelude::OwnedAttributeView<CustomAttributeValues>,
     ) -> Result<(), crate::ParseError> {
-        #[allow(clippy::single_match)]
         match attr.attribute {
             AttributeName::Overflow => {
                 if let Some(value) = attr.value.as_text() {
                     self.overflow = OverflowMode::parse(value).map_err(|_| ParseError)?;
                 }
             }
+            AttributeName::PointerEvents => {
+                if let Some(value) = attr.value.as_text() {
+                    self.pointer_events = PointerEvents::parse(value)?;
+                }
+            }
             _ => {}
         }
 
@@ -56,7 +62,10 @@ impl State<CustomAttributeValues> for ViewportState {
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
-        .with_attrs(AttributeMaskBuilder::Some(&[AttributeName::Overflow]))
+        .with_attrs(AttributeMaskBuilder::Some(&[
+            AttributeName::Overflow,
+            AttributeName::PointerEvents,
+        ]))
         .with_tag();
 
     fn update<'a>(
@@ -73,6 +82,11 @@ impl State<CustomAttributeValues> for ViewportState {
 
         let mut viewports_state = ViewportState {
             node_id: node_view.node_id(),
+            // Inherit the pointer events from the parent unless overridden
+            pointer_events: parent
+                .as_ref()
+                .map(|(parent,)| parent.pointer_events)
+                .unwrap_or_default(),
             ..Default::default()
         };
 
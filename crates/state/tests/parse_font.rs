@@ -0,0 +1,56 @@
+use freya_node_state::{
+    BaselineShift,
+    OpenTypeFeature,
+    Parse,
+};
+
+#[test]
+fn parse_baseline_shift() {
+    let baseline = BaselineShift::parse("baseline");
+    assert_eq!(baseline, Ok(BaselineShift::Baseline));
+
+    let superscript = BaselineShift::parse("super");
+    assert_eq!(superscript, Ok(BaselineShift::Super));
+
+    let subscript = BaselineShift::parse("sub");
+    assert_eq!(subscript, Ok(BaselineShift::Sub));
+
+    let custom = BaselineShift::parse("-4.5");
+    assert_eq!(custom, Ok(BaselineShift::Custom(-4.5)));
+
+    let invalid = BaselineShift::parse("Rust");
+    assert!(invalid.is_err());
+}
+
+#[test]
+fn parse_open_type_feature() {
+    let enabled = OpenTypeFeature::parse("tnum");
+    assert_eq!(
+        enabled,
+        Ok(OpenTypeFeature {
+            tag: "tnum".to_string(),
+            value: 1
+        })
+    );
+
+    let disabled = OpenTypeFeature::parse("\"liga\" off");
+    assert_eq!(
+        disabled,
+        Ok(OpenTypeFeature {
+            tag: "liga".to_string(),
+            value: 0
+        })
+    );
+
+    let alternate = OpenTypeFeature::parse("salt 2");
+    assert_eq!(
+        alternate,
+        Ok(OpenTypeFeature {
+            tag: "salt".to_string(),
+            value: 2
+        })
+    );
+
+    let invalid = OpenTypeFeature::parse("ligatures");
+    assert!(invalid.is_err());
+}
@@ -0,0 +1,15 @@
+use freya_node_state::{
+    Parse,
+    PointerEvents,
+};
+
+#[test]
+fn parse_pointer_events() {
+    assert_eq!(PointerEvents::parse("auto"), Ok(PointerEvents::Auto));
+    assert_eq!(PointerEvents::parse("none"), Ok(PointerEvents::None));
+}
+
+#[test]
+fn parse_invalid_pointer_events() {
+    assert!(PointerEvents::parse("all").is_err());
+}
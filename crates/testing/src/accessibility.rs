@@ -0,0 +1,291 @@
+use std::fmt;
+
+use accesskit::{
+    Action,
+    Node,
+    Role,
+};
+use freya_common::AccessibilityDirtyNodes;
+use freya_core::prelude::{
+    AccessibilityId,
+    AccessibilityTree,
+    DioxusDOM,
+    ACCESSIBILITY_ROOT_ID,
+};
+use freya_native_core::{
+    prelude::NodeImmutable,
+    tags::TagName,
+    NodeId,
+};
+use freya_node_state::AccessibilityNodeState;
+use torin::torin::Torin;
+
+/// Roles whose accessible name can be computed from their content, e.g the label of a button.
+const NAME_FROM_CONTENTS_ROLES: [Role; 11] = [
+    Role::Button,
+    Role::Link,
+    Role::Tab,
+    Role::CheckBox,
+    Role::RadioButton,
+    Role::Switch,
+    Role::MenuItem,
+    Role::ListItem,
+    Role::TreeItem,
+    Role::Heading,
+    Role::Cell,
+];
+
+/// Accessibility problem found by [`TestAccessibilityTree::audit`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccessibilityIssue {
+    /// A focusable node has no accessible name.
+    MissingName { id: AccessibilityId, role: Role },
+    /// An `image` element has no `a11y_alt`.
+    MissingAlt { node_id: NodeId },
+    /// Several nodes share the same `a11y_id`.
+    DuplicateId { id: AccessibilityId, count: usize },
+}
+
+impl fmt::Display for AccessibilityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingName { id, role } => write!(
+                f,
+                "The focusable node {id:?} with role {role:?} has no accessible name, give it a `a11y_name` or some text"
+            ),
+            Self::MissingAlt { node_id } => write!(
+                f,
+                "The image {node_id:?} has no alternative text, give it a `a11y_alt`"
+            ),
+            Self::DuplicateId { id, count } => {
+                write!(f, "The accessibility ID {id:?} is used by {count} nodes")
+            }
+        }
+    }
+}
+
+/// Accessibility tree of the app, built the same way as the one given to the assistive technologies.
+pub struct TestAccessibilityTree {
+    nodes: Vec<(AccessibilityId, Node)>,
+    images_without_alt: Vec<NodeId>,
+}
+
+impl TestAccessibilityTree {
+    pub(crate) fn new(rdom: &DioxusDOM, layout: &Torin<NodeId>) -> Self {
+        let tree_update = AccessibilityTree::new(ACCESSIBILITY_ROOT_ID).init(
+            rdom,
+            layout,
+            &mut AccessibilityDirtyNodes::default(),
+        );
+
+        let mut images_without_alt = Vec::new();
+        rdom.traverse_depth_first(|node_ref| {
+            if node_ref.node_type().tag() == Some(&TagName::Image) {
+                let node_accessibility = node_ref.get::<AccessibilityNodeState>().unwrap();
+                if node_accessibility.a11y_alt.is_none() {
+                    images_without_alt.push(node_ref.id());
+                }
+            }
+        });
+
+        Self {
+            nodes: tree_update.nodes,
+            images_without_alt,
+        }
+    }
+
+    /// Get the root node, the Window.
+    pub fn root(&self) -> TestAccessibilityNode<'_> {
+        self.get(ACCESSIBILITY_ROOT_ID)
+            .expect("The accessibility tree has no root")
+    }
+
+    /// Get a node by its accessibility ID.
+    pub fn get(&self, id: AccessibilityId) -> Option<TestAccessibilityNode<'_>> {
+        self.nodes
+            .iter()
+            .find(|(node_id, _)| *node_id == id)
+            .map(|(id, node)| TestAccessibilityNode {
+                tree: self,
+                id: *id,
+                node,
+            })
+    }
+
+    /// Iterate over all the nodes, in tree order.
+    pub fn nodes(&self) -> impl Iterator<Item = TestAccessibilityNode<'_>> {
+        self.nodes.iter().map(|(id, node)| TestAccessibilityNode {
+            tree: self,
+            id: *id,
+            node,
+        })
+    }
+
+    /// Get all the nodes with the given role and, optionally, accessible name.
+    pub fn query_all_by_role(
+        &self,
+        role: Role,
+        name: Option<&str>,
+    ) -> Vec<TestAccessibilityNode<'_>> {
+        self.nodes()
+            .filter(|node| node.role() == role)
+            .filter(|node| name.is_none() || node.name().as_deref() == name)
+            .collect()
+    }
+
+    /// Get the node with the given role and, optionally, accessible name.
+    /// Returns `None` if there is none and panics if there are several.
+    #[track_caller]
+    pub fn query_by_role(
+        &self,
+        role: Role,
+        name: Option<&str>,
+    ) -> Option<TestAccessibilityNode<'_>> {
+        let mut nodes = self.query_all_by_role(role, name);
+        if nodes.len() > 1 {
+            panic!(
+                "Found {} nodes with role {role:?} and name {name:?}",
+                nodes.len()
+            );
+        }
+        nodes.pop()
+    }
+
+    /// Get the node with the given role and, optionally, accessible name.
+    /// Panics if there isn't exactly one.
+    #[track_caller]
+    pub fn get_by_role(&self, role: Role, name: Option<&str>) -> TestAccessibilityNode<'_> {
+        self.query_by_role(role, name)
+            .unwrap_or_else(|| panic!("Found no node with role {role:?} and name {name:?}"))
+    }
+
+    /// Find accessibility problems, like focusable nodes without names,
+    /// images without `a11y_alt` or duplicated `a11y_id`s.
+    pub fn audit(&self) -> Vec<AccessibilityIssue> {
+        let mut issues = Vec::new();
+
+        for node in self.nodes() {
+            if node.id() != ACCESSIBILITY_ROOT_ID && node.is_focusable() && node.name().is_none() {
+                issues.push(AccessibilityIssue::MissingName {
+                    id: node.id(),
+                    role: node.role(),
+                });
+            }
+        }
+
+        for node_id in &self.images_without_alt {
+            issues.push(AccessibilityIssue::MissingAlt { node_id: *node_id });
+        }
+
+        let mut ids = self.nodes.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        ids.sort();
+        for chunk in ids.chunk_by(|a, b| a == b) {
+            if chunk.len() > 1 {
+                issues.push(AccessibilityIssue::DuplicateId {
+                    id: chunk[0],
+                    count: chunk.len(),
+                });
+            }
+        }
+
+        issues
+    }
+
+    /// Panic if the [`audit`](Self::audit) finds any problem.
+    #[track_caller]
+    pub fn assert_accessible(&self) {
+        let issues = self.audit();
+        if !issues.is_empty() {
+            let issues = issues
+                .iter()
+                .map(|issue| format!("- {issue}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            panic!("Found accessibility issues:\n{issues}");
+        }
+    }
+}
+
+/// Node of a [`TestAccessibilityTree`].
+#[derive(Clone, Copy)]
+pub struct TestAccessibilityNode<'a> {
+    tree: &'a TestAccessibilityTree,
+    id: AccessibilityId,
+    node: &'a Node,
+}
+
+impl<'a> TestAccessibilityNode<'a> {
+    /// Get the accessibility ID.
+    pub fn id(&self) -> AccessibilityId {
+        self.id
+    }
+
+    /// Get the accesskit node.
+    pub fn node(&self) -> &'a Node {
+        self.node
+    }
+
+    /// Get the role.
+    pub fn role(&self) -> Role {
+        self.node.role()
+    }
+
+    /// Get the value, e.g the text of a label or the alternative text of an image.
+    pub fn value(&self) -> Option<&'a str> {
+        self.node.value()
+    }
+
+    /// Check if the node can be focused.
+    pub fn is_focusable(&self) -> bool {
+        self.node.supports_action(Action::Focus)
+    }
+
+    /// Get the children.
+    pub fn children(&self) -> Vec<TestAccessibilityNode<'a>> {
+        self.node
+            .children()
+            .iter()
+            .filter_map(|id| self.tree.get(*id))
+            .collect()
+    }
+
+    /// Get the accessible name, from `a11y_name`, the value or,
+    /// for roles like buttons, the text of the descendants.
+    pub fn name(&self) -> Option<String> {
+        if let Some(name) = self.node.name() {
+            return Some(name.to_string());
+        }
+        if !matches!(self.role(), Role::TextInput | Role::MultilineTextInput) {
+            if let Some(value) = self.node.value() {
+                return Some(value.to_string());
+            }
+        }
+        if NAME_FROM_CONTENTS_ROLES.contains(&self.role()) {
+            return self.children_text();
+        }
+        None
+    }
+
+    /// Text of the descendants, joined by spaces.
+    fn children_text(&self) -> Option<String> {
+        let text = self
+            .children()
+            .iter()
+            .filter_map(|child| {
+                child
+                    .node
+                    .name()
+                    .or(child.node.value())
+                    .map(|text| text.to_string())
+                    .or_else(|| child.children_text())
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let text = text.trim();
+        if text.is_empty() {
+            None
+        } else {
+            Some(text.to_string())
+        }
+    }
+}
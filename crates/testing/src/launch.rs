@@ -3,6 +3,7 @@ use std::sync::{
     Mutex,
 };
 
+use dioxus::prelude::use_context_provider;
 use dioxus_core::{
     fc_to_builder,
     Element,
@@ -15,6 +16,7 @@ use freya_core::prelude::{
     *,
 };
 use freya_engine::prelude::*;
+use freya_hooks::UseClipboard;
 use tokio::sync::{
     broadcast,
     mpsc::unbounded_channel,
@@ -24,6 +26,7 @@ use winit::window::CursorIcon;
 
 use crate::{
     config::TestingConfig,
+    mock_clipboard::MockClipboard,
     test_handler::TestingHandler,
     test_utils::TestUtils,
     SCALE_FACTOR,
@@ -38,7 +41,8 @@ pub fn launch_test(root: AppComponent) -> TestingHandler {
 
 /// Run a Component in a headless testing environment
 pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> TestingHandler {
-    let vdom = with_accessibility(root);
+    let clipboard = MockClipboard::default();
+    let vdom = with_accessibility(root, clipboard.clone());
     let fdom = FreyaDOM::default();
     let sdom = SafeDOM::new(fdom);
 
@@ -51,10 +55,9 @@ pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> Tes
         information: PlatformInformation::new(config.size, false, false, false),
         scale_factor: SCALE_FACTOR,
     });
+    let fonts = FontsRegistry::new([], default_fonts(), Vec::new());
     let mut font_collection = FontCollection::new();
-    let font_mgr = FontMgr::default();
-    font_collection.set_dynamic_font_manager(font_mgr.clone());
-    font_collection.set_default_font_manager(font_mgr.clone(), None);
+    let font_mgr = fonts.setup_font_collection(&mut font_collection);
 
     let mut handler = TestingHandler {
         vdom,
@@ -62,6 +65,7 @@ pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> Tes
         nodes_state: NodesState::default(),
         font_collection,
         font_mgr,
+        fonts,
         event_emitter,
         event_receiver,
         utils: TestUtils { sdom },
@@ -70,7 +74,12 @@ pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> Tes
         platform_event_receiver,
         accessibility_tree: Arc::new(Mutex::new(AccessibilityTree::new(ACCESSIBILITY_ROOT_ID))),
         ticker_sender: broadcast::channel(5).0,
+        menu_sender: broadcast::channel(5).0,
+        close_requests: CloseRequests::default(),
+        is_closed: false,
         cursor_icon: CursorIcon::default(),
+        opened_windows: Vec::new(),
+        clipboard,
         platform_sender,
         platform_receiver,
     };
@@ -80,10 +89,11 @@ pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> Tes
     handler
 }
 
-fn with_accessibility(app: AppComponent) -> VirtualDom {
+fn with_accessibility(app: AppComponent, clipboard: MockClipboard) -> VirtualDom {
     #[derive(Clone)]
     struct RootProps {
         app: AppComponent,
+        clipboard: MockClipboard,
     }
 
     #[allow(non_snake_case)]
@@ -91,12 +101,14 @@ fn with_accessibility(app: AppComponent) -> VirtualDom {
         #[allow(non_snake_case)]
         let App = props.app;
 
+        use_context_provider(|| UseClipboard::new(props.clipboard.clone()));
+
         rsx!(NativeContainer {
             App {}
         })
     }
 
-    VirtualDom::new_with_props(Root, RootProps { app })
+    VirtualDom::new_with_props(Root, RootProps { app, clipboard })
 }
 
 type AppComponent = fn() -> Element;
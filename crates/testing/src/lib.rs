@@ -152,9 +152,42 @@
 //!     assert_eq!(label_text.text(), Some("Hello World!"));
 //! }
 //! ````
+//!
+//! ## Accessibility example
+//!
+//! `a11y_tree` builds the accessibility tree that the assistive technologies would get,
+//! so you can query its nodes by role and name, and audit it for common problems.
+//!
+//! ```rust, no_run
+//! #[tokio::test]
+//! async fn test() {
+//!     fn our_component() -> Element {
+//!         rsx!(
+//!             Button {
+//!                 label {
+//!                     a11y_role: "label",
+//!                     "Save"
+//!                 }
+//!             }
+//!         )
+//!     }
+//!
+//!     let mut utils = launch_test(our_component);
+//!     utils.wait_for_update().await;
+//!
+//!     let tree = utils.a11y_tree();
+//!     let button = tree.get_by_role(Role::Button, Some("Save"));
+//!     assert!(button.is_focusable());
+//!
+//!     // Panics if there are focusable nodes without names, images without `a11y_alt` or duplicated `a11y_id`s
+//!     tree.assert_accessible();
+//! }
+//! ```
 
+pub mod accessibility;
 pub mod config;
 pub mod launch;
+pub mod mock_clipboard;
 pub mod test_handler;
 pub mod test_node;
 pub mod test_utils;
@@ -166,8 +199,10 @@ pub mod prelude {
     pub use freya_node_state::*;
 
     pub use crate::{
+        accessibility::*,
         config::*,
         launch::*,
+        mock_clipboard::*,
         test_handler::*,
         test_node::*,
         test_utils::*,
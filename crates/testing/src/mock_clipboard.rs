@@ -0,0 +1,53 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use freya_hooks::{
+    ClipboardContent,
+    ClipboardError,
+    ClipboardFormat,
+    ClipboardProvider,
+};
+
+/// In-memory clipboard used instead of the system clipboard when testing.
+///
+/// Accessible from the tests with [`TestingHandler::clipboard`](crate::test_handler::TestingHandler::clipboard).
+#[derive(Clone, Default)]
+pub struct MockClipboard {
+    content: Arc<Mutex<Option<ClipboardContent>>>,
+}
+
+impl MockClipboard {
+    /// Get the current content of the clipboard.
+    pub fn content(&self) -> Option<ClipboardContent> {
+        self.content.lock().unwrap().clone()
+    }
+
+    /// Replace the content of the clipboard.
+    pub fn set_content(&self, content: impl Into<Option<ClipboardContent>>) {
+        *self.content.lock().unwrap() = content.into();
+    }
+}
+
+impl ClipboardProvider for MockClipboard {
+    fn get(&mut self, format: ClipboardFormat) -> Result<ClipboardContent, ClipboardError> {
+        match (self.content(), format) {
+            (Some(content), format) if content.format() == format => Ok(content),
+            // Like most platforms, HTML can be read as its plain text alternative
+            (
+                Some(ClipboardContent::Html {
+                    alt_text: Some(text),
+                    ..
+                }),
+                ClipboardFormat::Text,
+            ) => Ok(ClipboardContent::Text(text)),
+            _ => Err(ClipboardError::ContentNotAvailable),
+        }
+    }
+
+    fn set(&mut self, content: ClipboardContent) -> Result<(), ClipboardError> {
+        self.set_content(content);
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@ use freya_core::prelude::{
     TextGroupMeasurement,
     *,
 };
+use freya_elements::events::AccessibilityAction;
 use freya_engine::prelude::{
     raster_n32_premul,
     Color,
@@ -19,7 +20,6 @@ use freya_engine::prelude::{
     FontCollection,
     FontMgr,
 };
-use freya_native_core::dioxus::NodeImmutableDioxusExt;
 use tokio::{
     sync::{
         broadcast,
@@ -46,7 +46,9 @@ use winit::{
 };
 
 use crate::{
+    accessibility::TestAccessibilityTree,
     config::TestingConfig,
+    mock_clipboard::MockClipboard,
     test_node::TestNode,
     test_utils::TestUtils,
     SCALE_FACTOR,
@@ -66,10 +68,16 @@ pub struct TestingHandler {
     pub(crate) platform_receiver: NativePlatformReceiver,
     pub(crate) font_collection: FontCollection,
     pub(crate) font_mgr: FontMgr,
+    pub(crate) fonts: FontsRegistry,
     pub(crate) accessibility_tree: SharedAccessibilityTree,
     pub(crate) config: TestingConfig,
     pub(crate) ticker_sender: broadcast::Sender<()>,
+    pub(crate) menu_sender: MenuEventsSender,
+    pub(crate) close_requests: CloseRequests,
+    pub(crate) is_closed: bool,
     pub(crate) cursor_icon: CursorIcon,
+    pub(crate) opened_windows: Vec<NewWindow>,
+    pub(crate) clipboard: MockClipboard,
 }
 
 impl TestingHandler {
@@ -94,6 +102,10 @@ impl TestingHandler {
             .insert_any_root_context(Box::new(self.platform_receiver.clone()));
         self.vdom
             .insert_any_root_context(Box::new(Arc::new(self.ticker_sender.subscribe())));
+        self.vdom
+            .insert_any_root_context(Box::new(self.menu_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.close_requests.clone()));
         let accessibility_generator = {
             let sdom = self.sdom();
             let fdom = sdom.get();
@@ -146,32 +158,33 @@ impl TestingHandler {
                         }
                     }
                     EventMessage::FocusNextAccessibilityNode => {
-                        let fdom = self.utils.sdom.get();
-                        let rdom = fdom.rdom();
-                        let (tree, _) = self
-                            .accessibility_tree
-                            .lock()
-                            .unwrap()
-                            .set_focus_on_next_node(AccessibilityFocusStrategy::Forward, rdom);
-                        self.platform_sender.send_modify(|state| {
-                            state.focused_id = tree.focus;
-                        });
+                        self.focus_next_node(AccessibilityFocusStrategy::Forward);
                     }
                     EventMessage::FocusPrevAccessibilityNode => {
-                        let fdom = self.utils.sdom.get();
-                        let rdom = fdom.rdom();
-                        let (tree, _) = self
-                            .accessibility_tree
-                            .lock()
-                            .unwrap()
-                            .set_focus_on_next_node(AccessibilityFocusStrategy::Backward, rdom);
-                        self.platform_sender.send_modify(|state| {
-                            state.focused_id = tree.focus;
-                        });
+                        self.focus_next_node(AccessibilityFocusStrategy::Backward);
+                    }
+                    EventMessage::FocusAccessibilityNodeWithStrategy(strategy) => {
+                        self.focus_next_node(strategy);
                     }
                     EventMessage::SetCursorIcon(icon) => {
                         self.cursor_icon = icon;
                     }
+                    EventMessage::NewWindow(new_window) => {
+                        self.opened_windows.push(new_window);
+                    }
+                    EventMessage::UpdateFonts(operation) => {
+                        if self.fonts.apply(&operation) {
+                            self.font_mgr =
+                                self.fonts.setup_font_collection(&mut self.font_collection);
+                            self.utils.sdom().get_mut().layout().reset();
+                        }
+                    }
+                    EventMessage::ExitApp => {
+                        self.is_closed = true;
+                    }
+                    EventMessage::PlatformEvent(platform_event) => {
+                        self.push_event(platform_event);
+                    }
                     EventMessage::RemeasureTextGroup(text_measurement) => {
                         self.measure_text_group(text_measurement);
                     }
@@ -182,17 +195,15 @@ impl TestingHandler {
             if let Ok(events) = vdom_events {
                 let fdom = self.utils.sdom().get();
                 let rdom = fdom.rdom();
-                for event in events {
-                    if let Some(element_id) =
-                        rdom.get(event.node_id).and_then(|node| node.mounted_id())
-                    {
-                        let name = event.name.into();
-                        let data = event.data.any();
-                        self.vdom
-                            .handle_event(name, data, element_id, event.bubbles);
+                emit_dom_events(
+                    events,
+                    rdom,
+                    &mut self.nodes_state,
+                    |name, data, element_id, bubbles| {
+                        self.vdom.handle_event(name, data, element_id, bubbles);
                         self.vdom.process_events();
-                    }
-                }
+                    },
+                );
             }
         }
 
@@ -224,7 +235,7 @@ impl TestingHandler {
             },
             &mut self.font_collection,
             SCALE_FACTOR as f32,
-            &default_fonts(),
+            &self.fonts.default_fonts(),
         );
 
         let fdom = &self.utils.sdom().get_mut();
@@ -249,6 +260,22 @@ impl TestingHandler {
         );
     }
 
+    /// Focus the next accessibility node with the given strategy, like the keyboard navigation does.
+    fn focus_next_node(&self, strategy: AccessibilityFocusStrategy) {
+        let fdom = self.utils.sdom.get();
+        let rdom = fdom.rdom();
+        let layout = fdom.layout();
+        let (tree, _) = self
+            .accessibility_tree
+            .lock()
+            .unwrap()
+            .set_focus_on_next_node(strategy, rdom, &layout);
+        self.platform_sender.send_modify(|state| {
+            state.focused_id = tree.focus;
+            state.navigation_mode = NavigationMode::Keyboard;
+        });
+    }
+
     fn measure_text_group(&self, text_measurement: TextGroupMeasurement) {
         let sdom = self.utils.sdom();
         sdom.get()
@@ -280,6 +307,12 @@ impl TestingHandler {
         self.accessibility_tree.lock().unwrap().focused_id
     }
 
+    /// Build the accessibility tree of the app, to query it or audit it.
+    pub fn a11y_tree(&self) -> TestAccessibilityTree {
+        let fdom = self.utils.sdom().get();
+        TestAccessibilityTree::new(fdom.rdom(), &fdom.layout())
+    }
+
     /// Resize the simulated canvas.
     pub fn resize(&mut self, size: Size2D) {
         self.config.size = size;
@@ -294,6 +327,37 @@ impl TestingHandler {
         self.cursor_icon
     }
 
+    /// Get the secondary Windows requested so far, these are never actually opened.
+    pub fn opened_windows(&self) -> &[NewWindow] {
+        &self.opened_windows
+    }
+
+    /// Get the clipboard used by the app.
+    pub fn clipboard(&self) -> &MockClipboard {
+        &self.clipboard
+    }
+
+    /// Activate a native menu item, like if it was clicked in the tray icon menu or the menu bar.
+    pub async fn activate_menu_item(&mut self, id: impl Into<String>) {
+        self.menu_sender.send(MenuEvent { id: id.into() }).ok();
+        self.wait_for_update().await;
+    }
+
+    /// Request to close the Window, like if the user clicked its close button.
+    pub async fn request_close(&mut self) {
+        if self.close_requests.is_intercepted() {
+            self.close_requests.request();
+        } else {
+            self.is_closed = true;
+        }
+        self.wait_for_update().await;
+    }
+
+    /// Check if the Window was closed, by the user or by the app itself.
+    pub fn is_closed(&self) -> bool {
+        self.is_closed
+    }
+
     /// Get the [SafeDOM]
     pub fn sdom(&self) -> &SafeDOM {
         self.utils.sdom()
@@ -364,6 +428,48 @@ impl TestingHandler {
         self.wait_for_update().await;
     }
 
+    /// Shorthand to simulate hovering files over the window with the cursor in the given location.
+    pub async fn hover_files(
+        &mut self,
+        cursor: impl Into<CursorPoint>,
+        file_paths: impl IntoIterator<Item = impl Into<PathBuf>>,
+    ) {
+        self.push_event(PlatformEvent::File {
+            name: EventName::GlobalFileHover,
+            cursor: cursor.into(),
+            file_paths: file_paths.into_iter().map(Into::into).collect(),
+        });
+        self.wait_for_update().await;
+    }
+
+    /// Shorthand to simulate dropping files in the given location.
+    pub async fn drop_files(
+        &mut self,
+        cursor: impl Into<CursorPoint>,
+        file_paths: impl IntoIterator<Item = impl Into<PathBuf>>,
+    ) {
+        self.push_event(PlatformEvent::File {
+            name: EventName::FileDrop,
+            cursor: cursor.into(),
+            file_paths: file_paths.into_iter().map(Into::into).collect(),
+        });
+        self.wait_for_update().await;
+    }
+
+    /// Shorthand to simulate an assistive technology requesting an action on the given accessibility node.
+    pub async fn request_a11y_action(
+        &mut self,
+        target: AccessibilityId,
+        action: AccessibilityAction,
+    ) {
+        self.push_event(PlatformEvent::Accessibility {
+            name: EventName::A11yAction,
+            target,
+            action,
+        });
+        self.wait_for_update().await;
+    }
+
     /// Shorthand to simulate a click with cursor in the given location.
     pub async fn click_cursor(&mut self, cursor: impl Into<CursorPoint> + Clone) {
         self.push_event(PlatformEvent::Mouse {
@@ -0,0 +1,146 @@
+use accesskit::Action;
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+async fn query_by_role() {
+    fn query_by_role_app() -> Element {
+        let focus = use_focus();
+
+        rsx!(
+            Button {
+                label {
+                    a11y_role: "label",
+                    "Save"
+                }
+            }
+            Button {
+                label {
+                    a11y_role: "label",
+                    "Cancel"
+                }
+            }
+            rect {
+                a11y_id: focus.attribute(),
+                a11y_role: "button",
+                a11y_name: "Close",
+            }
+        )
+    }
+
+    let mut utils = launch_test(query_by_role_app);
+    utils.wait_for_update().await;
+
+    let tree = utils.a11y_tree();
+
+    assert_eq!(tree.query_all_by_role(Role::Button, None).len(), 3);
+
+    // The name comes from the text of the descendants
+    let save = tree.get_by_role(Role::Button, Some("Save"));
+    assert_eq!(
+        Some(save.id()),
+        utils.root().get(0).state().accessibility.a11y_id
+    );
+    assert!(save.is_focusable());
+
+    // Or from `a11y_name`
+    let close = tree.get_by_role(Role::Button, Some("Close"));
+    assert_eq!(close.name().as_deref(), Some("Close"));
+
+    assert!(tree.query_by_role(Role::Button, Some("Delete")).is_none());
+    assert!(tree.query_by_role(Role::Slider, None).is_none());
+
+    tree.assert_accessible();
+}
+
+#[tokio::test]
+async fn audit() {
+    fn audit_app() -> Element {
+        let focus = use_focus();
+
+        rsx!(
+            rect {
+                a11y_id: focus.attribute(),
+                a11y_role: "button",
+            }
+            rect {
+                a11y_id: focus.attribute(),
+                a11y_name: "Duplicated",
+            }
+            image { }
+            image {
+                a11y_alt: "A cat",
+            }
+        )
+    }
+
+    let mut utils = launch_test(audit_app);
+    utils.wait_for_update().await;
+
+    let duplicated_id = utils.root().get(0).state().accessibility.a11y_id.unwrap();
+
+    let issues = utils.a11y_tree().audit();
+
+    assert_eq!(issues.len(), 3);
+    assert_eq!(
+        issues[0],
+        AccessibilityIssue::MissingName {
+            id: duplicated_id,
+            role: Role::Button,
+        }
+    );
+    assert!(matches!(issues[1], AccessibilityIssue::MissingAlt { .. }));
+    assert_eq!(
+        issues[2],
+        AccessibilityIssue::DuplicateId {
+            id: duplicated_id,
+            count: 2,
+        }
+    );
+}
+
+#[tokio::test]
+async fn advertised_actions() {
+    fn advertised_actions_app() -> Element {
+        let focus = use_focus();
+
+        rsx!(
+            Slider {
+                value: 50.,
+                onmoved: |_| {},
+            }
+            ProgressBar {
+                progress: 50.,
+            }
+            rect {
+                a11y_id: focus.attribute(),
+                a11y_role: "button",
+                a11y_name: "Plain",
+            }
+            Button {
+                label {
+                    "Save"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(advertised_actions_app);
+    utils.wait_for_update().await;
+
+    let tree = utils.a11y_tree();
+
+    // Only adjustable nodes handling the actions can be incremented
+    let slider = tree.get_by_role(Role::Slider, None);
+    assert!(slider.node().supports_action(Action::Increment));
+    let progress_bar = tree.get_by_role(Role::ProgressIndicator, None);
+    assert!(!progress_bar.node().supports_action(Action::Increment));
+    assert!(!progress_bar.node().supports_action(Action::SetValue));
+
+    // Only focusable nodes handling the actions can be activated
+    let plain = tree.get_by_role(Role::Button, Some("Plain"));
+    assert!(plain.is_focusable());
+    assert!(!plain.node().supports_action(Action::Default));
+    let save = tree.get_by_role(Role::Button, Some("Save"));
+    assert!(save.node().supports_action(Action::Default));
+}
@@ -0,0 +1,80 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use bytes::Bytes;
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Clipboard", (500.0, 450.0));
+}
+
+fn app() -> Element {
+    let clipboard = use_clipboard();
+    let mut message = use_signal(String::new);
+    let mut images = use_signal::<Vec<Bytes>>(Vec::new);
+
+    // Paste images into the message composer
+    let onglobalkeydown = move |e: KeyboardEvent| {
+        let paste = e.code == Code::KeyV
+            && (e.modifiers.contains(Modifiers::CONTROL) || e.modifiers.contains(Modifiers::META));
+        if paste {
+            let clipboard = clipboard.clone();
+            spawn(async move {
+                if let Ok(image) = clipboard.get_image().await {
+                    images.write().push(image);
+                }
+            });
+        }
+    };
+
+    let onclick = move |_| {
+        let clipboard = clipboard.clone();
+        let html = format!("<p>{}</p>", message.read());
+        let text = message.read().clone();
+        spawn(async move {
+            clipboard.set_html(html, Some(text)).await.ok();
+        });
+    };
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "20",
+            spacing: "10",
+            onglobalkeydown,
+            label {
+                "Paste images with Ctrl+V"
+            }
+            ScrollView {
+                height: "calc(100% - 90)",
+                direction: "horizontal",
+                spacing: "10",
+                for (i, image) in images.read().iter().enumerate() {
+                    image {
+                        key: "{i}",
+                        image_data: dynamic_bytes(image.clone()),
+                        width: "150",
+                        height: "150",
+                    }
+                }
+            }
+            rect {
+                direction: "horizontal",
+                spacing: "10",
+                Input {
+                    value: message.read().clone(),
+                    onchange: move |text| message.set(text),
+                }
+                Button {
+                    onclick,
+                    label {
+                        "Copy as HTML"
+                    }
+                }
+            }
+        }
+    )
+}
@@ -31,7 +31,7 @@ fn app() -> Element {
 
     rsx!(
         Input {
-            ..form.input(FormEntry::Name)
+            ..form.validated_input(FormEntry::Name, |name| !name.is_empty())
         },
         Input {
             ..form.input(FormEntry::Description)
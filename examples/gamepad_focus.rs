@@ -3,83 +3,13 @@
     windows_subsystem = "windows"
 )]
 
-use std::thread;
+// Run with `cargo run --example gamepad_focus --features gamepad`.
+// The D-pad moves the focus and the south button (e.g `A`) presses the focused button.
 
 use freya::prelude::*;
-use freya_core::prelude::{
-    EventMessage,
-    EventName,
-    FreyaPlugin,
-    PlatformEvent,
-    PluginEvent,
-    PluginHandle,
-};
-use gilrs::{
-    EventType,
-    Gilrs,
-};
 
 fn main() {
-    launch_cfg(
-        app,
-        LaunchConfig::<()>::new().with_plugin(GamePadPlugin::default()),
-    )
-}
-
-#[derive(Default)]
-pub struct GamePadPlugin;
-
-impl GamePadPlugin {
-    pub fn listen_gamepad(handle: PluginHandle) {
-        thread::spawn(move || {
-            println!("Listening for gamepads");
-
-            let mut gilrs_instance = Gilrs::new().unwrap();
-
-            loop {
-                while let Some(ev) = gilrs_instance.next_event() {
-                    match ev.event {
-                        EventType::ButtonReleased(_, code) => {
-                            // NOTE: You might need to tweak these codes
-                            match code.into_u32() {
-                                4 => {
-                                    handle.send_event_loop_event(
-                                        EventMessage::FocusPrevAccessibilityNode,
-                                    );
-                                }
-                                6 => {
-                                    handle.send_event_loop_event(
-                                        EventMessage::FocusNextAccessibilityNode,
-                                    );
-                                }
-                                13 => {
-                                    handle.send_platform_event(PlatformEvent::Keyboard {
-                                        name: EventName::KeyDown,
-                                        key: Key::Enter,
-                                        code: Code::Enter,
-                                        modifiers: Modifiers::default(),
-                                    });
-                                }
-                                _ => {}
-                            }
-                        }
-                        _ => {}
-                    }
-                }
-            }
-        });
-    }
-}
-
-impl FreyaPlugin for GamePadPlugin {
-    fn on_event(&mut self, event: &PluginEvent, handle: PluginHandle) {
-        match event {
-            PluginEvent::WindowCreated(_) => {
-                Self::listen_gamepad(handle);
-            }
-            _ => {}
-        }
-    }
+    launch(app);
 }
 
 fn app() -> Element {
@@ -0,0 +1,57 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use std::time::Duration;
+
+use freya::{
+    headless::*,
+    prelude::*,
+};
+
+fn app() -> Element {
+    let mut status = use_signal(|| "Loading...".to_string());
+
+    use_hook(move || {
+        spawn(async move {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            status.set("Loaded!".to_string());
+        })
+    });
+
+    rsx!(
+        rect {
+            height: "fill",
+            width: "fill",
+            main_align: "center",
+            cross_align: "center",
+            background: "rgb(0, 119, 182)",
+            color: "white",
+            label {
+                font_size: "50",
+                font_weight: "bold",
+                "{status}"
+            }
+        }
+    )
+}
+
+#[tokio::main]
+async fn main() {
+    let mut renderer = HeadlessRenderer::new(
+        app,
+        HeadlessConfig::new()
+            .with_size(400.0, 200.0)
+            .with_scale_factor(2.0)
+            .with_idle_timeout(Duration::from_millis(500)),
+    );
+
+    // Wait for the data to load
+    renderer.wait_for_work().await;
+
+    std::fs::write("./export.png", renderer.render_png().unwrap()).unwrap();
+    std::fs::write("./export.webp", renderer.render_webp(90).unwrap()).unwrap();
+    std::fs::write("./export.pdf", renderer.render_pdf()).unwrap();
+    std::fs::write("./export.svg", renderer.render_svg()).unwrap();
+}
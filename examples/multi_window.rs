@@ -0,0 +1,84 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Multi Window", (400.0, 300.0));
+}
+
+fn app() -> Element {
+    let platform = use_platform();
+    // Sync signals can be shared with other Windows
+    let mut count = use_hook(|| Signal::new_maybe_sync(0));
+
+    let open_inspector = move |_| {
+        platform.new_window_with_context(
+            inspector,
+            WindowConfig {
+                title: "Inspector",
+                size: (250.0, 150.0),
+                ..Default::default()
+            },
+            count,
+        );
+    };
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            main_align: "center",
+            cross_align: "center",
+            spacing: "10",
+            label {
+                "Count is {count}"
+            }
+            Button {
+                onclick: move |_| count += 1,
+                label {
+                    "Increase"
+                }
+            }
+            Button {
+                onclick: open_inspector,
+                label {
+                    "Open inspector"
+                }
+            }
+        }
+    )
+}
+
+fn inspector() -> Element {
+    let platform = use_platform();
+    let mut count = use_context::<SyncSignal<i32>>();
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            main_align: "center",
+            cross_align: "center",
+            spacing: "10",
+            label {
+                "Count is {count}"
+            }
+            Button {
+                onclick: move |_| count.set(0),
+                label {
+                    "Reset"
+                }
+            }
+            Button {
+                // Only closes this Window
+                onclick: move |_| platform.exit(),
+                label {
+                    "Close"
+                }
+            }
+        }
+    )
+}
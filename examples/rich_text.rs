@@ -0,0 +1,61 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(app);
+}
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "20",
+            spacing: "20",
+            paragraph {
+                font_size: "24",
+                text {
+                    "E = mc"
+                }
+                text {
+                    font_size: "14",
+                    baseline_shift: "super",
+                    "2"
+                }
+                text {
+                    "  H"
+                }
+                text {
+                    font_size: "14",
+                    baseline_shift: "sub",
+                    "2"
+                }
+                text {
+                    "O"
+                }
+            }
+            paragraph {
+                font_size: "18",
+                text {
+                    "Did you read the "
+                }
+                text {
+                    background: "rgb(255, 230, 100)",
+                    "highlighted"
+                }
+                text {
+                    " message?"
+                }
+            }
+            label {
+                font_size: "18",
+                font_features: "tnum",
+                "111.11\n999.99"
+            }
+        }
+    )
+}
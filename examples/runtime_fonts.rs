@@ -0,0 +1,57 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+static SANSITA_SWASHED: &[u8] = include_bytes!("./SansitaSwashed-Regular.ttf");
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::new()
+            .with_fallback_font("Noto Color Emoji")
+            .with_fallback_font("Noto Sans CJK JP"),
+    );
+}
+
+fn app() -> Element {
+    let fonts = use_fonts();
+    let mut registered = use_signal(|| false);
+    let system_families = use_hook(move || fonts.system_font_families().len());
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            padding: "12",
+            spacing: "8",
+            label {
+                "{system_families} font families installed in the system"
+            }
+            Button {
+                onclick: move |_| {
+                    if registered() {
+                        fonts.unregister_font("Sansita Swashed");
+                    } else {
+                        fonts.register_font("Sansita Swashed", SANSITA_SWASHED);
+                    }
+                    registered.toggle();
+                },
+                label {
+                    if registered() {
+                        "Unregister font"
+                    } else {
+                        "Register font"
+                    }
+                }
+            }
+            label {
+                font_family: "Sansita Swashed",
+                font_size: "40",
+                "Hello, World! こんにちは 👋"
+            }
+        }
+    )
+}
@@ -0,0 +1,34 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "TextArea", (400.0, 350.0));
+}
+
+fn app() -> Element {
+    let mut value = use_signal(String::new);
+
+    rsx!(
+        rect {
+            padding: "7",
+            spacing: "7",
+            width: "100%",
+            height: "100%",
+            TextArea {
+                value: value.read().clone(),
+                placeholder: "Write something...",
+                show_line_numbers: true,
+                onchange: move |txt| {
+                    value.set(txt);
+                }
+            }
+            label {
+                "{value.read().lines().count()} lines"
+            }
+        }
+    )
+}
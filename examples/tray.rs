@@ -0,0 +1,87 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::{
+    prelude::*,
+    tray_icon::{
+        menu::{
+            Menu,
+            MenuItem,
+            PredefinedMenuItem,
+            Submenu,
+        },
+        Icon,
+        TrayIconBuilder,
+    },
+};
+
+const ICON: &[u8] = include_bytes!("./freya_icon.png");
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::new()
+            .with_title("Sync")
+            .with_hide_on_close(true)
+            .with_tray_icon(|| {
+                TrayIconBuilder::new()
+                    .with_tooltip("Sync")
+                    .with_icon(load_icon())
+            })
+            .with_tray_menu(|| {
+                Menu::with_items(&[
+                    &MenuItem::with_id("sync", "Sync now", true, None),
+                    &PredefinedMenuItem::separator(),
+                    &MenuItem::with_id("quit", "Quit", true, None),
+                ])
+                .unwrap()
+            })
+            .with_menu_bar(|| {
+                let file = Submenu::with_items(
+                    "File",
+                    true,
+                    &[
+                        &MenuItem::with_id("sync", "Sync now", true, None),
+                        &MenuItem::with_id("quit", "Quit", true, None),
+                    ],
+                )
+                .unwrap();
+                Menu::with_items(&[&file]).unwrap()
+            }),
+    );
+}
+
+fn load_icon() -> Icon {
+    let image = image::load_from_memory(ICON).unwrap().into_rgba8();
+    let (width, height) = image.dimensions();
+    Icon::from_rgba(image.into_raw(), width, height).unwrap()
+}
+
+fn app() -> Element {
+    let platform = use_platform();
+    let mut synced = use_signal(|| 0);
+
+    use_menu_event(move |event| match event.id.as_str() {
+        "sync" => synced += 1,
+        "quit" => platform.exit(),
+        _ => {}
+    });
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            main_align: "center",
+            cross_align: "center",
+            spacing: "8",
+            label { "Synced {synced} times" }
+            label { "Close the Window to keep it running in the tray" }
+            Button {
+                onclick: move |_| platform.set_visible_window(false),
+                label { "Hide to tray" }
+            }
+        }
+    )
+}
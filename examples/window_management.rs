@@ -0,0 +1,61 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::new()
+            .with_size(500.0, 400.0)
+            .with_position(100.0, 100.0)
+            .with_persisted_geometry(std::env::temp_dir().join("freya-window-management")),
+    );
+}
+
+fn app() -> Element {
+    let platform = use_platform();
+    let PlatformInformation {
+        window_position,
+        viewport_size,
+        is_focused,
+        is_occluded,
+        ..
+    } = *use_platform_information().read();
+    let mut always_on_top = use_signal(|| false);
+    let monitors = use_resource(move || async move { platform.monitors().await });
+
+    let position = window_position
+        .map(|position| format!("{}, {}", position.x, position.y))
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            padding: "12",
+            spacing: "8",
+            label { "Position: {position}" }
+            label { "Size: {viewport_size.width}x{viewport_size.height}" }
+            label { "Focused: {is_focused}, Occluded: {is_occluded}" }
+            Button {
+                onclick: move |_| {
+                    always_on_top.toggle();
+                    platform.set_always_on_top_window(always_on_top());
+                },
+                label { "Always on top: {always_on_top}" }
+            }
+            if let Some(monitors) = &*monitors.read() {
+                for (i, monitor) in monitors.clone().into_iter().enumerate() {
+                    Button {
+                        key: "{i}",
+                        onclick: move |_| platform.move_window_to_monitor(&monitor),
+                        label { "Move to monitor {i}" }
+                    }
+                }
+            }
+        }
+    )
+}